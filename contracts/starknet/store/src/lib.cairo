@@ -7,6 +7,10 @@ pub trait IFossilStore<TContractState> {
         min_update_interval: u64,
     );
     fn store_latest_blockhash_from_l1(ref self: TContractState, block_number: u64, blockhash: u256);
+    /// Records a verified batch and its hourly fees. When an hour's blocks arrive in
+    /// more than one batch, `data_points`, `avg_fee`, `min_fee` and `max_fee` are
+    /// combined, while `median_fee`, `weighted_avg_fee`, `twap_fee` and `blob_base_fee`
+    /// cannot be and are set to 0.
     fn update_store_state(
         ref self: TContractState,
         verifier_caller: starknet::ContractAddress,
@@ -21,6 +25,10 @@ pub trait IFossilStore<TContractState> {
     fn get_batch_last_block_link(self: @TContractState, batch_index: u64) -> u256;
     fn get_batch_first_block_parent_hash(self: @TContractState, batch_index: u64) -> u256;
     fn get_avg_fee(self: @TContractState, timestamp: u64) -> u64;
+    /// Fee statistics of the hour starting at `timestamp`. `median_fee`,
+    /// `weighted_avg_fee`, `twap_fee` and `blob_base_fee` are 0 for an hour proven
+    /// across several batches, see `update_store_state`.
+    fn get_hourly_fees(self: @TContractState, timestamp: u64) -> verifier::AvgFees;
     fn get_avg_fees_in_range(
         self: @TContractState, start_timestamp: u64, end_timestamp: u64,
     ) -> Array<u64>;
@@ -61,6 +69,12 @@ pub mod Store {
     pub struct AvgFees {
         data_points: u64,
         avg_fee: u64,
+        min_fee: u64,
+        max_fee: u64,
+        median_fee: u64,
+        weighted_avg_fee: u64,
+        twap_fee: u64,
+        blob_base_fee: u64,
     }
 
     #[derive(Drop, Serde, Debug)]
@@ -206,18 +220,47 @@ pub mod Store {
 
             for avg_fee in avg_fees {
                 let mut curr_avg_fee = self.avg_fees.entry(*avg_fee.timestamp);
-                if curr_avg_fee.data_points.read() == 0 {
+                let existing_points = curr_avg_fee.data_points.read();
+                if existing_points == 0 {
                     curr_avg_fee.data_points.write(*avg_fee.data_points);
                     curr_avg_fee.avg_fee.write(*avg_fee.avg_fee);
+                    curr_avg_fee.min_fee.write(*avg_fee.min_fee);
+                    curr_avg_fee.max_fee.write(*avg_fee.max_fee);
+                    curr_avg_fee.median_fee.write(*avg_fee.median_fee);
+                    curr_avg_fee.weighted_avg_fee.write(*avg_fee.weighted_avg_fee);
+                    curr_avg_fee.twap_fee.write(*avg_fee.twap_fee);
+                    curr_avg_fee.blob_base_fee.write(*avg_fee.blob_base_fee);
                 } else {
-                    let existing_points = curr_avg_fee.data_points.read();
-                    let existing_fee = curr_avg_fee.avg_fee.read();
-                    let new_data_points = existing_points + *avg_fee.data_points;
-                    let new_avg_fee = (existing_fee * existing_points
-                        + *avg_fee.avg_fee * *avg_fee.data_points)
-                        / new_data_points;
+                    let new_points = *avg_fee.data_points;
+                    let new_data_points = existing_points + new_points;
                     curr_avg_fee.data_points.write(new_data_points);
-                    curr_avg_fee.avg_fee.write(new_avg_fee);
+                    curr_avg_fee
+                        .avg_fee
+                        .write(
+                            merge_weighted(
+                                curr_avg_fee.avg_fee.read(),
+                                existing_points,
+                                *avg_fee.avg_fee,
+                                new_points,
+                            ),
+                        );
+
+                    let existing_min = curr_avg_fee.min_fee.read();
+                    if *avg_fee.min_fee < existing_min {
+                        curr_avg_fee.min_fee.write(*avg_fee.min_fee);
+                    }
+                    let existing_max = curr_avg_fee.max_fee.read();
+                    if *avg_fee.max_fee > existing_max {
+                        curr_avg_fee.max_fee.write(*avg_fee.max_fee);
+                    }
+
+                    // Medians, gas- and time-weighted averages and blob fee means of
+                    // two partial hours do not combine into those of the whole hour
+                    // without the samples behind them, so they are left unknown
+                    curr_avg_fee.median_fee.write(0);
+                    curr_avg_fee.weighted_avg_fee.write(0);
+                    curr_avg_fee.twap_fee.write(0);
+                    curr_avg_fee.blob_base_fee.write(0);
                 }
             };
 
@@ -277,6 +320,22 @@ pub mod Store {
             curr_state.avg_fee.read()
         }
 
+        fn get_hourly_fees(self: @ContractState, timestamp: u64) -> verifier::AvgFees {
+            assert!(timestamp % HOUR_IN_SECONDS == 0, "Timestamp must be a multiple of 3600");
+            let curr_state = self.avg_fees.entry(timestamp);
+            verifier::AvgFees {
+                timestamp,
+                data_points: curr_state.data_points.read(),
+                avg_fee: curr_state.avg_fee.read(),
+                min_fee: curr_state.min_fee.read(),
+                max_fee: curr_state.max_fee.read(),
+                median_fee: curr_state.median_fee.read(),
+                weighted_avg_fee: curr_state.weighted_avg_fee.read(),
+                twap_fee: curr_state.twap_fee.read(),
+                blob_base_fee: curr_state.blob_base_fee.read(),
+            }
+        }
+
         fn get_avg_fees_in_range(
             self: @ContractState, start_timestamp: u64, end_timestamp: u64,
         ) -> Array<u64> {
@@ -307,4 +366,8 @@ pub mod Store {
             self.upgradeable.upgrade(new_class_hash);
         }
     }
+
+    fn merge_weighted(existing: u64, existing_points: u64, new: u64, new_points: u64) -> u64 {
+        (existing * existing_points + new * new_points) / (existing_points + new_points)
+    }
 }
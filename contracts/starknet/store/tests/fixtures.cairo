@@ -2,27 +2,85 @@ use verifier::{AvgFees, Journal};
 
 pub fn test_journal() -> Journal {
     Journal {
+        version: 1,
+        method_id: 0x1111111122222222333333334444444455555555666666667777777788888888,
+        hasher_id: 1,
+        chain_id: 1,
         batch_index: 21372,
         latest_mmr_block: 21885255,
         latest_mmr_block_hash: 43682514556216407473011458789597897698293896008872145031777516179699381505159,
         root_hash: 12772579079189990682026072054525913505284574149192308227726174460436807697618,
         leaves_count: 328,
         first_block_parent_hash: 60262473598450372754459219060383372720591663961595942183656363127567779592634,
+        fork_id: 3,
     }
 }
 
 pub fn test_avg_fees_1() -> Span<AvgFees> {
     array![
-        AvgFees { timestamp: 1740020400, data_points: 120, avg_fee: 647012712 },
-        AvgFees { timestamp: 1740024000, data_points: 208, avg_fee: 640408910 },
-        AvgFees { timestamp: 1740027600, data_points: 100, avg_fee: 640208910 },
-        AvgFees { timestamp: 1740031200, data_points: 100, avg_fee: 640404910 },
+        AvgFees {
+            timestamp: 1740020400,
+            data_points: 120,
+            avg_fee: 647012712,
+            min_fee: 647012712,
+            max_fee: 647012712,
+            median_fee: 647012712,
+            weighted_avg_fee: 647012712,
+            twap_fee: 647012712,
+            blob_base_fee: 1,
+        },
+        AvgFees {
+            timestamp: 1740024000,
+            data_points: 208,
+            avg_fee: 640408910,
+            min_fee: 640408910,
+            max_fee: 640408910,
+            median_fee: 640408910,
+            weighted_avg_fee: 640408910,
+            twap_fee: 640408910,
+            blob_base_fee: 1,
+        },
+        AvgFees {
+            timestamp: 1740027600,
+            data_points: 100,
+            avg_fee: 640208910,
+            min_fee: 640208910,
+            max_fee: 640208910,
+            median_fee: 640208910,
+            weighted_avg_fee: 640208910,
+            twap_fee: 640208910,
+            blob_base_fee: 1,
+        },
+        AvgFees {
+            timestamp: 1740031200,
+            data_points: 100,
+            avg_fee: 640404910,
+            min_fee: 640404910,
+            max_fee: 640404910,
+            median_fee: 640404910,
+            weighted_avg_fee: 640404910,
+            twap_fee: 640404910,
+            blob_base_fee: 1,
+        },
     ]
         .span()
 }
 
 pub fn test_avg_fees_2() -> Span<AvgFees> {
-    array![AvgFees { timestamp: 1740020400, data_points: 80, avg_fee: 647000000 }].span()
+    array![
+        AvgFees {
+            timestamp: 1740020400,
+            data_points: 80,
+            avg_fee: 647000000,
+            min_fee: 647000000,
+            max_fee: 647000000,
+            median_fee: 647000000,
+            weighted_avg_fee: 647000000,
+            twap_fee: 647000000,
+            blob_base_fee: 1,
+        },
+    ]
+        .span()
 }
 
 pub fn calldata_default() -> Span<felt252> {
@@ -290,8 +290,28 @@ fn test_weighted_average_fee_calculation() {
     // Create test data with known weighted average result
     let timestamp: u64 = 3600; // 1 hour
     let mut avg_fees = array![
-        verifier::AvgFees { timestamp, avg_fee: 100, data_points: 10 },
-        verifier::AvgFees { timestamp, avg_fee: 200, data_points: 20 },
+        verifier::AvgFees {
+            timestamp,
+            data_points: 10,
+            avg_fee: 100,
+            min_fee: 90,
+            max_fee: 110,
+            median_fee: 100,
+            weighted_avg_fee: 100,
+            twap_fee: 100,
+            blob_base_fee: 1,
+        },
+        verifier::AvgFees {
+            timestamp,
+            data_points: 20,
+            avg_fee: 200,
+            min_fee: 150,
+            max_fee: 250,
+            median_fee: 200,
+            weighted_avg_fee: 200,
+            twap_fee: 200,
+            blob_base_fee: 1,
+        },
     ];
 
     // First update
@@ -300,6 +320,33 @@ fn test_weighted_average_fee_calculation() {
     // Expected weighted average: (100 * 10 + 200 * 20) / (10 + 20) = 166.67 ≈ 166
     let fee = dispatcher.get_avg_fee(timestamp);
     assert_eq!(fee, 166, "Incorrect weighted average calculation");
+
+    let hourly_fees = dispatcher.get_hourly_fees(timestamp);
+    assert_eq!(hourly_fees.data_points, 30);
+    assert_eq!(hourly_fees.avg_fee, 166);
+    assert_eq!(hourly_fees.min_fee, 90);
+    assert_eq!(hourly_fees.max_fee, 250);
+    // These do not combine across partial hours
+    assert_eq!(hourly_fees.median_fee, 0);
+    assert_eq!(hourly_fees.weighted_avg_fee, 0);
+    assert_eq!(hourly_fees.twap_fee, 0);
+    assert_eq!(hourly_fees.blob_base_fee, 0);
+}
+
+#[test]
+fn test_single_batch_hour_keeps_all_fees() {
+    let dispatcher = deploy();
+
+    start_cheat_caller_address(dispatcher.contract_address, verifier_address());
+    let avg_fees = test_avg_fees_1();
+    dispatcher.update_store_state(OWNER(), test_journal(), avg_fees, "IPFS_HASH_CID");
+
+    let hourly_fees = dispatcher.get_hourly_fees(*avg_fees[0].timestamp);
+    assert_eq!(hourly_fees.data_points, *avg_fees[0].data_points);
+    assert_eq!(hourly_fees.median_fee, *avg_fees[0].median_fee);
+    assert_eq!(hourly_fees.weighted_avg_fee, *avg_fees[0].weighted_avg_fee);
+    assert_eq!(hourly_fees.twap_fee, *avg_fees[0].twap_fee);
+    assert_eq!(hourly_fees.blob_base_fee, *avg_fees[0].blob_base_fee);
 }
 
 #[test]
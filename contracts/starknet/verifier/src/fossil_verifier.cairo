@@ -6,8 +6,11 @@ pub trait IFossilVerifier<TContractState> {
     fn update_verifier_address(
         ref self: TContractState, new_verifier_address: starknet::ContractAddress,
     );
+    fn update_method_id(ref self: TContractState, new_method_id: u256);
     fn get_verifier_address(self: @TContractState) -> starknet::ContractAddress;
     fn get_fossil_store_address(self: @TContractState) -> starknet::ContractAddress;
+    fn get_method_id(self: @TContractState) -> u256;
+    fn get_chain_id(self: @TContractState) -> u64;
     fn upgrade(ref self: TContractState, new_class_hash: starknet::ClassHash);
 }
 
@@ -17,7 +20,7 @@ mod FossilVerifier {
     use fossil_store::{IFossilStoreDispatcher, IFossilStoreDispatcherTrait};
     use openzeppelin_access::ownable::OwnableComponent;
     use openzeppelin_upgrades::UpgradeableComponent;
-    use verifier::decode_journal;
+    use verifier::{decode_journal, method_id_from_image_id};
     use verifier::groth16_verifier::{
         IRisc0Groth16VerifierBN254Dispatcher, IRisc0Groth16VerifierBN254DispatcherTrait,
     };
@@ -38,6 +41,10 @@ mod FossilVerifier {
     struct Storage {
         bn254_verifier: IRisc0Groth16VerifierBN254Dispatcher,
         fossil_store: IFossilStoreDispatcher,
+        // Image ID of the guest whose proofs are accepted, as the journal encodes it
+        method_id: u256,
+        // The Ethereum chain the batches are built from
+        chain_id: u64,
         #[substorage(v0)]
         ownable: OwnableComponent::Storage,
         #[substorage(v0)]
@@ -68,11 +75,15 @@ mod FossilVerifier {
         verifier_address: starknet::ContractAddress,
         fossil_store_address: starknet::ContractAddress,
         owner: starknet::ContractAddress,
+        method_id: u256,
+        chain_id: u64,
     ) {
         self
             .bn254_verifier
             .write(IRisc0Groth16VerifierBN254Dispatcher { contract_address: verifier_address });
         self.fossil_store.write(IFossilStoreDispatcher { contract_address: fossil_store_address });
+        self.method_id.write(method_id);
+        self.chain_id.write(chain_id);
         self.ownable.initializer(owner);
     }
 
@@ -82,13 +93,21 @@ mod FossilVerifier {
             ref self: ContractState, mut proof: Span<felt252>, ipfs_hash: ByteArray, is_build: bool,
         ) -> bool {
             let _ = proof.pop_front();
-            let journal = self
+            let (image_id, journal) = self
                 .bn254_verifier
                 .read()
                 .verify_groth16_proof_bn254(proof)
                 .expect('Failed to verify proof');
 
+            // The Groth16 verifier accepts a proof of any guest, on any chain. The method ID
+            // in the journal is whatever the prover passed in, so only the proven image ID
+            // tells which guest ran.
+            let method_id = self.method_id.read();
+            assert!(method_id_from_image_id(image_id) == method_id, "Unknown image ID");
+
             let (journal, avg_fees) = decode_journal(journal);
+            assert!(journal.method_id == method_id, "Unknown method ID");
+            assert!(journal.chain_id == self.chain_id.read(), "Chain ID mismatch");
 
             let fossil_store = self.fossil_store.read();
 
@@ -140,6 +159,11 @@ mod FossilVerifier {
                 );
         }
 
+        fn update_method_id(ref self: ContractState, new_method_id: u256) {
+            self.ownable.assert_only_owner();
+            self.method_id.write(new_method_id);
+        }
+
         fn get_verifier_address(self: @ContractState) -> starknet::ContractAddress {
             self.bn254_verifier.read().contract_address
         }
@@ -148,6 +172,14 @@ mod FossilVerifier {
             self.fossil_store.read().contract_address
         }
 
+        fn get_method_id(self: @ContractState) -> u256 {
+            self.method_id.read()
+        }
+
+        fn get_chain_id(self: @ContractState) -> u64 {
+            self.chain_id.read()
+        }
+
         fn upgrade(ref self: ContractState, new_class_hash: starknet::ClassHash) {
             self.ownable.assert_only_owner();
             self.upgradeable.upgrade(new_class_hash);
@@ -2,16 +2,28 @@ pub mod fossil_verifier;
 pub mod groth16_verifier;
 mod groth16_verifier_constants;
 pub mod universal_ecip;
-use core::num::traits::{Bounded, WideMul};
+
+// Must match `guest_types::journal::JOURNAL_VERSION`
+pub const JOURNAL_VERSION: u8 = 1;
+// Must match `guest_types::journal::SHA256_HASHER_ID`
+pub const SHA256_HASHER_ID: u8 = 1;
+
+const AVG_FEES_SIZE: usize = 72;
 
 #[derive(Drop, Debug, Copy, PartialEq, Serde)]
 pub struct Journal {
+    pub version: u8,
+    // The eight big-endian u32 words of the image ID, concatenated
+    pub method_id: u256,
+    pub hasher_id: u8,
+    pub chain_id: u64,
     pub batch_index: u64,
     pub latest_mmr_block: u64,
     pub latest_mmr_block_hash: u256,
     pub root_hash: u256,
     pub leaves_count: u64,
     pub first_block_parent_hash: u256,
+    pub fork_id: u8,
 }
 
 #[derive(Drop, Debug, PartialEq, Serde)]
@@ -19,246 +31,165 @@ pub struct AvgFees {
     pub timestamp: u64,
     pub data_points: u64,
     pub avg_fee: u64,
+    pub min_fee: u64,
+    pub max_fee: u64,
+    pub median_fee: u64,
+    pub weighted_avg_fee: u64,
+    pub twap_fee: u64,
+    pub blob_base_fee: u64,
 }
 
+// Decodes the versioned journal written by `GuestOutput::encode` in guest-types.
+// All integers are big-endian and hashes are 32 raw bytes.
 pub fn decode_journal(journal_bytes: Span<u8>) -> (Journal, Array<AvgFees>) {
-    let mut offset = 0; // Skip initial bytes
+    let mut offset = 0;
 
-    // Parse batch_index
-    let mut batch_index: u64 = 0;
-    let mut i = 0;
-    while i < 8 {
-        let f0: u64 = (*journal_bytes.at(offset + i)).into();
-        let f1: u64 = BitShift::shl(f0, 8 * i.into());
-        batch_index += f1;
-        i += 1;
-    };
+    let version = *journal_bytes.at(offset);
+    assert!(version == JOURNAL_VERSION, "Unsupported journal version: {}", version);
+    offset += 1;
+
+    let method_id = read_u256(journal_bytes, offset);
+    offset += 32;
 
-    // Parse latest_mmr_block
+    let hasher_id = *journal_bytes.at(offset);
+    assert!(hasher_id == SHA256_HASHER_ID, "Unknown hasher ID: {}", hasher_id);
+    offset += 1;
+
+    let chain_id = read_u64(journal_bytes, offset);
     offset += 8;
-    let mut latest_mmr_block: u64 = 0;
-    let mut j = 0;
-    while j < 8 {
-        let f0: u64 = (*journal_bytes.at(offset + j)).into();
-        let f1: u64 = BitShift::shl(f0, 8 * j.into());
-        latest_mmr_block += f1;
-        j += 1;
-    };
-    // Parse latest_mmr_block_hash
-    offset += 8; // Skip to start of hash length
-    offset += 4; // Skip length indicator (66, 0, 0, 0)
-    offset += 2; // Skip "0x" prefix
-    let mut latest_mmr_block_hash: u256 = 0;
-    let mut i = offset;
-    let loop_end = offset + 64; // 64 hex characters for 32 bytes
-
-    loop {
-        if i >= loop_end {
-            break;
-        }
-
-        let f0: u256 = BitShift::shl(latest_mmr_block_hash, 4);
-        let f1: u256 = (*journal_bytes.at(i)).into();
-        let f2: u256 = if f1 < 58 { // '0'-'9' vs 'a'-'f'
-            48 // ASCII '0'
-        } else {
-            87 // ASCII 'a' - 10
-        };
-        latest_mmr_block_hash = f0 + f1 - f2;
-        i += 1;
-    };
-    // Parse root_hash
-    offset += 66; // Skip past latest_mmr_block_hash (64 hex chars + "0x")
-    offset += 4; // Skip length indicator (66, 0, 0, 0)
-    offset += 2; // Skip "0x" prefix
-    let mut root_hash: u256 = 0;
-    let mut i = offset;
-    let loop_end = offset + 64; // 64 hex characters for 32 bytes
-
-    loop {
-        if i >= loop_end {
-            break;
-        }
-
-        let f0: u256 = BitShift::shl(root_hash, 4);
-        let f1: u256 = (*journal_bytes.at(i)).into();
-        let f2: u256 = if f1 < 58 { // '0'-'9' vs 'a'-'f'
-            48 // ASCII '0'
-        } else {
-            87 // ASCII 'a' - 10
-        };
-        root_hash = f0 + f1 - f2;
-        i += 1;
-    };
-    // Parse leaves_count
-    offset += 66;
-    let mut leaves_count: u64 = 0;
-    let mut m = 0;
-    while m < 8 {
-        let f0: u64 = (*journal_bytes.at(offset + m)).into();
-        let f1: u64 = BitShift::shl(f0, 8 * m.into());
-        leaves_count += f1;
-        m += 1;
-    };
 
-    // Parse first_block_parent_hash
+    let batch_index = read_u64(journal_bytes, offset);
     offset += 8;
-    offset += 4; // Skip length indicator (66, 0, 0, 0)
-    offset += 2; // Skip "0x" prefix
-    let mut first_block_parent_hash: u256 = 0;
-    let mut i = offset;
-    let loop_end = offset + 64;
-
-    loop {
-        if i >= loop_end {
-            break;
-        }
-
-        let f0: u256 = BitShift::shl(first_block_parent_hash, 4);
-        let f1: u256 = (*journal_bytes.at(i)).into();
-        let f2: u256 = if f1 < 58 { // '0'-'9' vs 'a'-'f'
-            48 // ASCII '0'
-        } else {
-            87 // ASCII 'a' - 10
-        };
-        first_block_parent_hash = f0 + f1 - f2;
-        i += 1;
-    };
 
-    // Parse avg_fees
-    offset += 66;
+    let latest_mmr_block = read_u64(journal_bytes, offset);
+    offset += 8;
 
-    let mut avg_fees_len: usize = 0;
-    let mut i = 0;
-    while i < 4 {
-        let f0: u32 = (*journal_bytes.at(offset + i)).into();
-        let f1: u32 = BitShift::shl(f0, 8 * i.into());
-        avg_fees_len += f1;
-        i += 1;
-    };
+    let latest_mmr_block_hash = read_u256(journal_bytes, offset);
+    offset += 32;
 
-    offset += 4;
-    let mut avg_fees: Array<AvgFees> = array![];
+    let root_hash = read_u256(journal_bytes, offset);
+    offset += 32;
 
-    for _ in 0..avg_fees_len {
-        let mut timestamp: u64 = 0;
-        let mut data_points: u64 = 0;
-        let mut avg_fee: u64 = 0;
-
-        let mut j = 0;
-        while j < 8 {
-            let f0: u64 = (*journal_bytes.at(offset + j)).into();
-            let f1: u64 = BitShift::shl(f0, 8 * j.into());
-            timestamp += f1;
-            j += 1;
-        };
+    let leaves_count = read_u64(journal_bytes, offset);
+    offset += 8;
 
-        offset += 8;
-        let mut j = 0;
-        while j < 8 {
-            let f0: u64 = (*journal_bytes.at(offset + j)).into();
-            let f1: u64 = BitShift::shl(f0, 8 * j.into());
-            data_points += f1;
-            j += 1;
-        };
+    let first_block_parent_hash = read_u256(journal_bytes, offset);
+    offset += 32;
 
-        offset += 8;
-        let mut j = 0;
-        while j < 8 {
-            let f0: u64 = (*journal_bytes.at(offset + j)).into();
-            let f1: u64 = BitShift::shl(f0, 8 * j.into());
-            avg_fee += f1;
-            j += 1;
-        };
+    let fork_id = *journal_bytes.at(offset);
+    offset += 1;
 
-        offset += 8;
+    let avg_fees_len: usize = read_be(journal_bytes, offset, 4).try_into().unwrap();
+    offset += 4;
+    assert!(
+        journal_bytes.len() == offset + avg_fees_len * AVG_FEES_SIZE,
+        "Journal length does not match its avg fees",
+    );
 
-        avg_fees.append(AvgFees { timestamp, data_points, avg_fee });
+    let mut avg_fees: Array<AvgFees> = array![];
+    for _ in 0..avg_fees_len {
+        let timestamp = read_u64(journal_bytes, offset);
+        let data_points = read_u64(journal_bytes, offset + 8);
+        let avg_fee = read_u64(journal_bytes, offset + 16);
+        let min_fee = read_u64(journal_bytes, offset + 24);
+        let max_fee = read_u64(journal_bytes, offset + 32);
+        let median_fee = read_u64(journal_bytes, offset + 40);
+        let weighted_avg_fee = read_u64(journal_bytes, offset + 48);
+        let twap_fee = read_u64(journal_bytes, offset + 56);
+        let blob_base_fee = read_u64(journal_bytes, offset + 64);
+        offset += AVG_FEES_SIZE;
+
+        avg_fees
+            .append(
+                AvgFees {
+                    timestamp,
+                    data_points,
+                    avg_fee,
+                    min_fee,
+                    max_fee,
+                    median_fee,
+                    weighted_avg_fee,
+                    twap_fee,
+                    blob_base_fee,
+                },
+            );
     };
 
     (
         Journal {
+            version,
+            method_id,
+            hasher_id,
+            chain_id,
             batch_index,
             latest_mmr_block,
             latest_mmr_block_hash,
             root_hash,
             leaves_count,
             first_block_parent_hash,
+            fork_id,
         },
         avg_fees,
     )
 }
 
-trait BitShift<T> {
-    fn shl(x: T, n: T) -> T;
-    fn shr(x: T, n: T) -> T;
+// Converts the image ID returned by the Groth16 verifier to the method ID encoding the
+// journal uses. Garaga reads each word of the image ID big-endian, while the words of a
+// RISC Zero image ID, which the journal encodes, are little-endian.
+pub fn method_id_from_image_id(image_id: Span<u32>) -> u256 {
+    assert!(image_id.len() == 8, "Image ID must have 8 words");
+    let mut method_id: u256 = 0;
+    for word in image_id {
+        method_id = method_id * 0x100000000 + swap_bytes(*word).into();
+    };
+    method_id
 }
 
-impl U256BitShift of BitShift<u256> {
-    fn shl(x: u256, n: u256) -> u256 {
-        let res = WideMul::wide_mul(x, pow(2, n));
-        u256 { low: res.limb0, high: res.limb1 }
-    }
-
-    fn shr(x: u256, n: u256) -> u256 {
-        x / pow(2, n)
-    }
+fn swap_bytes(word: u32) -> u32 {
+    let b0 = word % 0x100;
+    let b1 = (word / 0x100) % 0x100;
+    let b2 = (word / 0x10000) % 0x100;
+    let b3 = word / 0x1000000;
+    b0 * 0x1000000 + b1 * 0x10000 + b2 * 0x100 + b3
 }
 
-impl U32BitShift of BitShift<u32> {
-    fn shl(x: u32, n: u32) -> u32 {
-        (WideMul::wide_mul(x, pow(2, n)) & Bounded::<u32>::MAX.into()).try_into().unwrap()
-    }
-
-    fn shr(x: u32, n: u32) -> u32 {
-        x / pow(2, n)
-    }
+// Reads `len` bytes starting at `offset` as a big-endian integer
+fn read_be(journal_bytes: Span<u8>, offset: usize, len: usize) -> u256 {
+    let mut value: u256 = 0;
+    let mut i = 0;
+    while i < len {
+        let byte: u256 = (*journal_bytes.at(offset + i)).into();
+        value = value * 256 + byte;
+        i += 1;
+    };
+    value
 }
 
-impl U64BitShift of BitShift<u64> {
-    fn shl(x: u64, n: u64) -> u64 {
-        (WideMul::wide_mul(x, pow(2, n)) & Bounded::<u64>::MAX.into()).try_into().unwrap()
-    }
-
-    fn shr(x: u64, n: u64) -> u64 {
-        x / pow(2, n)
-    }
+fn read_u64(journal_bytes: Span<u8>, offset: usize) -> u64 {
+    read_be(journal_bytes, offset, 8).try_into().unwrap()
 }
 
-impl U128BitShift of BitShift<u128> {
-    fn shl(x: u128, n: u128) -> u128 {
-        let res = WideMul::wide_mul(x, pow(2, n));
-        res.low
-    }
-
-    fn shr(x: u128, n: u128) -> u128 {
-        x / pow(2, n)
-    }
-}
-
-fn pow<T, +Sub<T>, +Mul<T>, +Div<T>, +Rem<T>, +PartialEq<T>, +Into<u8, T>, +Drop<T>, +Copy<T>>(
-    base: T, exp: T,
-) -> T {
-    if exp == 0_u8.into() {
-        1_u8.into()
-    } else if exp == 1_u8.into() {
-        base
-    } else if exp % 2_u8.into() == 0_u8.into() {
-        pow(base * base, exp / 2_u8.into())
-    } else {
-        base * pow(base * base, exp / 2_u8.into())
-    }
+fn read_u256(journal_bytes: Span<u8>, offset: usize) -> u256 {
+    read_be(journal_bytes, offset, 32)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::decode_journal;
+    use super::{decode_journal, method_id_from_image_id};
 
+    // Same bytes as crates/guest-types/test-vectors/journal_v1.hex
     #[test]
     fn decode_journal_test() {
         let journal_bytes = get_journal_bytes();
 
         let (journal, avg_fees) = decode_journal(journal_bytes);
+        assert_eq!(journal.version, 1);
+        assert_eq!(
+            journal.method_id,
+            0x1111111122222222333333334444444455555555666666667777777788888888,
+        );
+        assert_eq!(journal.hasher_id, 1);
+        assert_eq!(journal.chain_id, 11155111);
         assert_eq!(journal.batch_index, 21369);
         assert_eq!(journal.latest_mmr_block, 21882622);
         assert_eq!(
@@ -269,10 +200,21 @@ mod tests {
             journal.root_hash, 0x930aa189e5be10188debe34e6df8930d0bce5ee7c61ff26222e5a33e2ce421fb,
         );
         assert_eq!(journal.leaves_count, 767);
+        assert_eq!(
+            journal.first_block_parent_hash,
+            0x85350e4a9d2bd94d4b2bb6b9e2a09a3e8f6b85c7e39b04de6d8f5eb4fa32d0c3,
+        );
+        assert_eq!(journal.fork_id, 3);
         assert_eq!(avg_fees.len(), 3);
         assert_eq!(*avg_fees[0].timestamp, 1739984400);
         assert_eq!(*avg_fees[0].data_points, 210);
         assert_eq!(*avg_fees[0].avg_fee, 1356994173);
+        assert_eq!(*avg_fees[0].min_fee, 1210000000);
+        assert_eq!(*avg_fees[0].max_fee, 1520000000);
+        assert_eq!(*avg_fees[0].median_fee, 1350000000);
+        assert_eq!(*avg_fees[0].weighted_avg_fee, 1361000000);
+        assert_eq!(*avg_fees[0].twap_fee, 1355000000);
+        assert_eq!(*avg_fees[0].blob_base_fee, 1);
 
         assert_eq!(*avg_fees[1].timestamp, 1739988000);
         assert_eq!(*avg_fees[1].data_points, 297);
@@ -281,326 +223,421 @@ mod tests {
         assert_eq!(*avg_fees[2].timestamp, 1739991600);
         assert_eq!(*avg_fees[2].data_points, 260);
         assert_eq!(*avg_fees[2].avg_fee, 864421784);
+        assert_eq!(*avg_fees[2].median_fee, 860000000);
+        assert_eq!(*avg_fees[2].blob_base_fee, 2);
+    }
+
+    #[test]
+    #[should_panic(expected: "Unsupported journal version: 2")]
+    fn decode_journal_rejects_unknown_version() {
+        let bytes = get_journal_bytes();
+        let mut patched = array![2_u8];
+        for byte in bytes.slice(1, bytes.len() - 1) {
+            patched.append(*byte);
+        };
+        decode_journal(patched.span());
+    }
+
+    #[test]
+    fn method_id_from_image_id_test() {
+        let image_id = array![0x78563412, 0, 0, 0, 0, 0, 0, 0x01000000].span();
+        assert_eq!(
+            method_id_from_image_id(image_id),
+            0x1234567800000000000000000000000000000000000000000000000000000001,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected: "Image ID must have 8 words")]
+    fn method_id_from_image_id_rejects_short_id() {
+        method_id_from_image_id(array![1, 2, 3].span());
     }
 
     fn get_journal_bytes() -> Span<u8> {
         array![
-            121,
-            83,
+            1,
+            17,
+            17,
+            17,
+            17,
+            34,
+            34,
+            34,
+            34,
+            51,
+            51,
+            51,
+            51,
+            68,
+            68,
+            68,
+            68,
+            85,
+            85,
+            85,
+            85,
+            102,
+            102,
+            102,
+            102,
+            119,
+            119,
+            119,
+            119,
+            136,
+            136,
+            136,
+            136,
+            1,
             0,
             0,
             0,
             0,
             0,
+            170,
+            54,
+            167,
             0,
-            254,
-            230,
-            77,
-            1,
             0,
             0,
             0,
             0,
-            66,
             0,
+            83,
+            121,
             0,
             0,
-            48,
-            120,
-            57,
-            51,
-            48,
-            48,
-            52,
-            54,
-            97,
-            52,
-            50,
-            100,
-            50,
-            101,
-            57,
-            97,
-            101,
-            48,
-            57,
-            52,
-            101,
-            52,
-            56,
-            56,
-            57,
-            48,
-            57,
-            48,
-            51,
-            102,
-            57,
-            57,
-            56,
-            100,
-            54,
-            101,
-            100,
-            102,
-            49,
-            50,
-            50,
-            54,
-            53,
-            97,
-            97,
-            100,
-            55,
-            102,
-            53,
-            102,
-            54,
-            50,
-            48,
+            0,
+            0,
+            1,
+            77,
+            230,
+            254,
+            147,
+            0,
+            70,
+            164,
+            45,
+            46,
+            154,
+            224,
+            148,
+            228,
+            136,
+            144,
+            144,
+            63,
+            153,
+            141,
+            110,
+            223,
+            18,
+            38,
+            90,
+            173,
+            127,
+            95,
             98,
-            101,
-            52,
-            53,
-            48,
-            55,
-            101,
-            57,
-            54,
-            49,
-            100,
-            52,
-            56,
+            11,
+            228,
+            80,
+            126,
+            150,
+            29,
+            72,
+            147,
+            10,
+            161,
+            137,
+            229,
+            190,
+            16,
+            24,
+            141,
+            235,
+            227,
+            78,
+            109,
+            248,
+            147,
+            13,
+            11,
+            206,
+            94,
+            231,
+            198,
+            31,
+            242,
+            98,
+            34,
+            229,
+            163,
+            62,
+            44,
+            228,
+            33,
+            251,
             0,
             0,
-            66,
             0,
             0,
             0,
-            48,
-            120,
-            57,
-            51,
-            48,
-            97,
-            97,
-            49,
-            56,
-            57,
-            101,
-            53,
-            98,
-            101,
-            49,
-            48,
-            49,
-            56,
-            56,
-            100,
-            101,
-            98,
-            101,
-            51,
-            52,
-            101,
-            54,
-            100,
-            102,
-            56,
-            57,
-            51,
-            48,
-            100,
-            48,
-            98,
-            99,
-            101,
-            53,
-            101,
-            101,
-            55,
-            99,
-            54,
-            49,
-            102,
-            102,
-            50,
-            54,
-            50,
-            50,
-            50,
-            101,
+            0,
+            2,
+            255,
+            133,
             53,
-            97,
-            51,
-            51,
-            101,
-            50,
-            99,
-            101,
-            52,
+            14,
+            74,
+            157,
+            43,
+            217,
+            77,
+            75,
+            43,
+            182,
+            185,
+            226,
+            160,
+            154,
+            62,
+            143,
+            107,
+            133,
+            199,
+            227,
+            155,
+            4,
+            222,
+            109,
+            143,
+            94,
+            180,
+            250,
             50,
-            49,
-            102,
-            98,
+            208,
+            195,
+            3,
             0,
             0,
-            255,
-            2,
             0,
+            3,
             0,
             0,
             0,
             0,
+            103,
+            182,
+            14,
+            16,
             0,
-            66,
             0,
             0,
             0,
-            48,
-            120,
-            49,
-            100,
-            51,
-            52,
-            97,
-            52,
-            51,
-            50,
-            51,
-            102,
-            54,
-            55,
-            97,
-            50,
-            55,
-            57,
-            53,
-            98,
-            56,
-            51,
-            52,
-            102,
-            55,
-            100,
-            54,
-            52,
-            53,
-            98,
-            48,
-            57,
-            102,
-            100,
-            100,
-            102,
-            51,
-            55,
-            97,
-            50,
-            99,
-            99,
-            49,
-            49,
-            100,
-            52,
-            100,
-            98,
-            51,
-            50,
-            56,
-            52,
-            102,
-            52,
-            56,
-            55,
-            100,
-            102,
-            52,
-            53,
-            102,
-            102,
-            53,
-            54,
-            101,
-            101,
             0,
             0,
-            3,
             0,
+            210,
             0,
             0,
-            16,
-            14,
-            182,
-            103,
             0,
             0,
+            80,
+            226,
+            22,
+            125,
             0,
             0,
-            210,
             0,
             0,
+            72,
+            31,
+            34,
+            128,
+            0,
+            0,
+            0,
+            0,
+            90,
+            153,
+            92,
             0,
             0,
             0,
             0,
             0,
-            125,
-            22,
-            226,
             80,
+            119,
+            93,
+            128,
             0,
             0,
             0,
             0,
-            32,
-            28,
-            182,
-            103,
+            81,
+            31,
+            54,
+            64,
+            0,
+            0,
+            0,
+            0,
+            80,
+            195,
+            168,
+            192,
+            0,
+            0,
+            0,
             0,
             0,
             0,
             0,
-            41,
             1,
             0,
             0,
             0,
             0,
+            103,
+            182,
+            28,
+            32,
             0,
             0,
-            20,
-            13,
+            0,
+            0,
+            0,
+            0,
+            1,
+            41,
+            0,
+            0,
+            0,
+            0,
+            57,
             22,
+            13,
+            20,
+            0,
+            0,
+            0,
+            0,
+            52,
+            115,
+            188,
+            0,
+            0,
+            0,
+            0,
+            0,
+            62,
+            149,
+            186,
+            128,
+            0,
+            0,
+            0,
+            0,
+            56,
+            175,
+            27,
+            192,
+            0,
+            0,
+            0,
+            0,
             57,
+            86,
+            244,
+            128,
             0,
             0,
             0,
             0,
-            48,
-            42,
-            182,
-            103,
+            56,
+            236,
+            36,
+            192,
+            0,
+            0,
+            0,
             0,
             0,
             0,
             0,
-            4,
             1,
             0,
             0,
             0,
             0,
+            103,
+            182,
+            42,
+            48,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            1,
+            4,
+            0,
             0,
             0,
+            0,
+            51,
+            134,
+            7,
             152,
+            0,
+            0,
+            0,
+            0,
+            47,
+            22,
+            113,
+            128,
+            0,
+            0,
+            0,
+            0,
+            56,
             7,
-            134,
+            67,
+            0,
+            0,
+            0,
+            0,
+            0,
             51,
+            66,
+            143,
+            0,
+            0,
             0,
             0,
             0,
+            51,
+            219,
+            37,
+            128,
+            0,
+            0,
             0,
+            0,
+            51,
+            158,
+            28,
+            128,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            2,
         ]
             .span()
     }
@@ -1,21 +1,73 @@
-use verifier::{AvgFees, Journal};
+use super::groth16_fixture::{test_avg_fees, test_journal};
 
-pub fn test_journal() -> Journal {
-    Journal {
-        batch_index: 21372,
-        latest_mmr_block: 21885255,
-        latest_mmr_block_hash: 43682514556216407473011458789597897698293896008872145031777516179699381505159,
-        root_hash: 12772579079189990682026072054525913505284574149192308227726174460436807697618,
-        leaves_count: 328,
-        first_block_parent_hash: 60262473598450372754459219060383372720591663961595942183656363127567779592634,
-    }
+// The image ID `test_journal` claims, as the Groth16 verifier returns it: the bytes of
+// each method ID word in reverse order
+pub fn test_image_id() -> Span<u32> {
+    let method_id = test_journal().method_id;
+    let mut image_id = array![];
+    let mut i: usize = 8;
+    while i > 0 {
+        i -= 1;
+        let mut shifted = method_id;
+        for _ in 0..i {
+            shifted /= 0x100000000;
+        };
+        let word: u32 = (shifted % 0x100000000).try_into().unwrap();
+        image_id
+            .append(
+                (word % 0x100) * 0x1000000
+                    + ((word / 0x100) % 0x100) * 0x10000
+                    + ((word / 0x10000) % 0x100) * 0x100
+                    + word / 0x1000000,
+            );
+    };
+    image_id.span()
 }
 
-pub fn test_avg_fees() -> Array<AvgFees> {
-    array![
-        AvgFees { timestamp: 1740020400, data_points: 120, avg_fee: 647012712 },
-        AvgFees { timestamp: 1740024000, data_points: 208, avg_fee: 640408910 },
-    ]
+// `test_journal` and `test_avg_fees` encoded the way the guest commits them, for tests
+// that mock the Groth16 verifier
+pub fn test_journal_bytes() -> Span<u8> {
+    let journal = test_journal();
+    let mut bytes = array![];
+    append_be(ref bytes, journal.version.into(), 1);
+    append_be(ref bytes, journal.method_id, 32);
+    append_be(ref bytes, journal.hasher_id.into(), 1);
+    append_be(ref bytes, journal.chain_id.into(), 8);
+    append_be(ref bytes, journal.batch_index.into(), 8);
+    append_be(ref bytes, journal.latest_mmr_block.into(), 8);
+    append_be(ref bytes, journal.latest_mmr_block_hash, 32);
+    append_be(ref bytes, journal.root_hash, 32);
+    append_be(ref bytes, journal.leaves_count.into(), 8);
+    append_be(ref bytes, journal.first_block_parent_hash, 32);
+    append_be(ref bytes, journal.fork_id.into(), 1);
+
+    let avg_fees = test_avg_fees();
+    append_be(ref bytes, avg_fees.len().into(), 4);
+    for fees in avg_fees {
+        append_be(ref bytes, fees.timestamp.into(), 8);
+        append_be(ref bytes, fees.data_points.into(), 8);
+        append_be(ref bytes, fees.avg_fee.into(), 8);
+        append_be(ref bytes, fees.min_fee.into(), 8);
+        append_be(ref bytes, fees.max_fee.into(), 8);
+        append_be(ref bytes, fees.median_fee.into(), 8);
+        append_be(ref bytes, fees.weighted_avg_fee.into(), 8);
+        append_be(ref bytes, fees.twap_fee.into(), 8);
+        append_be(ref bytes, fees.blob_base_fee.into(), 8);
+    };
+    bytes.span()
+}
+
+// Appends the `len` low bytes of `value`, big-endian
+fn append_be(ref bytes: Array<u8>, value: u256, len: usize) {
+    let mut i = len;
+    while i > 0 {
+        i -= 1;
+        let mut shifted = value;
+        for _ in 0..i {
+            shifted /= 256;
+        };
+        bytes.append((shifted % 256).try_into().unwrap());
+    };
 }
 
 pub fn invalid_proof() -> Span<felt252> {
@@ -2569,2555 +2621,3 @@ pub fn invalid_proof() -> Span<felt252> {
     ]
         .span()
 }
-
-pub fn calldata_default() -> Span<felt252> {
-    array![
-        0x9f1,
-        0x6f9e832a5659c51f7383f17e,
-        0x935e6e1c5682ae615b2fea79,
-        0x1f35092744b08932,
-        0x0,
-        0x5aaf547526963720611306b,
-        0x568c88575968857ca242b884,
-        0x3ba3d9ff6b3079,
-        0x0,
-        0x1103226a1aa631f129a2f2b4,
-        0x7ec56457a502860fba77a856,
-        0x1749c82e9c3e12ef,
-        0x0,
-        0x26aa0e2f0223855212d2a09e,
-        0xe6d2007fdd9ebe936be856b8,
-        0x298b01ab86ea085f,
-        0x0,
-        0xb6f03dd8909813b1818fa9a3,
-        0x99e5afd33374b0775d934523,
-        0xbd9a236e0377bff,
-        0x0,
-        0x44b18d90d9459fda4e16b7b4,
-        0xb8b5fdb79b49a8d277431cd5,
-        0x629d35c404aaf6c,
-        0x0,
-        0xe4de7ee1f1d924fcdbaf44f7,
-        0x30b929bb025d9312a86b8f,
-        0x18040b9e479b175e,
-        0x0,
-        0xce3e4f16d029056fcb4df888,
-        0x85986b8f844de15f991349ab,
-        0x86ac1758508884c,
-        0x0,
-        0x8,
-        0x179ddbee,
-        0x119babfe,
-        0xb1a76733,
-        0xcbeaf9ed,
-        0x2f35a430,
-        0xcf2708ad,
-        0xcfb5b519,
-        0xc81d5d5f,
-        0x124,
-        0x7c,
-        0x53,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x47,
-        0xf1,
-        0x4d,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x42,
-        0x0,
-        0x0,
-        0x0,
-        0x30,
-        0x78,
-        0x36,
-        0x30,
-        0x39,
-        0x33,
-        0x36,
-        0x64,
-        0x35,
-        0x34,
-        0x62,
-        0x31,
-        0x62,
-        0x31,
-        0x30,
-        0x31,
-        0x36,
-        0x62,
-        0x30,
-        0x31,
-        0x35,
-        0x38,
-        0x39,
-        0x39,
-        0x63,
-        0x30,
-        0x64,
-        0x35,
-        0x33,
-        0x66,
-        0x65,
-        0x38,
-        0x33,
-        0x65,
-        0x63,
-        0x36,
-        0x39,
-        0x30,
-        0x30,
-        0x30,
-        0x36,
-        0x33,
-        0x66,
-        0x32,
-        0x31,
-        0x64,
-        0x65,
-        0x35,
-        0x64,
-        0x31,
-        0x64,
-        0x64,
-        0x39,
-        0x34,
-        0x34,
-        0x37,
-        0x61,
-        0x31,
-        0x31,
-        0x61,
-        0x63,
-        0x62,
-        0x30,
-        0x63,
-        0x38,
-        0x37,
-        0x0,
-        0x0,
-        0x42,
-        0x0,
-        0x0,
-        0x0,
-        0x30,
-        0x78,
-        0x31,
-        0x63,
-        0x33,
-        0x64,
-        0x30,
-        0x36,
-        0x30,
-        0x38,
-        0x63,
-        0x65,
-        0x38,
-        0x35,
-        0x64,
-        0x61,
-        0x38,
-        0x39,
-        0x30,
-        0x36,
-        0x66,
-        0x31,
-        0x63,
-        0x30,
-        0x32,
-        0x63,
-        0x64,
-        0x34,
-        0x61,
-        0x35,
-        0x36,
-        0x30,
-        0x36,
-        0x31,
-        0x33,
-        0x36,
-        0x31,
-        0x32,
-        0x31,
-        0x35,
-        0x39,
-        0x31,
-        0x63,
-        0x31,
-        0x35,
-        0x33,
-        0x32,
-        0x37,
-        0x36,
-        0x36,
-        0x34,
-        0x63,
-        0x61,
-        0x38,
-        0x62,
-        0x38,
-        0x35,
-        0x66,
-        0x64,
-        0x63,
-        0x63,
-        0x30,
-        0x34,
-        0x34,
-        0x64,
-        0x32,
-        0x0,
-        0x0,
-        0x48,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x42,
-        0x0,
-        0x0,
-        0x0,
-        0x30,
-        0x78,
-        0x38,
-        0x35,
-        0x33,
-        0x62,
-        0x35,
-        0x39,
-        0x66,
-        0x31,
-        0x34,
-        0x35,
-        0x32,
-        0x35,
-        0x39,
-        0x34,
-        0x34,
-        0x31,
-        0x63,
-        0x31,
-        0x64,
-        0x33,
-        0x35,
-        0x37,
-        0x62,
-        0x31,
-        0x66,
-        0x33,
-        0x63,
-        0x34,
-        0x64,
-        0x63,
-        0x34,
-        0x63,
-        0x36,
-        0x36,
-        0x38,
-        0x32,
-        0x38,
-        0x33,
-        0x34,
-        0x64,
-        0x63,
-        0x35,
-        0x39,
-        0x34,
-        0x37,
-        0x66,
-        0x33,
-        0x66,
-        0x33,
-        0x64,
-        0x63,
-        0x35,
-        0x61,
-        0x63,
-        0x38,
-        0x65,
-        0x35,
-        0x30,
-        0x34,
-        0x37,
-        0x37,
-        0x39,
-        0x62,
-        0x61,
-        0x0,
-        0x0,
-        0x2,
-        0x0,
-        0x0,
-        0x0,
-        0xb0,
-        0x9a,
-        0xb6,
-        0x67,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x78,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x68,
-        0xa1,
-        0x90,
-        0x26,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0xc0,
-        0xa8,
-        0xb6,
-        0x67,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0xd0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x4e,
-        0xdd,
-        0x2b,
-        0x26,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0xf841836668ca056247c9b127,
-        0x82c7056a7aa8ed6c8c6232b2,
-        0x14c1c8e9228d9bc1,
-        0xc3e7806f30550c651e646374,
-        0x6a3ba0715ec440498c38058c,
-        0xfea83e80db33a38,
-        0xb8fbfdb9b87d3909fd3af754,
-        0x7649f50752af5c3674015304,
-        0xe908955448d2afd,
-        0x11d08a4507cb12c9dc3cabea,
-        0x5fda2a4ebee77351f16cc376,
-        0x2b97d2119a5eba36,
-        0x22e017bf6ec80c5fdf8c6c0c,
-        0xa162c264a22a6e80d147aff5,
-        0x99722699219da3,
-        0x182e8e36c5e96f0d69680140,
-        0x60b3b4be6e963106c89dc35f,
-        0x4b760ab2eb3c66d,
-        0x497a2eb7fd946b01a63645df,
-        0xdd82e8dd5d5a85fbafd78abd,
-        0xbf0e9d64f51e97d,
-        0xe892ffbd26223c87509ae526,
-        0xe2f53eaf31a3d4634390e8fa,
-        0x840028437745738,
-        0x3c8aef2b728c4ee0133e35b5,
-        0xf2ca7c2182ddb4c3840d1ebe,
-        0x53fe02fb9b7b4fa,
-        0x4de6fa1fb62d3ad332b38073,
-        0xd126df68b6eb64aff331953,
-        0x1a9e653c39a7d112,
-        0x178fe7e28ece3fa03f0a4120,
-        0xb6491b47dde2ad336c6e1ed0,
-        0x192743fa4ee34c3d,
-        0x60929f536e00b48729f28bb5,
-        0x749c20156bf95d4cdf8a3304,
-        0x27bd713ea7aad7dd,
-        0xc0e9158e27934831908d8d7d,
-        0xe3e3ffdee6f04544a4572fcf,
-        0x3ef38f79047a42d,
-        0xc5fffa43e307d25640fa1c65,
-        0x7d7d610502013a9d644e31e2,
-        0x14c3f81ba0fe3e29,
-        0xe4d2abf0602ac9b05bc5c997,
-        0x99483c8976b96e53e7de38bf,
-        0x2abf205a97c7891b,
-        0xdb61519a0c9c6b624abf4c93,
-        0xe630754e123e1945b077ba47,
-        0xc39e9d45e65b62f,
-        0xada9c40b87cfe198bab55359,
-        0xc04e26efc5866d6e1002dc63,
-        0x1342815d7d3b732,
-        0xae2e188829fe319cdaf53170,
-        0x343a928f1ce0342c7b1f0acc,
-        0x1067d34bc7d6b4f1,
-        0x5aa650d782bd044cf0541380,
-        0x837f445c0d453570a55123dd,
-        0x27c18437cd2cc0ac,
-        0x44258aeabffc51a39bff0406,
-        0x5b9556721983a31cfa59557b,
-        0x19402d067493d346,
-        0xe0eeaaabdb838af788e21f4a,
-        0x79d68268e7b7b12871edcc6c,
-        0x16a496b168cb0e3f,
-        0xd6e0e7dbb0b03702300fd5da,
-        0x5313fc01b671f5ab83d071de,
-        0x1930926265a7a7d,
-        0x861057393cfff397f781790b,
-        0x91cddf652cf4b4ff014c2ebf,
-        0x9659ceb208aaf2,
-        0x59cb90bbc1e2d40c0e3a186f,
-        0x2ea84339ae543354228a7949,
-        0x2926146a23fb8d34,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x73692b9376d090b20e5ccfca,
-        0x7bd294b9c757d6f7aa4c6347,
-        0x778f7e113269a6,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0x0,
-        0xc24790f6d545aed52a2fbdb8,
-        0x184dac0e71909eb223ea52bf,
-        0x279a0a9dbd98089c,
-        0x23,
-        0x6fab4677b3091a620b2f469e,
-        0xed511f566aa00c921841b35d,
-        0x2b0ed689a5dccb52,
-        0xd6b184fa661d230b1b055880,
-        0x4da51d1398a43e9577ecb5ed,
-        0x3026380683a1103,
-        0xed1b4b3e370f575ccefa47cb,
-        0xcb2e347843a369cae62e5e3,
-        0x955db97a673bf58,
-        0x583423c65933a0fdfdbd6a92,
-        0x9db4a1f8a18c35116f3090e1,
-        0xeefb1e7a7b4520b,
-        0x5475572781b475e4df6a3d4d,
-        0x32762da62f1cddf6036b07ed,
-        0x1135ccb911c34a36,
-        0xace483158c879b907ba5d55f,
-        0x87b90eeeddf17db595f6b22d,
-        0x2c93f620bc712e0d,
-        0xd6d144f4f154283576bf8649,
-        0x2ca6fa67c870134be9a83cf3,
-        0x109cd89a804f6eac,
-        0x8e710d601ec3572fbc97e1a0,
-        0x6260b30392eb942f59151a0c,
-        0x1a43c3f0fddcb893,
-        0x98edbbb9ffb53ffe574e9be0,
-        0x5630b49c5fb3139a3c4430db,
-        0x1e119302a9532533,
-        0x7755fe3e96feaeffa570c08d,
-        0xe40b9512202c5cbc3f4848b7,
-        0x12d7182c1b7a1930,
-        0x3673739ab2bdcb3c0499ad5e,
-        0x2db5953655a520d0c9cd6f45,
-        0xe41a3a5d4e22a84,
-        0xf29d7d00cdf4e5d5940d3419,
-        0x47d100e8f7cb5c56960113d7,
-        0x215480d923ba8c0a,
-        0x351184263c6b0840d3b6f294,
-        0x5654db8135dbe39c57400ed,
-        0x1d77d42128333873,
-        0x6ce84eafc83b842122dbede0,
-        0x3f86608f1633e8901d1970b0,
-        0x242eb6ba041b6455,
-        0x1f8ae57f3501ff13256da379,
-        0x30b2aa75e76060052264f0ba,
-        0x182446c93941b26a,
-        0xae24e78001a29eb471341bc6,
-        0xac25171959e6bd15c8af6cb8,
-        0x82a0ef070c77efb,
-        0x42a32dd844352dea2474cf30,
-        0x6a6c10a0ea203f49631fc92f,
-        0x2970ca67620c1811,
-        0xb52a824e329a5c04c022bfaa,
-        0x1412595d902040b5c46c1f40,
-        0x5c02f2561ee1426,
-        0xccb86df4435632a1ee8df09a,
-        0xc168f5248514d948712b3ecd,
-        0x1c7e1375ad47b874,
-        0x55eeef0b18098455d5b9531c,
-        0xf74d8f217453688899beb303,
-        0x21d03aae71999a2a,
-        0x604809b88781c56c3c7cebff,
-        0x3d4e5fc012c528839e1f4d6a,
-        0x304ae5592397eb16,
-        0xd219543159a95330ad1509be,
-        0x42fe51cc54dfe81382505430,
-        0x22e25503433d52a3,
-        0x7a09d75021e3d18b9c9c0fc7,
-        0x2933a9c94ae04815266a1c,
-        0x14f0cf35bff89888,
-        0xf8492b1e428c0f01cfffb68c,
-        0x6de3b188f785d469bd1fb5da,
-        0x165d9884c994cf9e,
-        0x5c2ae6d194ea4372da0cc9f,
-        0xea137f1878d5f1daf3421a22,
-        0x8cac37c68788fbc,
-        0x74be0eaca6e92c00f5cdcbd0,
-        0xfa98558fe293e7007e15eda9,
-        0x1dfc79db9352b54e,
-        0xf3887a7433cc893abfc09a26,
-        0x3cd18440f25c54eef4bc2ab6,
-        0x10a1ca0eabc9e389,
-        0x3267df0904792ef0d4d9e057,
-        0x1699f8ceda97a4f37fc962ab,
-        0x1d32cc90730ad635,
-        0xabc4123a9ab12ff5148cc248,
-        0xeadd865ce324e366645b92f6,
-        0x13d9cba67fbeff9a,
-        0x3dce0982dcc772e5eb192aa3,
-        0xdb483a4178fc4f6166955479,
-        0x6b837d570bd93bd,
-        0x8eba1341b55d99585b5a34de,
-        0x920ed3c6d5a3a703a37e7fa9,
-        0xa7d74a2c17abe2b,
-        0x49503ce477b7a778f2dd1b43,
-        0x524ad2530215236597e87fa,
-        0x84398cac1e87802,
-        0xc62e4cb32c116fab235b0d4f,
-        0x26998a7864070e86bc3f1df7,
-        0x1bdb4f7ebb81a161,
-        0x690bb0e21ceef58547705ece,
-        0xa01a6e3dda8484488bbe335b,
-        0x97389266d20768b,
-        0xee2eb22945fa82a87fd5ba10,
-        0x5a082980b1cedbe3b6c37c4e,
-        0x8f565c983dc0a,
-        0x67d9c40b0ec2386fcc59b7c2,
-        0x16f30417fb758fac5abc3189,
-        0x15d9f493deb4f9ef,
-        0x7d06670ff6dfbe6a11ccf384,
-        0x9c248417b2864fb0d7f3fdf1,
-        0x237e369942df2c6c,
-        0xb99a63cc9b132bbc204112cf,
-        0x751581dd6c881b9c44011acc,
-        0x131b1a8d6e70b81d,
-        0x3e49c7d5e908915fb1f68bc6,
-        0xf1647fe03e05eb0e1fa7ce79,
-        0xa51f2e8cbdcbd83,
-        0x66e49b600cb1f9b5ffeb220,
-        0xf9b820c1ec57e5c796309b0,
-        0x88fc43762a1c102,
-        0xdd8da0e0a3eb7ea00b0bec70,
-        0x736bd8cf0edb61271f0e1f00,
-        0x2858a9325b7a53c4,
-        0xe341c927df4c357d54e946e4,
-        0x5c1fed51bb7d9edd7e5d3d09,
-        0x18f24de6db39dd85,
-        0x7314a3a9efe83413d648203f,
-        0x231cfca2953536b999ee006b,
-        0x1883288a61549ef6,
-        0x5af7ca961d7715bf9bc740e7,
-        0xfac4efce0c7f33c3947d978a,
-        0x9b9bd738d40b965,
-        0x6f18ee6a0f4473b348c36539,
-        0x39f494b48fa8b74d7d0dda1c,
-        0x2744e96204c887d0,
-        0xccf0e91a28280fe91cc529b3,
-        0x3dea83009789e447be809298,
-        0x2dbdeda735b5dd69,
-        0x5533458ec51b36be9821b6e5,
-        0x460c37c9ac1dc31b679022c6,
-        0x1293bd415d7b38e1,
-        0xe8bcc12e9bdd4cf068d2fe0f,
-        0xbfdd41ec8ee517ca677e0dc3,
-        0x242d0a5d249cd3ac,
-        0x8831ef81902e9d7f4440c294,
-        0xa5dd43258fa43b79ec3d1960,
-        0x1d08814f1e2accf9,
-        0x19e1edd140f6f91f2f18550a,
-        0x11f8914a56387679d966ccd1,
-        0x3a8665cf741833c,
-        0xd7abaea8dc719f2398eb7b31,
-        0xd382305b10de1f89ece177c0,
-        0x20f775e0f08aa4b5,
-        0x1cd76917181ece37ed3ae1eb,
-        0xc59ac7d0266ae929398a0087,
-        0x13d78ff211cbc4aa,
-        0x5546aa85258efd035f6a4fe5,
-        0x2896525db111006a4436caa8,
-        0x303a59e2669a9a5e,
-        0xc30559797942bcc2255a193c,
-        0x31c0a8ad1f87e32c42e7cb3b,
-        0x2fce176587e89f09,
-        0xbc19f9d1ff0ae395948a4c8c,
-        0xf3551c8b3b5a3738d8839ab,
-        0x2decdbf5710f4493,
-        0x8c031aa7fb5020f8ed2624ae,
-        0x1817a3b009121ee8242b6275,
-        0x1b9c06771323baac,
-        0x1eef680d30fdfb8960d56a43,
-        0x41efd2aece4322a314c84c92,
-        0x1863d0979893df66,
-        0x420cd53c77e8cdd1a2ba04b0,
-        0x27c046a397014dc0132e437d,
-        0x2abeb03722b18ecf,
-        0x7df9eb8c03323ae5f7288a83,
-        0xd3ab4be289fcdeec897d3a57,
-        0xf1f99e081960679,
-        0x3cd7291592f4d68a01708598,
-        0xc49ae4c15a436a70d937fdca,
-        0x10710bdf9399faca,
-        0xc2391ec4216d6124e44f4d52,
-        0xf976bf50aeb6c521d7517be6,
-        0x49bb865bd0636fa,
-        0xd92436107ea0f39958320cc1,
-        0xebc50ca41c928a5dc0676295,
-        0x13c26bc5a265bcf2,
-        0xbb89aec5bd006a974a44d427,
-        0xfc062354f23668f4dc476aec,
-        0x13f809ad6859bff1,
-        0x7ba4cbc5e1a9a2ca86b4caff,
-        0x525a4a38ac017a10a8d985d,
-        0x15fd46d0e92e2f44,
-        0x9ce0d643a7d9dc310dedfcde,
-        0xbed9734bdb3179931946b1bd,
-        0x2d5a9a6619643479,
-        0xc6d6e7b208fc9ab2ec83827d,
-        0xa4eb15f7cef9bccb92df2eb5,
-        0x18bf640e0a1edfde,
-        0xb1b22e8aa67cf99c2e4e0eab,
-        0xf9ada96013248e0e56dcc73,
-        0xed92fc1be337208,
-        0x4b373e1f541c99815ff0d75e,
-        0xa8ccded8b575cf29f0a35cd5,
-        0x5387dacd01a5809,
-        0xcca21d75cadc364660efa81,
-        0x2fdba6b059ef1b5f000955ff,
-        0x1328d343f6230624,
-        0x99f560bb66eeb061305d3fdf,
-        0xf127fa6121d3d0156beaa15,
-        0x194c1056283feaff,
-        0x599dc03c8a4c302a56ebe9e5,
-        0xbc423be78e603503e2e52536,
-        0xafe833fabff3433,
-        0xf46b6c435dbf50b3e6f85075,
-        0xc1cbfc70064a400220c4ac9f,
-        0x1fc7be5dc598026b,
-        0xdf80a711ea2442582328e7a0,
-        0x697c50dbdc1ce1ae19be5c74,
-        0x2b338ce42405b2f7,
-        0xca9138ff64d3818d2ba7c4b3,
-        0x196cd813aec829f7bbfc8885,
-        0x1db1e9bbb110f38b,
-        0x4c357f5fb02fe0e8c516d2dd,
-        0x6174de69e487e3c28f2ae90,
-        0x52ff87cd1e6b8e9,
-        0x8fd917ff1fdc60a1599139f3,
-        0xd9fcd2640b162d14733fe01a,
-        0xff421c4f95ef1ef,
-        0x5880f988b7696a20c39145f8,
-        0x61f6dfd2961e314a3a3c4d75,
-        0x125784d12c8ecfc1,
-        0xa2f6c4fa19ef851d3e5d3973,
-        0x9efbdbb266e57447575452b5,
-        0xc36e25fd73cef5a,
-        0x8f9f797d8ced5e769d58e3fc,
-        0x584a7233d25f53f25cacca5f,
-        0x1a7940437839125f,
-        0xb9125acefccf3a4db25a7ff3,
-        0xc5b4a24678218cfbdf3f933f,
-        0x2f78ae019340dd1,
-        0xa048b5ec31e344028edd6354,
-        0x32e607ec1a42056278088bdd,
-        0x17985bbf7af525ad,
-        0x5d9e4ddca4fb3ec76120e263,
-        0x51c79149b9feb2b0b09b5c43,
-        0x210e2e93ae734d12,
-        0x6630d456010e8fa4db1119c9,
-        0xc4dd5bf754e09a1a60acd256,
-        0xc7cd0f645dcdb92,
-        0xc71ad71c142dc085ce149f5c,
-        0x7f09e9eebe7ef736bfb1f48b,
-        0x2f11952b66ff535d,
-        0xfc60d23775b5d0b165c76898,
-        0xa33f581bf23382835f1bf7b1,
-        0x2e6714e0986d1b91,
-        0x1971847ae7bdaec18155db6e,
-        0x898a0cd9b16dc8ca7d80a1d2,
-        0xfa721e26bcf8e57,
-        0xe66179e55e1abc462be3bdbb,
-        0x1e738c8b45a04cc4b299c0d6,
-        0x1b82dc863a5efe30,
-        0xa2caa105e3f070980f14459e,
-        0x79e1c50b86a4a1cf848ae201,
-        0x2b1029b5676eb91d,
-        0xd2e7207d379c37b05be97f66,
-        0x92199f652ecba42ebf719358,
-        0x250c9e150f9240d0,
-        0xefb194b1e5a35c309049a088,
-        0x8ca08db2f952d7dbcdb720ed,
-        0x2d9fdb8d35bfa0ad,
-        0xb388e0055d1b4e238b3d4d24,
-        0x89bda8cbe080ed96785c9f4b,
-        0x125cf51b3b1d21c8,
-        0xf95201df60d54c8c4e7dd82b,
-        0x5988b2d5b931637c5afa9159,
-        0x1dfb20db861a64e6,
-        0xc9d375338abfa22e2116aa96,
-        0x8234ba146d516ea791be9ce0,
-        0xd33f16df432e57,
-        0x71bdf1663730306e54a2eacc,
-        0x6f9912ee0a807085df2fec,
-        0x267d34855df24814,
-        0x6f9233478295adfea1b2bbe,
-        0xdd50db85e107971f3dd55610,
-        0x89dcce240a4476e,
-        0xea85880740e56d811740382b,
-        0xfb8a1ffc83782c0718247827,
-        0x17ed4eb0c12f6ab7,
-        0xd4b5d63a39b2a52b15f04d62,
-        0x5e144fe35dd9d86c3c93d1a9,
-        0x23d7f3460cb28732,
-        0xfda66b8cf2207d45b4c633a0,
-        0xf1b54f1183b15b63dad3a7e,
-        0x2128e435775df97c,
-        0x8f72e4c0f7fd2e8f20a0ce35,
-        0xe962e0c9c96a3aaf544f43c8,
-        0x3635fe59a69298,
-        0x113180ef1cc5b9fe5423db61,
-        0xe2a6d061d8a1b3f518bd79c,
-        0x17d4cc9863bcac73,
-        0xb0d46b5d8206c6c537355107,
-        0xe45e0f015ea34127b9532886,
-        0x16a53c8802593cd8,
-        0xe3bf7db3d1b85db04e59cea1,
-        0xb50d4dcc6e17621a93eceb6f,
-        0xe9b3faf743ab647,
-        0xbfc525b94d0fa9414cbf0f2a,
-        0x8a88841d5252328a59350e88,
-        0x23fcc66937742df3,
-        0x786c3d5612e39980794f3657,
-        0xcfbbbbac6d5b258afd825a27,
-        0x21ff1336ae737b9,
-        0x2a427e40885ca872f95cbd5,
-        0x626debcc86c435c28a39910d,
-        0x1b3fdf2abef64643,
-        0x5e0780aa6c669363f0699e10,
-        0xea2eee094860b571708f8e8a,
-        0x2aae598397a77ece,
-        0x802400fdeab10a5c2d23dc02,
-        0xc87d6d1e2be2e28ea69191cd,
-        0x1de0667bed6c0a62,
-        0x942d172735be90056260a447,
-        0x87de331cfe3c980e289c97a3,
-        0x2a7a49a5f1d026d9,
-        0xb3863c092faebac7501b8d27,
-        0x2764f64c9caad2bf34124fbd,
-        0xfea993ca88b8a97,
-        0x48831c6501925bd4bec7a877,
-        0x699f5c35e1407218c23160be,
-        0x29b195ba8856f578,
-        0x96c113147e3262ffa7ffdff,
-        0x2fe4a92aa7f0031f4c17c922,
-        0x20bd4afad9cf1b62,
-        0x80d759b471220ee15209f986,
-        0x2a14818739eb52c7899efba6,
-        0x1a8fd097319c100,
-        0x87ce82db6bf968c2af7b08be,
-        0x2ceea5f5649beb5351aabfaf,
-        0x1fae7aad6b8f2995,
-        0x65acee50e320f2bba02bc46e,
-        0xed3bcfd9503aff31ac34166e,
-        0x255a90c6a867f541,
-        0xce40884d9feb721de917d3f8,
-        0x4014ddd5e00b0ed03a710d7b,
-        0x1284715b4cc1e0b4,
-        0xe637e3e2ce5e9be585603173,
-        0xba761e8e078c5b7cbe2c54fb,
-        0x1e91933c82a1686a,
-        0xefb81bfc23efce6a80c00131,
-        0x44424d2f3a587e615034f9c2,
-        0x26219b8b9622fc67,
-        0x1e6d6ce7ee6024a627fb581f,
-        0x78f8fd88991e449ecebe60dd,
-        0x470fbc2b65d586c,
-        0xf74dfcbc1c8407315e24dc16,
-        0x50a929653f7b33cf7312d754,
-        0x12bb43c1acd306b4,
-        0x55e502ecf70de62128143cef,
-        0x17947ef7b17f418c93efc387,
-        0x272632b7556a5ff5,
-        0x3b40d56f61f6a4c0ca67a2ef,
-        0x4f8c3b93759ffc08b478acf5,
-        0x8b64d29a82e231f,
-        0x257f7ec5f300c86ad9ab2858,
-        0x67a220a3ca4c3d1e72d15773,
-        0x275dcd1a7918ee65,
-        0x393a614dae5170c473591d2c,
-        0xe7abf2d36e893215a221ccda,
-        0x162010eb943b931a,
-        0xd0784f28bf57c2aae6916b55,
-        0xb740d8c4352c61c4de757dcf,
-        0x175f17a8d8ae4753,
-        0x47ce02545a0dfb6e03b9f663,
-        0x45304f4d0d24306ac97a2fa3,
-        0x1989b01508d8d8c7,
-        0xfb3be3fe1556a1f01bece836,
-        0x4f13533d34f0f8b6e3fdd01f,
-        0xc3157fa2e907128,
-        0x5172fd6f8cd730c3e3e82f80,
-        0xaecfcea278019eb70e2d3f04,
-        0x146fbd7d3dbf318c,
-        0x8fdf423d65b4a6a6626b992b,
-        0xc03b6357e1270fcbb33faaec,
-        0x2c7aa6883781d01a,
-        0x1f1fbad56e67b96910aa9f8c,
-        0xd8af88c9e6d6801c5e6110bb,
-        0x2f38c3062a6eabc,
-        0xa1894a761910887b60503bfc,
-        0x9463e12acfdc2be6f149d0a0,
-        0x647d1cbd8544ec6,
-        0x5f354c396642a027b48b08ab,
-        0xc222ef56e1d652f634c356b,
-        0x2af57668e6f7cc1e,
-        0x76e6a4a21023b79adc96bade,
-        0x592b173c89b14af33834d413,
-        0x271a3b617c6a27a5,
-        0x48cac707d55297bec98dc366,
-        0x85ccfb52dfac466f66085f0,
-        0x1ef8c35447ebd32,
-        0x838d1b77191feea201241f0c,
-        0xa9861ed6660fde48a45d99f8,
-        0x1ccd90ebb3e54956,
-        0x4ce718bfd40e00b64371a4c8,
-        0x59205d2c9ccc6d2538ca735,
-        0x2fb0b99a6a26e7f1,
-        0x44626d890a93b8edc335d055,
-        0xb1f8f74b90cec693bdc86dd2,
-        0x285e0736b2b9a6bc,
-        0xf0e2a0f523865002e76387,
-        0xecb05557d1ea911d16ae0475,
-        0x1a5381576b4992b9,
-        0x8e4bff3cce4c751d6b34e510,
-        0x8f1925d174b46fcdfa61f922,
-        0x21f6642d80c31387,
-        0x146a1bb0456a32776b561b0d,
-        0xa0e94c644aae507d469dd6bd,
-        0xac08509e9a28ff,
-        0xbcec6b63f6e6ed8b6bc626ad,
-        0x97f5e6df704b0ed8484c1f59,
-        0x2c50a557d2ef51a8,
-        0x17cda9d5274a81bcdc386e48,
-        0x3fba496ef4f19447ab8310d,
-        0x185aff2579dd20a,
-        0x4d96a5ab084ff575a85925d,
-        0x14758f843b996eecc6cec260,
-        0x1799ca250dadca4f,
-        0x26c54d17eb69620786220b68,
-        0x3c210463d0bca144b900b4b6,
-        0x1a746020a813b469,
-        0xebefb8f7c8a1a5a44d416f84,
-        0x33c08059fd68b6b586bd6320,
-        0xb9e261484f1a12d,
-        0x921e6babd603b6a362c10da,
-        0xb607ec128a6525ac919fcc8f,
-        0xa018da5d469a7b,
-        0x408e0de1494264833eb88b6d,
-        0x92ca3e4231401e14614396ae,
-        0x5c002c4b7431c34,
-        0x91613340b6be856d19c4762c,
-        0xaf0532845d4b99e9cf9861d3,
-        0x1477bb3982fe1656,
-        0x249e0c3249c2cb22cc989085,
-        0x7b905e5dd9c9de634244600,
-        0x1ef2435ee4bfc37b,
-        0xf63d18d143ca21786fbb7ff9,
-        0x65bb24a33318c001b8a66d07,
-        0xab165cd64ee2f2a,
-        0x1690436378f5901b36cc7e10,
-        0x9f6bef353b22c483c48a93ad,
-        0x139e8aab7d59389,
-        0x965cabc1d704882de2dfb54a,
-        0xd9650b3e551e5195bffa825f,
-        0x11228537c1ca7700,
-        0x36d2ac77a4b9096b4cd97e62,
-        0x52b0c3339ed618121c7fb330,
-        0x96d8fba1feba548,
-        0x54290452e709d916c34fa8a7,
-        0x5a9c96b503b1cc1b2c5d263b,
-        0x1e18e7e026e62572,
-        0x788b463546613e200252731f,
-        0x7f0e4130c48f106f86da9a2d,
-        0x22213246f20252d3,
-        0x16508b017da45751d3e3a0ad,
-        0x94962745067ecd4c945853be,
-        0x1b27af967cd8ac24,
-        0xec225373f582331bb3c39c0e,
-        0x3cd5afe10175e7b51dcef2e6,
-        0x10e4264ec2e71674,
-        0x3e0e92350601669bc696261f,
-        0xe71ac4fb8337e8fff3d5a0ab,
-        0x94f6ef79e6fe056,
-        0x73c44d4b6b6c2e7b90a4d9d3,
-        0x4d7ff9b5ce51f7b2adecacd5,
-        0x69ba7081ddefcb,
-        0x4beeb8d9f2195ea0f9df1b7f,
-        0xa448e7727709fe6a4bd09dd9,
-        0xf928cb36a37da07,
-        0x60e79dbf948c9af5ebfec800,
-        0x3a1ba846506f7800136dbd62,
-        0x215df113925d9847,
-        0xbe24d308a83f152825a72fb8,
-        0xd9aff94e3a7722054883063f,
-        0x2111718ef498aef9,
-        0x700b9e5ed44652afa7a8d7af,
-        0x2211bced38e8740bc95ac018,
-        0x2f58676cb2818cf8,
-        0xf560aa9c58ab25d8fb397a66,
-        0x64d2f0c52aa391b6792efd51,
-        0x26d5930c6c62c843,
-        0xe548ce572b3a0f5b5f74c57d,
-        0x4add2444f0adda7d9c48ff78,
-        0xcf59475ac28cfd,
-        0x48794316344c68d3995bda4b,
-        0xfe992c437089bdf3067ac54e,
-        0xcb9b5fdcf7fafd4,
-        0xa6efca2d1511185ee0d1991,
-        0xe5efdff78334939b930dcd8f,
-        0x2b95402772fdf92a,
-        0x8592fc1554759cf486f9e9e3,
-        0x46a4b1f52c47a9c12bc78c74,
-        0x16c8899506f6d29a,
-        0x11461ccf374f2d9333e0a631,
-        0xf4473ad529e6874432b81419,
-        0x2a276b5f5109914e,
-        0x2ea3771f9b53ef0b8e2be517,
-        0xef2827dda3e5c20b5b62481d,
-        0x95e82b63ffbbaff,
-        0xd84a51b940fade9946929b51,
-        0x1ee659c72e8e98a2454aa5ea,
-        0x2539d556ea843658,
-        0xe700024f6f302c5173495043,
-        0xf304a25f66b4a2bae4d0bec8,
-        0x16af13070d7e3679,
-        0x159b21c62da6df6830009725,
-        0x1d4d66917def502e8414112e,
-        0xd6f61f1576c055f,
-        0xd8dea28510bbfb27d675b624,
-        0x862f53f8f7d08d726a13199f,
-        0xc8a73b7913f517c,
-        0x4b4b53e0890d210bff5d999b,
-        0xf4074cc2f601d0f3dd7d4ab2,
-        0x2cca098cb820a5c7,
-        0x3dd4fd363aa1dc8a83756916,
-        0x2212454792c1e9fd47f194b4,
-        0xaa67f5898dd5bbd,
-        0xee7424b99c7ad969112c604d,
-        0x463433b52886100cd02221d5,
-        0x2f1d91df57f6731,
-        0x8fd3a6dd0cfb492465ac14d5,
-        0xe49219484cc387065f0eb03e,
-        0x2a8394b816e1b348,
-        0xb72931d6ea2600c54b584469,
-        0x41a35e3d1fab1507804230a6,
-        0xc13b20871a273a5,
-        0x3fad7ac009b1b7d5a375e53f,
-        0x5995c45e6084788f7e55fd1f,
-        0x1aef2c09a5dcc4ba,
-        0x40b985a90a3e09be1ad4943b,
-        0xa29314e0813b8eeff653f0f5,
-        0xd028641e40edfb0,
-        0x9849bcc87f51002822e2c954,
-        0x3dba85b9532a0a12fc277a49,
-        0x23575f30c5a625fa,
-        0xd922dce2853f5b61b8341a40,
-        0xd32b7f30cc2145d5d7a8445a,
-        0x202ad5fad11c66de,
-        0x15f44684e5fbecd9939520ea,
-        0xc618fdbc9aa2fb35b978f76,
-        0x195a817b8ba048b1,
-        0x4daa7409e60403e5df2a734d,
-        0xbcd92fc51d290829bd983d75,
-        0x91952e5f5a74fc1,
-        0xd41a6415679bab9f61dab704,
-        0xd0cd312e8b0fe54867826f3d,
-        0x2367d4e68dd9c3f2,
-        0xd31f5228404e95118b61d965,
-        0xa3940a02d451f03206e0c7e4,
-        0x23b1a029070344a7,
-        0xb7a490bc23351e2ecc663dc2,
-        0xf553c6be86a6010b40fcaf60,
-        0x1622380c01d51dd6,
-        0xec0f1386d0bc4b4fdc883cf0,
-        0xf5b7bd3fcc20cfa71a21e840,
-        0x2b2c801816f34f2b,
-        0xdc2c557a679b8c9fc8ffbcbf,
-        0x51305bf12951fb6cfb02f933,
-        0x5c0f1da680ff45e,
-        0xab2d953e3ffaa2ec98de4b6d,
-        0xd8c487f0868660a202d8cd47,
-        0x15e0ae8832cfbc7a,
-        0x7b8838e0f31d096dcd249d4d,
-        0x864e02c4a3ce997c478d8545,
-        0x28780071fa3d46e5,
-        0xaea9c9dffb05475503f72c75,
-        0x9bc716784cf64fd5008e4bb0,
-        0x5a77d01e8647587,
-        0x22c4eb4e0733b682cb95425e,
-        0x3b09576f80c616af6ae5d40d,
-        0x12dc80f56468e891,
-        0xd6177dbc0967afe9374f41be,
-        0xc8bc880a029446e99e23da22,
-        0x173abb4b7b5eef45,
-        0x287089d21312ff68374917cf,
-        0x63570e028f5156299d76f074,
-        0x131f1de7b8077d5d,
-        0xe1886dfb835b41301272daf7,
-        0x7d8e38dd8efdd058b07059,
-        0x61edc92aad8543d,
-        0xf19d67b09e95aa45c79c4001,
-        0x528ae35956c732e6d372d6f8,
-        0x9b15b23073ba598,
-        0xa831d7bd73ca10c686092c7,
-        0x58ff915aeba55f1e04a9c88e,
-        0x1590b3cb93cc7aff,
-        0xb2559af3b360bbf18693ff0,
-        0x364eb8ae8a08f9ac09e324eb,
-        0x2d75cdd3cb8bad11,
-        0x6ad2ae2a45656c776a0560c0,
-        0x63fb527d28265b6c225d85aa,
-        0x28f874bdaea610fa,
-        0x1303d49486d13bdaf65275de,
-        0xc8489526671f242693f8fcab,
-        0x26290679b234c1f2,
-        0xe1a1771825a641ef938afda6,
-        0xbee8b203e8313815683d4055,
-        0xd1b2d2bb8ed7b8a,
-        0xab5e97b6c3723d130463c70b,
-        0xd77d1f8d4d85e2295b400ecc,
-        0x274350fd268b7bd2,
-        0x251b087bb34ec70c6deb8b57,
-        0xe41dbe58475d921690482ab4,
-        0x65b66b5618c856b,
-        0x4ff188033dcd159f993fffca,
-        0x35823309b6b72c789f6e4576,
-        0x241e9c174cf3ca9b,
-        0x3ab1adf00ae2df3a792cbe80,
-        0xa8f986541373cd33ca63df23,
-        0x30406d5129002722,
-        0xd75cad129d93294ce11583da,
-        0xa97d16541e27e635f7ad2c05,
-        0x2c7f8f74ef2d292c,
-        0xa2c8c7e1765e9fb64eee8f6,
-        0xef430fe192d28782f652ad24,
-        0x19093c4fe0e8984c,
-        0xacdc9789dbe8d4317a0fd1a2,
-        0x8ab69f9cc9601bbea6bb073b,
-        0x313e81f33020843,
-        0xae6527be58e7246a38ab3c8a,
-        0xeafdd884553350e19fdf541c,
-        0x1c728510aaaed89e,
-        0xee5ec6a563bd50e2973401ec,
-        0x5aa8e14db6acc72593352def,
-        0x3e80213710a7170,
-        0xa23943ea891906242c857e00,
-        0x37866323d1afd9c20713f02,
-        0x11ae1922e9e13181,
-        0x25cc13c3ae46393aa13431,
-        0x9214dd5cec264ebb6ac33ce1,
-        0x2cefd8aac3330abc,
-        0xec0705911b67a107368b8781,
-        0xd81a80d7b8499bf9fa73ed83,
-        0xa1bf5852025d297,
-        0x7f3e621e4e5d327d3ba89f8b,
-        0x5668fe8c0d5ab1a07ff498,
-        0x202fd5d3afb94078,
-        0xbcb1353ef6179fca4176d611,
-        0x52f729d4b18e930ea23d6c23,
-        0x1725d4f50a35be07,
-        0x38adab8704ee7ca6fed31ed5,
-        0x909a6d305d0509f435b1d20b,
-        0x119ff568af1c7d15,
-        0x16960f56d394f9bdce4b5aaf,
-        0x6110c085fae8274b5d84557c,
-        0x7af673983fffa74,
-        0xc6c57d6ecfe8679b28c00c38,
-        0xd6c9944c690bd2be230d2fad,
-        0x16165d9c1211d849,
-        0xabc424955686c87b1448c5bf,
-        0xb66a5873b14aba9a03a3c803,
-        0x11ab0dc36d40465f,
-        0xa1750948ecc60d92315a2a6f,
-        0x1a256d9b800d8bf7cf907628,
-        0x2d6d8a8be1f89c5c,
-        0x87cfdd3b1f7c3fada5a97440,
-        0x2773abea85808eef628cb3f8,
-        0x170f8eb203b506e1,
-        0x4144e0c3fcd2b8ec49a3d219,
-        0xe051ff61a509438284be61f3,
-        0x19ead35b1b95f311,
-        0x76e90a6659549423510b97be,
-        0x4eed1745239c2055011302d4,
-        0x543cd90447be751,
-        0x8faa1ef5ad2fbf6cddf4ae99,
-        0x1fc2cdf79fa5198bdeaf3454,
-        0x2243ee36dfa274d3,
-        0x4887a7811611ea4612570e4f,
-        0x7f82c9affeb25f7c0976805f,
-        0x1cc077710deb5cfd,
-        0x532cc339526b9cc6c76b9c2c,
-        0x72be2e74aded0195c42ba8be,
-        0x1e55eea3ffca2ce0,
-        0x7914714203ac3ae765776ef2,
-        0xa9a44f1d041fa152e660996c,
-        0x275927caab46ebf0,
-        0x67a462b107524dcf5803ca79,
-        0x881b5211f8032e48879dcf77,
-        0x61025a394eea5f5,
-        0x9113148d581a0ce660976435,
-        0xd2c1e178adbb627ef22933a7,
-        0x1958d7fcafb4f329,
-        0x8c5750424f6106fe52863cd9,
-        0x32af3d60b0f4867006ce9010,
-        0x7a45a5834592fb8,
-        0xc7e36f58b9dabd3613b99d82,
-        0x16adfaaf30e0783f97f980c8,
-        0x231764054fb30707,
-        0xca5131eff8ed2d3a796e959b,
-        0xd4863346ed877a34ea563801,
-        0x23b10db2ee72ef0d,
-        0xf73d7b9af8ad7cd7da0c138a,
-        0xa89811b74a4a589aa644986a,
-        0x707ec76dbf64016,
-        0xba0e325327aefea699abe4b,
-        0x3a5cede3c56f44b5b017dde0,
-        0x2a743443397d8e45,
-        0x99578b0e84c4717bc5de1d3e,
-        0x13067e57d79ae69687ca4d02,
-        0x3c9c0fd32f7e241,
-        0x82babb74c8c17d86ad91d588,
-        0xaadd2e615d763e0b48425d0,
-        0x5b4428308539a1e,
-        0xeb58b7633b0cb0d09fbb8016,
-        0x3c02c5383eb0743bb3f2a2c5,
-        0x27de81d6566ff732,
-        0x1f690888254bc66636202be,
-        0x218c674bd4c7a373f4dd7b9d,
-        0xb12d7fcc26f19ca,
-        0x279ef25d30d4c806560ccb6d,
-        0xae1ccd069f1feafb822b3f43,
-        0x1fad54603af35c59,
-        0xb7dfa05e2f24389eaba1ed17,
-        0xb78400bddc17704e5af9f6f4,
-        0x2d8bfc4b7756ce4b,
-        0xb1a3a3948fdadcefc09dd6dc,
-        0x88f53f01c21210eaa5e05d94,
-        0xb58e1e461c9858d,
-        0xe27cb06095942ca350704d1c,
-        0xea9bebe0d209ff1dfc312927,
-        0x2a068073ed9e5948,
-        0x30e328265c4864e63c8e0c86,
-        0x39c63ca21f26d18534cb8b10,
-        0x19e082e493a6d92c,
-        0x4f8cb60328a835b9ef619522,
-        0x2c1cf27379eb4bd0a6c46e31,
-        0x2c7e0ea810da240a,
-        0x2a1fac95286dc90bc3ff08d2,
-        0x86e4c6e9310ae39f9f30ef25,
-        0x185959944d4ebcad,
-        0x783abe842c2e5dfa9049e40c,
-        0x9464c31a7eccfd448c06b9d7,
-        0x2209fd621ecc059a,
-        0xf46e36229d68cd306dd756c6,
-        0xf0cde451b1243794e23e808f,
-        0x2876901a4e08b020,
-        0x247b1a98375bb239cb191fc0,
-        0x85bbd9aaf6aac5f04f7def48,
-        0x1894b60bbb87d702,
-        0x842ef9b64dae55f988e13034,
-        0xad7e892880f57835e93b5db2,
-        0xd173cccf42a4e4,
-        0x76390e99c06b469cec88c0ac,
-        0x8cb8b54c1c3dabdf2996e4dc,
-        0x1cdececfa2b9c11b,
-        0x63af5c5b2e2b19659c3814c9,
-        0x2078b30aea4a2dc44401b7c2,
-        0x1beb8c2bc5f5ab8f,
-        0xff11f4fe8e5dab38887c4b02,
-        0x3aa63429cb6035ad536d9dfa,
-        0x2c2230ebf0638108,
-        0xf2e340d27daec672c51c1171,
-        0xa68b53f6b1d5eba577aea0c5,
-        0xac0f35dbc7b82fa,
-        0xbe0a4c1dc7ed6d33bf485799,
-        0xb4cae906eb38d33d11cc49d1,
-        0x1af7b0e6d08fd809,
-        0xf9bdd37d4b2e82883d4185a0,
-        0x34c1a8828984fa2d10ce86b0,
-        0x25c71b10a90b979f,
-        0x82ecba41cc668750a51e0e4e,
-        0x5f7bc3932e7a5b781461e19b,
-        0xed7cdc8be144267,
-        0xcc75eb1b70b878ae21ccee9e,
-        0xb1c6cbbeb5e2327dc7ba8acc,
-        0x18c22c148c3e09ad,
-        0x22afe8b66085056682c7171c,
-        0x28b9add6c6f05b6452e508bb,
-        0x2f787fb241554036,
-        0x73ce6b4afb1e800f73a56db1,
-        0x79c05879305c04aabb5541e4,
-        0x2b7234179eef21f8,
-        0x471be9c9ef65e99fbd6643f3,
-        0xbb2dcdf0a587d8df2fdc45dd,
-        0x2420d91c737909dc,
-        0x2758907188e027be86f3674e,
-        0x404b2cd8ccdb5b2d764e9855,
-        0x5267f6a421e7b38,
-        0x623b498cfabe8700bf33aedc,
-        0xf5191770404ce5de4dfbab61,
-        0x7bddeb92c2d51d3,
-        0x61029ab442de6ed9bed1d26b,
-        0xdd7993ad909a65654fe67a8b,
-        0x2294f29b2fff36f2,
-        0xddfe33b242b4e9e0763d60bd,
-        0xb2e46c5e8c45493cc986b5ef,
-        0x9ee99b414cd3fda,
-        0x1c8a8f129d7e8b1e32f5ac66,
-        0x3076e2366e589b3033bb5bdb,
-        0x8ce7d867c991f2e,
-        0x655bb76b77c9e458884d1ba4,
-        0x5472bc4e06dfe0b87cc36ce5,
-        0x1717d25448e49d10,
-        0xdfbab643bdcf30bcbfd3b347,
-        0xa925c493219fa71317d6a66f,
-        0xdb116523ef473bc,
-        0x5a76f65e39ed9d343d36dfc3,
-        0x33de895f3023a3fc27d398f2,
-        0x4656dc50728163,
-        0x7bb8dbff5e9397ec7097b230,
-        0x4ec4f57adae1099ccf82bce9,
-        0xb46967a3974aa91,
-        0x9b07aeed2edf7ec7c8191a0e,
-        0x7217b77df13be308852c208b,
-        0x216205d9d27ea094,
-        0xb9c9bca191d1bb14716aec1c,
-        0xc6fd62d184843fa5fa97d665,
-        0x1c7a399a70d94202,
-        0xb5b19952f9e9eaa508e1f7fd,
-        0x5f272c057214d48f3fd485b7,
-        0x24ccec73d182575e,
-        0x17dc2af9947ff53b3794377f,
-        0x55675d6bd082c4d7d1dd4f15,
-        0x1044b7976e46cb5f,
-        0x3bcad1255ed7c0448f273fcc,
-        0xc9a0e39bb6824bef3487a0cb,
-        0x18d49adf1409a6b7,
-        0xa679d591fe5a3c11ee131174,
-        0xdce44a0813dd06c5b98a34b7,
-        0x1d25f1f956a2025c,
-        0xd109a888c54825300f8d1261,
-        0xeb158b9c428af097adab8157,
-        0x2727b50cc1584495,
-        0x7a46305db1583d11513a1c34,
-        0x46d7746858b343ed1584745c,
-        0x1c035e75198aa577,
-        0x35e9dc36b6221b669e2b0bed,
-        0x8dcf9f6b88a852f661ea0a1,
-        0x1299e4f52067d06e,
-        0xf602d672016c24cbf242eeb2,
-        0x7fed00e8adaf6d0cead03f76,
-        0x1680ef8a1d476960,
-        0x7b7818f641e79dab55d5e59a,
-        0x2e7304a04f208e49d59fafbf,
-        0x8f86b6dbd1b729,
-        0xbe6c2a050c47d73a723beefb,
-        0x6e36925d03775733faec4281,
-        0x2b2032116aad9cbf,
-        0x4fd798103ebe21b1a70dbaa4,
-        0xd842014536a7ba7432232ca4,
-        0x5e36a01d19ca098,
-        0x355e112796c491d3018571f8,
-        0x6fe69387dfef098af85a9c21,
-        0x2c81c68ac557ce2b,
-        0xcc1c8568743712b13938ebce,
-        0xcfff9ea5b9566bf8e33af736,
-        0x13ebd03b3baafe1d,
-        0x5347ad3041a28e8053c68149,
-        0xc1daa2ed88ce7083d9f56945,
-        0x12b3db4d04f14058,
-        0xfe26affa1afb641d7f0b1575,
-        0xd48f9ea318afd2b6f4d580f4,
-        0x88548871e1559bd,
-        0xa922d15c3884a9c5272900c5,
-        0x24dadec04318f4d0ca4fde24,
-        0x19d8ae0b2e07e5e6,
-        0xa5c6ce80d394bcf5669fe0a7,
-        0xbcfba6e2a82a810d76591591,
-        0x1fe6d5cb85e0e18d,
-        0x4f93bef4feb21a3340a1d3f7,
-        0xdf6e42d8fa8c27b6858045b7,
-        0x2708c9c0a8903f18,
-        0x4e036ab0882abbee0fb4721d,
-        0x965ac595b6391e3f6bec2def,
-        0x273e516fea522691,
-        0xe2a273356104112e2bc8ed85,
-        0x18c2bd14d49035e464b72dec,
-        0x19b6f696945691dc,
-        0x8aea49cf192d1148af5443e,
-        0xef6e753949cc84f6abae55a9,
-        0x216e863fc0c1d176,
-        0xe7ce2652447af972a8adb98c,
-        0x1ecbc2e95a728c0b546eba50,
-        0x2c0215b572a8b497,
-        0x31d3b442a6a70ad4c4a182df,
-        0xbf9512154edd628bfa64c2e0,
-        0x24e49daf67462b45,
-        0x4687ca9ae498bd1db78afebb,
-        0x4d03ef3656a7ad3d325d4059,
-        0xf206fc894482f31,
-        0xfcf55bba59d36379f5d02a4d,
-        0x9863f1bec69f0440ae02afa8,
-        0x2ecf30a35b077cc2,
-        0x80837e9e6c4a6bc88f3463c9,
-        0x35626d9be290380b3d55044e,
-        0x1d78890c26793ea1,
-        0xc2c394b84405c93781044da0,
-        0xf46f246eab57b48f58d25fbe,
-        0xffd422f24585529,
-        0x98e5eee59d054a537d66d3c2,
-        0x6cf1e59152ab7bb8ab039c96,
-        0x3b5d3eacb352d83,
-        0xc488e89d4990126db298b411,
-        0x6aa09092124a47b095d9509d,
-        0x1682aea611f93ea5,
-        0x52a3d0b9fc9eb86fee87d764,
-        0x6f7ee7ed408c9410b87f51a9,
-        0x24e3c208fa061685,
-        0x472332d1579e5abdecd39062,
-        0xf414a6cdc989c060d39ced5f,
-        0x2bfc1494cc02245a,
-        0xede85338f770ba2fce9501d2,
-        0xd9ad6bd44941eabce85e2f32,
-        0x22fa436e0a5fdac6,
-        0xa0d05831a6228b0ca2442820,
-        0x90d2327c4c1429f173b410e2,
-        0x1060293bed2d9917,
-        0xe0653f24946dc533c5b6bbed,
-        0xcf535914230e825c15860602,
-        0x1740c525ac89f9e0,
-        0x1982f0d14dca46afedcc8246,
-        0x910a4eb4729f8807a2c62cab,
-        0x1211e027b4f0a51d,
-        0x3d695887906cd0e10761c589,
-        0xeb9f04609764afd4c1e267d7,
-        0xb0c0125bb262eb0,
-        0xa2b30d4a97bb5dc1a6342997,
-        0x204aeb519769eb04f24ba302,
-        0x2c724430d00b6c05,
-        0xbab64a313c0c7ee6c5e9190b,
-        0x364943e0e876cc3e303da4f5,
-        0x1c3bb20a2b4b82a9,
-        0xcfbc585534fc977b93c18458,
-        0xe357c99791de7d345aedd815,
-        0x15b1aafabea43adf,
-        0x88cc9f9f95222fa20395c769,
-        0x951037de6156cb6fb32c2e59,
-        0x287f403a1b812af3,
-        0x7ac86ce9bb9c13764a18e8b,
-        0xd11707f4d370c7b1d02b447,
-        0x4171193515fce24,
-        0x3398a48b1b848abb282c4367,
-        0xfb7ee76aac4c28890717792d,
-        0x188c6ded3b3d2eba,
-        0x7a861a63670cd47189ac11c8,
-        0x97669c67d55e0850bb487fce,
-        0xabc3e09b5e143bd,
-        0x111cc3e58e716b0fd91bddbc,
-        0x7f713d109cb3d57478209290,
-        0x137f751743ae576f,
-        0x88c81affba8831ab97c967f,
-        0xe24c88bedf1f1315305cb4a3,
-        0x1a3d913875f2e9c0,
-        0xa117ca3095265eccc8db9b83,
-        0xaa8188c8491ffa2abaed5af9,
-        0x1a7b956ff67168b8,
-        0x91bf52360905a72c644b6deb,
-        0xbacd036f236a9a1a216570a4,
-        0x1d08ed02c4e5094a,
-        0x79925eb1a4e825037a5743fd,
-        0xcbce92cdd003f0b17b880c1,
-        0xa915d727e2df6e9,
-        0x4a83bf43c5e209b5f553b563,
-        0xdfa364dafdaac2d8ebbf4916,
-        0x23a56e4e414b4f69,
-        0x7f62555c2affaeeddd6acb31,
-        0x8601ad4429633799ce9af328,
-        0x7ae352c511d9d18,
-        0xa68e50fb9c67b1367bc6e97,
-        0x8435ef3b1b6299db483f3945,
-        0x1a3b8f04f72850ae,
-        0xa911eaa952b373158a8319b5,
-        0xce017b2901e442275f4c5302,
-        0x2486f6280c7581cc,
-        0xcffc0cd5e956688fd39d88cb,
-        0x5eb83f6c5d8094d1445b1812,
-        0xb45ba8a4e5fc3b5,
-        0x72e732c53825858592cc13e8,
-        0x5212f0104b2f8eb9065836bf,
-        0x761edf4f2462da0,
-        0xab5753efc534c8e49f192ab7,
-        0x44dee3b8ecd4366ba64e3317,
-        0xedb8df15232e446,
-        0xa7330faacd505693b0226ec9,
-        0xead56661aca84840727e8e16,
-        0x5491187ef1df72a,
-        0x223513461659d8e665b214c1,
-        0x33fdf6b9ce9e4098deecb5ca,
-        0x154d07ae142167b,
-        0x71415138f3f2938d960f0bea,
-        0xc70547074d7be0759e15f9b8,
-        0x30eee76c8f4468e,
-        0x1f344e751ad704d540a35944,
-        0x971ee9961ef50e25a58503c4,
-        0x2b451e8eea07f6c6,
-        0xab4cabffa3883405e7d2681d,
-        0x604f71bff2c119aed2aac1e1,
-        0xbef82007fdab95f,
-        0x91805563a2bf781d6a6ca2b5,
-        0x22fdda65874341bbb960cc02,
-        0x27fcd98c625b0178,
-        0xd6d420b6626ad8b4d194251,
-        0x6b9506197a9f98acb5196a76,
-        0x18633325f9a241fb,
-        0xce53536dd0906f7e0e64c91f,
-        0x8e5f5567834f45c0dc715c0d,
-        0xe1d7192058039ba,
-        0x85972f3f806bc836a6ad48b6,
-        0x71e61112ec411f3c1cbedbdb,
-        0xb5a9b4fc2210eea,
-        0x23e5bb16e40422b516719d90,
-        0xf4328df88ca16d8b2813e7,
-        0x1272e4e798d5784f,
-        0xaa1c52ac48940c494282ea61,
-        0x98f3c34d7e8535e8443ce515,
-        0x1c79c75c930ea25d,
-        0xf7c62a2a8c26b137a03126f3,
-        0x6ef023a13ab412225d9a6a30,
-        0xfb85377a16716ae,
-        0xff7ba3d74eec5cc0012d844,
-        0x3b5b9a3e654aeddc4ab25dd6,
-        0x1252275c6c15e1de,
-        0x382612b28e7e9a3eebfc5c0d,
-        0xd84e8a521be84c7967e95591,
-        0xe9a26e893c1774e,
-        0x59205866ec53dc46b3e859f,
-        0x3023c672c201868f60c9a236,
-        0x4b40c1214ea20,
-        0x96f6c37cc63bbb1962aae7b2,
-        0x98a40ba4c038128071ab2b77,
-        0x218488bb263e6a7,
-        0xaeba14ccde23016b4d69bd8,
-        0xdc7a8963712a9284c1ad9e6a,
-        0x1f430033a0e3769d,
-        0x475313a345de68ce0d881eb3,
-        0xda4eca527a607ba661d5ba2,
-        0x10feb420b878aeb8,
-        0xbdfc53576a079347a25b067a,
-        0x54679f51b37a1b50fdbeb190,
-        0x1aee600213388144,
-        0x7014b73135a42cdd7022abc2,
-        0xd17b57166da76015e98e64f7,
-        0x134545b48951010,
-        0x662dd5484eabcff9b54bc32f,
-        0x4d0366ab1fa00c75edffe82b,
-        0x2a15a92105f887f8,
-        0x9287f139ca68e849a7092301,
-        0x9be68a1406698e247ececdba,
-        0xff5c49a01b40f99,
-        0x9b1a3ac690fe1a245b21fb59,
-        0x79034f55d85dc7aae2951fe3,
-        0xc03bb2a301d12da,
-        0xd671a94442fd372d07e011,
-        0xd08a25f600e8e808cc7d06d5,
-        0x10c0afec2ed2982a,
-        0x805c07278344d3408ae9434d,
-        0x7a44421a92f44f40a7adc2ea,
-        0x234b900807f239b0,
-        0x581ae63d51777eef08c57c82,
-        0x10660a3ab4ec1c2212babaa1,
-        0xcde726919d88ad8,
-        0x4b9190aeb009604658c4c01d,
-        0x7fe5d3dc4e419a9a85b43ce2,
-        0x2c3ede6858835e5,
-        0x6de8ac55bee25f249f112a19,
-        0xbf47dae28c79c42ee209fede,
-        0x2e5baf788325c164,
-        0xf67f617be494be9153193fad,
-        0x54fbdbf88e7b0c72d84eab2b,
-        0x88b334331af7156,
-        0xc2de9e9030f297d12dadb413,
-        0x82367947ece2d342a8b4ea3,
-        0x189c56f6298c9620,
-        0xf07a96cae2f0a6858b4e919e,
-        0x6d30222199ad031af2985799,
-        0x15e40ea38ccc71a0,
-        0x1286b6390948064cced2b3e,
-        0x31de5427ec0620590cfdacb2,
-        0x205b4a093b29ff5a,
-        0x6f811dd850eacc3cc46d0c45,
-        0x208338116501a410e8009d25,
-        0x1a69d78639c8eaf9,
-        0x9078308b0997a482e19c8ac8,
-        0xbda1054ee33f1c9f63ef7dae,
-        0x1aca8c75d5bf0751,
-        0x37f73c84573763459741fc2,
-        0xbe2fbb14eb5277df37c62e41,
-        0x1e217511fa13be11,
-        0xdcf6a04ac51250948dff7809,
-        0xd6886e8d9e7f23d72f1de5bc,
-        0x27c54258397863e7,
-        0x734e40fbc6625a67d3e772eb,
-        0x9a1eb1772f0a5faedcc126cd,
-        0x2dd9a0e9d5e5ad87,
-        0x5d67360a47ed7e818491cf9d,
-        0x2061afd6b10df6b2b0b43cb,
-        0x9395373e0ab302d,
-        0xb4fde76591b705bf7a5c3,
-        0x61371017c7d593ed979bada9,
-        0xd756fa7d59cc2a3,
-        0x54431e7f0b1ed2a8b0050f0b,
-        0x1050e26993b6c8930e3874a1,
-        0x1951b1c2d980dc61,
-        0x1b9f2f3715b1a30ae708c30a,
-        0x595272794c8785a666771c31,
-        0x10209b3d08293e93,
-        0x43083182ef5eb77cbfcaea90,
-        0x31c4bed1522b5a50e8c4c74b,
-        0x17a5f624b617396d,
-        0xf9c454b0549bcac3de6389ac,
-        0x5e3b2adb4be4ff78a2737e81,
-        0xa8f9477dd869f7e,
-        0x91df36bcf0f5bb250ff36871,
-        0xf731e5c4316a810f13ce729a,
-        0x27a122677e6a6238,
-        0x452bf2f721b23efed44e75f3,
-        0x11b6116e20dda09d43b5ae4c,
-        0x1b23f0dae280df05,
-        0x6e344dfea4cbf31df470b06f,
-        0x23c0af4c5ffd80bc7284652b,
-        0x105ff78957126c87,
-        0x3f9606b500489b1c72422a26,
-        0x881ef332dbef6ccb323f5641,
-        0x6887b625d157152,
-        0xfa02075bcb6cffe8e093bdc1,
-        0x8c9e8e8f557f7ec58b3979a7,
-        0x1f4f63b211bf3284,
-        0x3c0c8fcf6c11aa1b6da28a96,
-        0xc45d8333f4250138c6fefa63,
-        0x262165f63a033d3,
-        0xbc307fc02eabdc7c54b4587e,
-        0x8a0029d3ae0d88a6b9c4de3f,
-        0x1699aea7abd3a8be,
-        0x4511731fa9a3e5f12823f2c2,
-        0x845646ed2474efda65128483,
-        0x1168e05722851739,
-        0xf9cf187b06cb6298f9ebe300,
-        0x675b95bfcce95c9eef2363e0,
-        0x27646447d5ee903,
-        0xb3d38e2a78f034c748bae9a8,
-        0xe0d6a2a388f50cee33e67854,
-        0x15d3821e13a4e003,
-        0xf64e5424ea030ea6946cde9,
-        0x779729ecc97baaf1eae40f4f,
-        0x1c9cf6c67af1b336,
-        0xf4d1459dbfcd878bbf1ace0c,
-        0xdb393a5cba6e28a0f55bbd54,
-        0x5b499c28ab02a41,
-        0xa39a6876cd75566bbabe9040,
-        0x44c62cb0862e3ec74776761a,
-        0x1676a429168c369d,
-        0x4c74dca10fb07c4cc019066d,
-        0x74bb2a308b0cbf361b591502,
-        0x5db2d2cc715451b,
-        0xf138c11016c746aa83268214,
-        0xa5f10fe8d426c40cf518afb3,
-        0x2812f8f889985222,
-        0x62db29676e930599561351d9,
-        0xa4e80d982500aae9177203f6,
-        0x298da6239b7214d7,
-        0x336abd00880af09fc3a100bf,
-        0x7101d98f033ca7a6cd098f75,
-        0x15a1baee35289723,
-        0x8c5070144f6b576ee261399d,
-        0x8a190883b0d328601a45f465,
-        0x116ffdae64ed3928,
-        0x22d084281297678c40c7784,
-        0x1d64b051aee38c29a3ac205,
-        0x28e9a33c6cf1cfb,
-        0xb66e94d1cf39ce32a98b18fd,
-        0x742f556e9fa7e155aafa8556,
-        0x25af23ac3edc7694,
-        0x5488f7bf3513e3073509d6bc,
-        0xa1670081c1d18b33aa553e09,
-        0x824ba679c978d7,
-        0x4395945c86deefd712438cd0,
-        0x9ce047597ee0b19b7d720a65,
-        0x341e3d83297b7d1,
-        0xa1832c12579bea874ed13103,
-        0xfc64bd2a268ea90b276f5356,
-        0x2770c95fadb40f29,
-        0xa714f2d773d9ba8a8e8c14d,
-        0x6bb2659c23e5d7ea3449f1b0,
-        0x1e2dca8163d5e5c6,
-        0xbc96a6ac9f278ef9815bd029,
-        0x56b4ccbe22ed585f256fd937,
-        0x189ffac0bf675db8,
-        0x22378c286672e6e67f70d505,
-        0xdc04ab67d1e9fbe837366083,
-        0x1f8abea51bb9cb2a,
-        0x89296d866b23328bd28a0332,
-        0xb4d7df8c5a77538a6d998239,
-        0xd7fc3258207a6e1,
-        0x5c487154b66e1f8a6819f969,
-        0x4ebddee30643ab51bcf16ddd,
-        0x17231cbda94dcc96,
-        0x749a95923dec860f1992d2da,
-        0x1c1d1ac3c5a7412d162226cc,
-        0x36e842b7d82f0df,
-        0xb60403cb029fe8c2c075fe28,
-        0xd99388c24044b8ad667eb821,
-        0x1a0141dcb04f99bd,
-        0x524edfda38745158140ea3a4,
-        0xc1c8ef1272af9912339e326c,
-        0xd1ac0bdeba59aaa,
-        0x1a221e253f0b9e00a7803f7e,
-        0x5a65ec2523687b7ec2f1054e,
-        0x1ba5ca6a436b8e19,
-        0x976a946faa9f1d4b4b6c97e9,
-        0x8fc0b5a2fc3c0c223513185,
-        0x186b080d6317b059,
-        0xc521bbc13cd2fd036cc8d7cb,
-        0x1d309cf67dbdf13c2702e732,
-        0x2a6a2dd66e3d57a1,
-        0xce2d64094ca7b7876ee991ae,
-        0xd18682abc4809739d1fdaaf7,
-        0x2da92ac3daf6326,
-        0x42204f45800db02b62d5a1e2,
-        0x99f3ae3372385bf3a95dd87b,
-        0xe8b9eb5d5e2f59e,
-        0x8eca37274f2c9478f7ab3b2a,
-        0x15e8acd00e297c8e2770e65f,
-        0x1940a1c01ceaa91d,
-        0xd0f15d066466f67e22a23e4,
-        0xd59c444d5a9d7d3dcd24a14c,
-        0x1e94b7c8e145ef3f,
-        0xa7f9cd1ce3fec03e56bd0c1a,
-        0xf646971c197658b92f0bbd5e,
-        0x27b2e2fa6fcb9ce2,
-        0x6bd1c2ffb69f42d89a70f5ce,
-        0x696cf92b95357a787eb3af34,
-        0x1dd4f8daa410287,
-        0x89c52270e12eb6d41d7afac9,
-        0x79b1d7760f8817810270b840,
-        0x2888f2a2fa6c7efa,
-        0xa580a8047cc5ab25f4b96565,
-        0xc7d1346fcf754bb6852078f3,
-        0x2b728a97f128ef31,
-        0xeae73276f807879e5755a7a9,
-        0x38c6b9b1bf37584db1c3c47d,
-        0x9893ef3a359f4a8,
-        0x9bea09dc6d328247d2e9681e,
-        0xfd9cd629cac816f1d8ae8116,
-        0x2ae64516ad4e360c,
-        0x157773368937b902174f8e3e,
-        0x3e7476d330cad46d642f0dfb,
-        0x26bbc4b4c362f485,
-        0xad2e7b8c5fcb43cbb4603569,
-        0x404cffb4a5d51418e975229b,
-        0x19e1d5e956b0e6f9,
-        0x4338cf83b9aeef569fff18b2,
-        0x481c93849422eed9b39d6a8d,
-        0x266c576c67461fac,
-        0x722067338cee2532f37db5ba,
-        0x7c7a7624237e7bd0dd7f21f1,
-        0x11090782963c0f5d,
-        0x2548a827a090e280d29b9393,
-        0xdf713adcdcf0d174218719da,
-        0x18372e54fcdf94b4,
-        0x2f7823dd7bea496f3b09a2fa,
-        0xb9f1dd3459adfc6a89b2859c,
-        0x16a5b4691b15f54a,
-        0xbe,
-        0xe7216e91c5362e426e2bb13c,
-        0x100fb89d5578a67f0334db,
-        0x19ebbdc81e52a0a9,
-        0x5188355eb6792faa8b460eb5,
-        0xf90532e48ec5f5d3c60f96f4,
-        0x2a0a4f589dd7039d,
-        0x292dd94452dfb22ba7d3f1af,
-        0xedda7f979850677ba4369ba1,
-        0x1a2fc22c9584a7b9,
-        0x89051756e31d9c84ec357eb3,
-        0x589f808159b54223654b97cc,
-        0x1c212df38f10791c,
-        0xb4dd48763d3991d55c45a387,
-        0xffb9494486216fa0f2235b77,
-        0x198d638adbcd8a05,
-        0xd9906a53d12f2c5d224113bb,
-        0x2ad44cf245e5a0399df22e93,
-        0x1a98db70c5b0f030,
-        0x7250c57e740f29a524e9bde8,
-        0x48d18646f4858c38692a3c89,
-        0x286c0af71374bf57,
-        0x5ae19e253231edbf10ac912,
-        0x619ba9d5adacb75e581ab61e,
-        0x20f1e813543a1249,
-        0x436f388df8bf52e0d078777a,
-        0x20a91c5d057586d8798a218a,
-        0x22e799f97beb49d1,
-        0x78c8ae3bcab8a395769bae8a,
-        0x8312a356365916fe95d3a63a,
-        0x1099821304ca3d78,
-        0x4abe5ded679b822196a80de2,
-        0x422f1df2e235cc423d22342d,
-        0xa979ee5a468f59c,
-        0xdf40aeecec9b405f91a95ebb,
-        0xcc259b660eab9d28e7209517,
-        0x6f5aa4821ab9580,
-        0x455ee1d8451a5a825e27720b,
-        0x433d4ec75ae1671c2cfca96b,
-        0x2387ebce549e9009,
-        0x792488c16bdcdb0d1c8f8da6,
-        0xef91317ae7b4e2e778b9a1d0,
-        0x2a769fe64edf5cff,
-        0xd41089e27ea22ba4f3bd8577,
-        0x766fac087e9631514050a31e,
-        0x23d6e0056c95599,
-        0xc9bdbf0c2156d52df946517e,
-        0x9722b4686726ce2a73a4ef25,
-        0x9ef24fb09df78ac,
-        0xeaf77e5a280294fe90779b79,
-        0xd28ecc329eeca39ca6f8c8ce,
-        0x2566ff0a6d4bb830,
-        0x211f0d835c744ac4ea77060e,
-        0x1ff03019190c48b8ae5560f0,
-        0x11e136aa683f442c,
-        0x23fe57bc8f22c6cb1be58dc1,
-        0xabae4dafe43bb3c2b3cd5bd2,
-        0x2a6b3a03e21debc3,
-        0x488587c48c027372c5b7be2,
-        0x6f3d4c739fef367f1ba40b72,
-        0x1dc5a88e2c2c3490,
-        0x55079aafad311ac3e2697f41,
-        0xcaf723479fff50187549081c,
-        0x1ecf9c7b3c21cdac,
-        0x538a4a4ec5668db9a8eaae42,
-        0x8719f2bd1fcf18a2cff4442b,
-        0x60dccd2fe3a6965,
-        0x92aa948ca56683ada129c302,
-        0x164823826130f04669fa50e6,
-        0x206f377a52a3fa24,
-        0x248f90c7f7c553e818f582c4,
-        0xeb576696ea9229c3bc8a6fc0,
-        0x1b607aea65938901,
-        0xb759c0cee5c4654995f287e3,
-        0xbddcd5b168b117f9c13d4c73,
-        0x2dfa249a2b4dbd90,
-        0xe749c2fe10c1aa07780a1c04,
-        0xd877d099f0cf29a7254e81c2,
-        0x48bde204a007228,
-        0x1cdb119accf444bc5491d7c1,
-        0x23ec05146645d71b69b886a6,
-        0x344c9fc331f80be,
-        0xe924569609d91b63a085e8e,
-        0xc5447c513589ec135c3a3db4,
-        0x300f4168e07ea13,
-        0xc46657060da41bfeebed1c87,
-        0x7977c5a76d5efea9ced18830,
-        0x2137925ed55407d5,
-        0x86ad0ac5614451e224982fad,
-        0xcac5ec981f3af997e45cca30,
-        0x25c920318240baf4,
-        0x7a15a9dfa762381ce2a83cd7,
-        0x238da53272872cf283c6df8d,
-        0x66ce4a96a2d50b6,
-        0xa9d97a3bb0e60d98e872ac8d,
-        0x91124e0d71d581073583e2b3,
-        0x2353c137419815f8,
-        0x4f8d5cfdc8e1bc68d0b65563,
-        0x6e9a34b504d4e6caeaf709f7,
-        0x2dc3cded18abf0f6,
-        0x1258612503ce3e06205c1cb5,
-        0xa3df3e935d903c410156f50d,
-        0x1ebd8c033b40c628,
-        0x4f37bc1ea59674676ac071f,
-        0x97733322d9ce08fa65d524fe,
-        0x126a4bdaef21e8f6,
-        0x2f3feb835ad461b88b2b0776,
-        0xb25e5704bebf2d14736b07fc,
-        0x17231b48ce03d94a,
-        0xe0a7547a4e69c8e82b4b28bd,
-        0x79b2d61df335ee845d0b48f,
-        0x1edb34ca19c9f228,
-        0xabe90c563b2b2f1b748c4d7,
-        0x9282433e79135c7e9ea6b5ab,
-        0x1134a299acec6e92,
-        0x805e7d0bf5e48b33e7211e3d,
-        0x240e44056e760d82a5f8e571,
-        0x17de8f717ae21dc8,
-        0x71a63cbc366c9ef2d0acd54,
-        0x389ef232a33949269962a175,
-        0x16c2fdfff24a4808,
-        0x29580c0be76807712c40ac51,
-        0x138c088f0a24576884dfdc73,
-        0x8708135d7b6cdd6,
-        0x8227d4b40d081abdfe854c25,
-        0x3852277eb541011a5f8c2ff,
-        0x1c9ba3dab75d790e,
-        0xc33779fd40b0f7194059e9ac,
-        0x1c5ad6e6f26b910b0543ef8c,
-        0xe91c342a2ee5134,
-        0x5bf358795d27b031dc5402d3,
-        0x8be852dbf83cda0ff2109063,
-        0x198a4928c0fb4a38,
-        0x19b0f892264732a43206b47b,
-        0x63f71464b8320c3a8b3e69f,
-        0x1040802252e1d062,
-        0x77dc32da09b399e5454c8798,
-        0x156a22f78c1e7ef840c24119,
-        0x22eb352f9c5b2871,
-        0x9f29210fc2a8caa8b06ccd0f,
-        0x4cbbe40951d511eeb7a69f2d,
-        0x1f9abc0ea98bf1db,
-        0x512e5318f7b6022730a3471e,
-        0x6536561ecf0b0cbbd2ad73e,
-        0x1dd1aecb741e8672,
-        0xc73b82982e5634cadfaad404,
-        0xb8e564beb2a70701144865fc,
-        0x7c5464cae695a2b,
-        0x7e750cd303b8de1c9566471c,
-        0x68fb7b4c4b9f2e23b832bc20,
-        0x28e124f5be084903,
-        0x56d86cf5e106cb2ccef8e2aa,
-        0x6f91d3368dc6b21c1212a1a7,
-        0xed2d115017b9724,
-        0x2dae3db3b59be06e7ef5c68,
-        0xea7986a5108170ced58ec18c,
-        0x80c3ebcd085c25c,
-        0xd3d36a2ec440a29dbdb06ef5,
-        0x388139e55d05bf50e5598fde,
-        0x166f6b6471a21e0f,
-        0xe5ad7fdad044a2b57e4c339d,
-        0x6ce42a36a068f96fecd4e3a8,
-        0x2114a6f4130d3d02,
-        0x8854026aa6558c0cff3d8db8,
-        0xad7aa9ac5af0600f7566f6c4,
-        0x183ea5e7d022a660,
-        0x3a54338db425729675131e8,
-        0x31330c2ad74ebf68673d4b36,
-        0x131b0497c90d7911,
-        0xf839b5581ef9e853f0015d57,
-        0x35cc6f6f8bd14f54c99cbcfa,
-        0x29b963519afb9183,
-        0x462c7a2ba29a248945864ae,
-        0x1672c3245e75afb2061bf831,
-        0x20c7de429106fd83,
-        0xe642425f2586c8cfe34332ac,
-        0xff45ba2ea5f2679aaad03e2f,
-        0x14cfb5d680b7476d,
-        0xbb149f60b0c4d1885e91fc10,
-        0x5fb48324664062b6736739f9,
-        0x31c1a4456c62fb3,
-        0xb989a87ea588416fc0ac8b0f,
-        0xc619c5d27b14ac3acff650e8,
-        0x17833cf0da0683bb,
-        0x46d778975b13b3552ff3a585,
-        0xb0966506cfcf06fbeefbfe9b,
-        0x289d543120c1116b,
-        0xd694688cfbae5dc13aa21511,
-        0x226b2ed429586c82fb55e918,
-        0x3008682dd696a972,
-        0xd44c7c0340c6b9292259362a,
-        0xd7f61adfe2780e532b963eb,
-        0xb25ac7bfd8a2bcd,
-        0xa57c11c77af33e384036a4be,
-        0xae2cc4abfa52da7959bc6256,
-        0xea29f860089c0a7,
-        0xe77b2dc9564dee44ab332de0,
-        0xf366b9e31fc2929eae953cbf,
-        0x1ba2546beabf418b,
-        0x778c8a435dc1f905052daa64,
-        0xf2c0a1644fb1ffe4ce619c63,
-        0x6d2c3bbd4d839d2,
-        0x989346a429abf9a7deb4a32f,
-        0xe18fd12bf77c5988c18ddce3,
-        0x2382fd0557a70e09,
-        0xca4e86568960c1bbdd9c1481,
-        0xdd2b81983c372d81bdba39e3,
-        0x25550c477dd9e890,
-        0x6f7cc32cbbda276aebb3832,
-        0x370bf3539aeaeb4489f41c9a,
-        0x2cc5b2bf2476dd34,
-        0xa7346ae8a05fea2229266ba1,
-        0xa323762d969a5136aa0b2d2b,
-        0xf6431d700cffee7,
-        0xa94c937dbe9ca3d582dc76bc,
-        0xca2b90595caf9d95d896d994,
-        0xac1a8d4036ee8b9,
-        0x516fb75614c0173582c6590e,
-        0x671c94d1137d9e087f48ec36,
-        0x17f6a8af113ceec1,
-        0x81b930363866cedf9293cbe6,
-        0x1d7bf1724a1727155035fdb1,
-        0x12a9161ffc54674c,
-        0xc190f6964a8dc8f8eed3df31,
-        0xd657e6d43063b8ea8a5feb74,
-        0x19904abd56c98e00,
-        0x9eb6f09f90789e6f7fd9b26b,
-        0x349457c7c07607ca69452ed3,
-        0x5ef832e8c4e2e94,
-        0x963eab65a58adc2e73f30e0e,
-        0xed6ff2e146aab0fedfef95c8,
-        0xefaf92c221debd3,
-        0xcf39256ca1be75062f04d712,
-        0xc52c155c96c3514d4f2b30e1,
-        0x2410f5adf305ff23,
-        0xe374ed65a25af2ba077ec3b8,
-        0xcdeeca4922d0cddef328dba9,
-        0x11e5955107c1da8f,
-        0x7856f5c550ded18b32adcf52,
-        0x423ebf3a83e9779ea631a6d,
-        0x925f247837246b,
-        0x9290e8a4700247fab28b2a91,
-        0x322b8d35d5bfb3d55f886a21,
-        0x23a334f7c7f748e4,
-        0x85ca9b2830fb6576310673a2,
-        0xf131b6c4e52df505441654e0,
-        0x5f5bf0d8981d704,
-        0xe810c7d8d9ecc06b7b025a30,
-        0x17d0f6358eb62860bfbe3ac1,
-        0x7b034ec593ff79d,
-        0xe4a45493321160692513c18d,
-        0x9e22d6440a3012c07bb58ee6,
-        0x167f0f942e2168fc,
-        0xcba889a405c972da0c84a66c,
-        0x5b8d0e338f5fd9d2467fb2b1,
-        0x1de77425394af663,
-        0x962d87c6516e8ab35e5eab60,
-        0x1bc47223b94b420ac126c1b4,
-        0x261e12d29792289,
-        0x434b6ce6163ce3f852520e9b,
-        0xa934c2726237f8f9dfca50dc,
-        0xca6fcd48a100d4e,
-        0xff37c75fafd67f0c11d2189e,
-        0xbd3fb1261234b9e6130e2dbd,
-        0x1e57e68c8883a5bc,
-        0x6d880e870e69ab7d6bb1a8b,
-        0x8ddd0edb694ab22e52a56f5a,
-        0x2edcb7ae94e90750,
-        0x1950b253dcf8c1f394b0c076,
-        0x7b4b50a37a92a25c35eeecdb,
-        0x14a98c6da549e38,
-        0xbfcca3238df4b78153f8ed29,
-        0xfc754f320da04ef75befc85e,
-        0x7d0194cf8600c8c,
-        0x710df16de6cb3cecc9510c2f,
-        0x2f4ccfe624c908d90cf816db,
-        0x2ddca284da9a65d6,
-        0xe4e409a1a080279d867e1b94,
-        0x314be41f3d62f9560c345817,
-        0x185e08d86f4b8090,
-        0x1e08d8d2b284d2c468514aa0,
-        0x20578826880180d6990605c8,
-        0xf13b77c2d437fd1,
-        0xd2bf96d1dcc286eecdbe003,
-        0x73805ac9296540531158a7f4,
-        0x105765f80194181e,
-        0x3cf898126901d22a08a879aa,
-        0x418a3c3ad6df143618942cc1,
-        0x3d8dd25ff661877,
-        0x7ec37e67a897b76c76a0a4ef,
-        0x1002bdafc6ff6d9b3dc429fa,
-        0x15afa6051a5604f8,
-        0x973ec1ffb92c9ff6b727629e,
-        0x5e25ef7d807c049af6e53e52,
-        0x2a17c652cb704554,
-        0xf601399346893d77219ee852,
-        0x73256ff4c800c11d0fbb82a2,
-        0x2dc3898cdabd663d,
-        0x4542d532e759b89c0d183c49,
-        0x57ea9e15a4527aeb4b01fd6d,
-        0x1db10798bfdc02b1,
-        0x18e4a6c10021b562749bd08b,
-        0x2d73b054001a77c3614b75c2,
-        0x1fb3adc6aa78de4d,
-        0x12648887219d24c2800e061a,
-        0xf1aa95323291516b83e2706,
-        0xce6f3a06b3d291a,
-        0x4d272e1b968cd63facafb13a,
-        0xe35d127b5ee976cdb4168c6e,
-        0x12a35d6d751966e9,
-        0x696cc56809a0ee2b181cd289,
-        0xc7e81fc25d5409e1316db62a,
-        0x2deb134b676e30b6,
-        0xe07a3b67efff27bb129349d6,
-        0xc89b0769cec12d92d12a67a5,
-        0xe7092be44b93acc,
-        0x9fbbd77e754f290f42e2d97d,
-        0x96213cfb414b2aa8594861c0,
-        0x11f5271b4643e62f,
-        0x578b49b50a8f0b8d765e8960,
-        0x11e073a367acd52cda8f7e5a,
-        0x1376e85447695e66,
-        0xe56695280932f1fdaa01d72f,
-        0x4006781ad9d0fa6a2cfd30b0,
-        0x17862c371c4bb454,
-        0x59f4814506b680085e7b3e8c,
-        0x2bf8d8bbd3b112b6754f4b5d,
-        0x19a54970af990439,
-        0x34eff851ca74857c05a174e5,
-        0xb44466814a3f683d61affec6,
-        0x2aca411798938cb7,
-        0x2ee9a939afa775e920709418,
-        0x58c040a5e20db3fc522c0df8,
-        0x1f5db72f822e54ae,
-        0x99b811c40bfd5e4d59c05dfd,
-        0x429e000aeb32a8d4500d20a9,
-        0x18628d9e4b1ecd85,
-        0x35e8de46c06fb101fa48fb83,
-        0xae9e1ad30e8103a86d2d88da,
-        0x22ffc7a47ede4321,
-        0xc91d611d6f8eec2436f08837,
-        0x925a24d40d4d0e954903bafe,
-        0x115b4f242919cc8e,
-        0x5902433be71a4c7c7858a7d3,
-        0xb370197b26f2816429a1723f,
-        0x2a4ebc6aad7446dc,
-        0xbdf1a6ddbf0d0904bcf38ef0,
-        0x2e7ca05cc4fd2b727e0a383b,
-        0x1fe24dbb6d8e2018,
-        0x9fd6322e47921985504228c5,
-        0xcc2c14bec8740fa54847e16,
-        0x1e64cabe3abd8175,
-        0x64bcde1a583d00c407947ce,
-        0x6ae3f99448f46b45d04999a0,
-        0x2bed7384bdd4dcef,
-        0x7d8c48bad0d14b34769156b1,
-        0x404f89e7b9636d107275fa24,
-        0x5ea062744a855df,
-        0xdaf82979f144c4339d03cae0,
-        0xa9486a1fb4670f848578c0dd,
-        0xa06efa54456f004,
-        0xf4e29c328a4e1c6cd9f38c55,
-        0xaf98762d7e34915d80932d1d,
-        0x87a5732cf1f24b5,
-        0x699837e4fbdcdddfa582dfa9,
-        0xb48fee1ec711ba01c79de4be,
-        0x134d09468463014a,
-        0xb0283d825b077b5920833bf9,
-        0xf434df3a1e78a750c312137c,
-        0x6c73170f31ced9d,
-        0x471e748c17d8a7b79fd6d007,
-        0x4ae4462982affa124bd7a305,
-        0x2c44cff5619fd321,
-        0xa9000e09e85a4fe9c57f259a,
-        0xc8c390aa2324123741ff2742,
-        0x264fb0e9c8f9c706,
-        0xd240f8f36714a931f04358ee,
-        0xc8fccde41eec71b238ff554e,
-        0x2be3ef432a38ce68,
-        0x5b84c72eca216350f2aa1715,
-        0xa1a0555bfaf6964cc4cb10d7,
-        0x164b963ff4211d1c,
-        0xd89465ea3c8d3a0161a0f3e,
-        0x76cfc7ec39e019dc7ced71f9,
-        0x25e1c82935e6e5a5,
-        0xb61b2e1769a136b9152e4647,
-        0xaea8349aad26ffe800bbea70,
-        0x1ef08c3580293f4c,
-        0xc4d837fa21e13a36bda6184a,
-        0xdd46cfd8730c73db3e2900e1,
-        0x14130abe1f897edd,
-        0x94743970c0960ff85258bae5,
-        0xaba1b82cfbc622d0fb15c3,
-        0x2173b46afb6ae683,
-        0x65e731b0d051e4cfb5d8310,
-        0x14d4183b426f654606be7459,
-        0xfbff06158d1403f,
-        0xa3fed152a7ffc6e9555e868c,
-        0xe97ea23641f122c6b135e440,
-        0x1456353b03dd1310,
-        0x771217597a0a136d7fc39f00,
-        0x3bb1fd0d358dd3af9da947a6,
-        0x55b874612b5d118,
-        0x3172e4246cab54b53c88a08d,
-        0x5b533e6b73f33e2021f997ce,
-        0x331bd094bf923b9,
-        0xc9127789abb231d7796e8cae,
-        0xa131f08fc69a68c32f1e0855,
-        0x10694244d7818654,
-        0x4d580f31dce679aeb0b431a2,
-        0x49aac88be7f1229e7cffa86a,
-        0x1fbd84ec53f96846,
-        0x8324c26cf36792309dfc0d75,
-        0x5cfe866a8335f585a7103748,
-        0x26a7f252b8259a84,
-        0xde2fcd1359839c9140c85ee,
-        0x6992de19305ce31499c42f3e,
-        0x127c16e72435b0aa,
-        0x9fb630b3283d934beda706b4,
-        0xabfa3d3336a3b80c1fa3db09,
-        0x1e74bd84f90271be,
-        0x9ca76655957941f99c915f3a,
-        0x15eb3c2ddffb911d80751568,
-        0x1aa24d6ee5460f53,
-        0x712683f3246599f5ca79c0b2,
-        0x79c4d01ee54b781e11e3a20f,
-        0x5c98ed090b0ca64,
-        0x8febfcdacde6067bcea0cdf0,
-        0xa3262f9ac650f93c6b7658,
-        0x1d25b611ace3fea6,
-        0x5cb1b28e964e2f7ade2eb967,
-        0xdffd3258c5d9704233fc380d,
-        0xde6dab3914c02dc,
-        0xfb99d66d74feacf5ac9868b7,
-        0x2618f141c7d5a3b27e1d177e,
-        0x22ecffd8f8003bb8,
-        0x7448961bff797d80717431ae,
-        0x3dc4a77b83c00b385d5ab4fa,
-        0x280a6f51d24fccaf,
-        0xc623675e5b0de813deb7e23c,
-        0x7ab575831a14f5ca8d6e294e,
-        0x8acdc988cf947ef,
-        0xea72c2cb0470398bec83e4ff,
-        0x38863196d1122b4e5e223c74,
-        0x271f6ceb9dca13dc,
-        0x70896aaefdb1f7211d780aa7,
-        0xd1a05f4dbcb248825ffa9c1d,
-        0xf68670b85ec6360,
-        0xd8bf80955da74d1c3c476e1f,
-        0x21a8f840b162efd0e18d1548,
-        0x13634f57b812001e,
-        0xd28a313bf600043cf724eb24,
-        0x855df7ad1fe62aa9cbec0d18,
-        0xcbc253b9eb5e309,
-        0x39e6b0bd56639c142c4f227b,
-        0x745da58d00349b9d484b025e,
-        0x16cb574bfa1bf831,
-        0xa26ff5a39288017b2804a007,
-        0x1a4f700b761397b15d275a0d,
-        0x1cb5f168d8c2d607,
-        0xeaa887ee4f8889a5ffe11e31,
-        0xe5f5596328fd9d22a6624d77,
-        0x3d85b52a074dbcc,
-        0xadaa4c993c1a191a4695576f,
-        0x5727d84b1048210926d7c23a,
-        0x1880326ce4ae6bac,
-        0x22585a66de81ab12f1d95406,
-        0x848c7eb5df2e16494f51914e,
-        0x54aab94a55dae42,
-        0xa8adba5e46f9eb1aab6bd216,
-        0xa705f59a9675b893a80602ad,
-        0x155789912bf08f71,
-        0xacf41289ec52cc9ce15317e6,
-        0x935391d95a17ccfbf9518f4f,
-        0x27dcb246fdb9dd27,
-        0x7ac337206497384abc6e8c35,
-        0xaecaed43cd62fcdb285c0524,
-        0xab8acc74dab1a8c,
-        0xf16fbd66208d2d4e567d02c6,
-        0xff2f73036f7e1d18b6c73db9,
-        0x19defa36a8db0451,
-        0x51f4c360e1b64e1897a67abc,
-        0x2b58da0e32fb80220f9d716e,
-        0xf58c87b394f08a1,
-        0x77ac43db16a0fb58bcac4555,
-        0x98487fb5bce108074ba595c0,
-        0x12013d889f8751f0,
-        0x2ea46a3dad300c6d22ba594d,
-        0x1678434b11f0ce6fdb4c5114,
-        0x1b8495ee877925f3,
-        0xc3ab01ad4af8d9904a021879,
-        0x655484af2002d6a38a155852,
-        0x2f32a85fa2dd9483,
-        0xa13ae8ed30aa152fae06fb28,
-        0xac7459ebf707d2c4b8f2614d,
-        0x1748c38ac220efd5,
-        0xb8e4aab2db2437fd282870c6,
-        0xb2fb25331952b35fc1be7f4f,
-        0x27fd6cc6c9efddd6,
-        0x12baebcf447382b3e572048,
-        0x65e8daa6bc58a5c67f8bc8d4,
-        0x8faaa45b6ad95c9,
-        0xc4b02b13a51652085df16f1,
-        0x1e1fd52cdd58a503c131c0cf,
-        0x2322fa6f1f6c6df0,
-        0x8c9f77e6815a5e287d829556,
-        0x1efa4ef0d862284446950404,
-        0x1e667a73406058b0,
-        0x2c4d4783ec6710ac1a94000f,
-        0x20c7faf7c31592df6bd8ccfb,
-        0x107010614eb8505b,
-        0x4e957c1b0e0750314ecf8219,
-        0xfe166e552aa07df729d84e62,
-        0x2116cd7a3e9089b8,
-        0x54de83d6116050401fdc307,
-        0x6dbc81b5399b5240f0d99982,
-        0x663365da06b9e17,
-        0xfda6c6b23217573cc2b82e77,
-        0x72c815e3c405c66795048a03,
-        0xa2286c092d062e4,
-        0x653a207ccd8be029b6d283a1,
-        0x59adf5b32f3759412bb20c2d,
-        0x248d80414cd7c78e,
-        0x8e2d06fc7b6414604bb40bd6,
-        0x4ee1d98a1c2ba1e3a8c6e3ff,
-        0x5c8ed6ec6b46864,
-        0xf4933db71a89764d46b9c3aa,
-        0xac797ac90f9b432f533d301d,
-        0x8e023c63863a8b,
-        0x1e83478c5bec73dd44dd67e1,
-        0xa43e0d39df02eaa25f127348,
-        0x114d3cbb573540e1,
-        0x77f53dc1823ae6424889fbd3,
-        0xe1accdc4865d4bb5d7099a1b,
-        0xba061ddca5a25d9,
-        0xbbfa2d85f409ec395a62be59,
-        0xdc7ba2ea3ac948c188c4f12b,
-        0x13f7490bd0233bde,
-        0xd840b3f5b61346ee714aade4,
-        0x29570232a78a712d8d5ae8c6,
-        0x26cf3fc08b9a9e2c,
-        0xd123f79573e4b27b6ef58aae,
-        0x4252e4f1667b2a9d6bec0c10,
-        0x2da651638b5e9701,
-        0xb6955a9ea4579787bb94a4b1,
-        0xb7abd6de442a097929dd3b03,
-        0x107a5a3b3118f0db,
-        0x25ac5f5607396695a50c84a1,
-        0x4658fe4788ceea65a183159a,
-        0x37e854033a83cbb,
-        0xf2ad5f3a3bda82dd54696f1,
-        0x1a0f601dba0fc58a9fd013f9,
-        0x34357affd2a9eec,
-        0x229ebcb2e3c3ff357f072c26,
-        0xf1cad69972f09d02c61e37d2,
-        0x2276a216b691cbc4,
-        0x6b2c4cd5b035c43eac68eaad,
-        0xee10438c8c1ed785f7563712,
-        0x1f6700d541f3206c,
-        0xcc5fe62c051f52eca198a1e3,
-        0xde2b9fcd707fa2b3cca33f17,
-        0x30587e10b58350e2,
-        0xbfaa66024390e98d23613265,
-        0x7cd74238b5b69226e99a6a86,
-        0x8347198b82bbcea,
-        0x127577ee289c509fa6e8fc3,
-        0x11e24e87d4b3d71e1f15f767,
-        0x2c09360ccd2c9276,
-        0xd6c42f2a53c8eec1843da481,
-        0x7578f308a5e02782c926eda5,
-        0x2290dd21991f4999,
-        0x7e86beae0a1e12ca3edd51d7,
-        0xd357b366ecf876c63bb5bf58,
-        0x1fb59fd1bc121651,
-        0xe5efac85c601670f0ec48a1,
-        0x321bfecc4b0fb1b019be6ee2,
-        0x9b16f088dc00ad6,
-        0x5220a09dff3ba0338662884e,
-        0xe49fed2aa00b9972b8b41142,
-        0x116662191cc785,
-        0xf572db076386cbe72afc2bea,
-        0x6e9cbcc45689bee816b366e0,
-        0x21e00537d99a6800,
-        0x5a58b370839f77c3fdf77b86,
-        0xb7360f999307ada76e1f3d7f,
-        0x29e7289822a60ee7,
-        0x2748a7d962c1e45e9d484ca0,
-        0xec0b4b551bcc29d22ef8e7ea,
-        0x138b6abb0baa1656,
-        0xf6bcc41a0564137bbe7cc752,
-        0x6f064fb7417794cc09655e4a,
-        0x5c44aca9f248626,
-        0xec855ba7f753492f4326bc73,
-        0xb6c69fa982608b41d4bf63c5,
-        0x241dfcd4e4cc1949,
-        0xe61671a57b362d8faa7bc67c,
-        0x7922da8d8fddf18e4083f1d8,
-        0x3046d49a44b3393a,
-        0xcdde79d38e86e085ec39eb24,
-        0xba4767d47c7948d80c649845,
-        0x28f6808fd11e05d4,
-        0x3a11ddfb877dc41c6f948a8,
-        0x40b6825a9ecff0710b2e773c,
-        0x2b3ba77889bfdae2,
-        0xff,
-        0x0,
-        0x2,
-        0x51,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x0,
-        0x1,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x1,
-        0x1,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x0,
-        0x1,
-        0x1,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x51,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x1,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x0,
-        0x0,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x1,
-        0x0,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x1,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x1,
-        0x0,
-        0x0,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0x0,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x0,
-        0x1,
-        0x1,
-        0x800000000000011000000000000000000000000000000000000000000000000,
-        0x1,
-        0xac13780b851096be687bb2b0,
-        0xea8ce1158d2179b063ba7a0e,
-        0x2ad20f46b53dc0b9,
-        0x0,
-        0x4bf44e584378ce9133a6c56c,
-        0xa76caf7d20a8c8254e5a9a2,
-        0x8819e8bf19e280c,
-        0x0,
-        0x3,
-        0xb375e68dd4da87378b52f99a,
-        0x268d904de3f4d700fd343a5e,
-        0xe0578efd90b6e87,
-        0x0,
-        0x2b17f3fc348d33b8b88d7f8,
-        0x17d46f14ddfc88d2ec48fb45,
-        0x10d522bf5abaac3b,
-        0x0,
-        0x468eb386382894af7cb7fb38,
-        0x615f983d,
-        0x0,
-        0x0,
-        0x4,
-        0x20f5f7780878a1389f1c8d6,
-        0xb0cc53c29c4e9911e3801233,
-        0x15f1092f675be3b4,
-        0x0,
-        0x842a5f63dbd3165083c06333,
-        0x4b54262844fd8f2a871afac8,
-        0x303d0b9d91ef1d46,
-        0x0,
-        0x8cac31c304bc3a1aeb898571,
-        0x37995e3f6c9ae6227afdeb97,
-        0x1f7099293be748d5,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x4,
-        0xe13b6fd0283dc571a4974203,
-        0x4d215f39f34c52812fc72f92,
-        0x11e2ae4418ccb28b,
-        0x0,
-        0xabd8eeb7bbd1d1e3e6c522cc,
-        0x3d6ee848ee5fa4a07a7fddd5,
-        0x276a16ce5ee1bc10,
-        0x0,
-        0x118ffee5d0f0e36b0477f0be,
-        0x4ab064f5e66f741eaad75cff,
-        0xb4eed5e836a13ec,
-        0x0,
-        0x85385fdcb1f4b18dc815b152,
-        0xae60dc973d05dcbcd320c294,
-        0x22fb87e7123c1f0c,
-        0x0,
-        0x7,
-        0x9dbc53d945761223c5585d3b,
-        0x5a14b591536a72d812fecc07,
-        0x116ecd1b54e20af4,
-        0x0,
-        0xbb9b89111b382ac3da472f0b,
-        0x715be70bcbf5fcc4664e1b36,
-        0x2fee85f2f36a177f,
-        0x0,
-        0x3d92cabbd2142239ea1f930c,
-        0xee7bd507c44f5a09d9785835,
-        0x2ded7d08d2843a55,
-        0x0,
-        0x20f5f7780878a1389f1c8d9,
-        0xb0cc53c29c4e9911e3801233,
-        0x15f1092f675be3b4,
-        0x0,
-        0x842a5f63dbd3165083c06333,
-        0x4b54262844fd8f2a871afac8,
-        0x303d0b9d91ef1d46,
-        0x0,
-        0x8cac31c304bc3a1aeb898571,
-        0x37995e3f6c9ae6227afdeb97,
-        0x1f7099293be748d5,
-        0x0,
-        0x1,
-        0x0,
-        0x0,
-        0x0,
-        0x66e8b07f8d23c97fd7798354,
-        0xe803d296963f49bdd67a0437,
-        0x1029e43cfae9575f,
-        0x0,
-        0x0,
-    ]
-        .span()
-}
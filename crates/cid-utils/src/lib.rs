@@ -0,0 +1,453 @@
+//! Content identifiers computed locally, so uploads and downloads can be checked
+//! against the CID they claim without trusting the node or gateway. Kept apart from
+//! `ipfs-utils` so crates that only parse CIDs don't pull in the storage backends.
+//!
+//! Files are hashed the way `ipfs add` does by default: 256 KiB chunks arranged in a
+//! balanced DAG of at most 174 links per node. CIDv0 wraps every leaf in a dag-pb
+//! node; CIDv1 uses raw leaves.
+#![deny(unused_crate_dependencies)]
+
+use eyre::{eyre, Result};
+use sha2::{Digest, Sha256};
+
+pub const CHUNK_SIZE: usize = 256 * 1024;
+pub const MAX_LINKS: usize = 174;
+
+const CID_V1: u8 = 0x01;
+const RAW_CODEC: u8 = 0x55;
+const DAG_PB_CODEC: u8 = 0x70;
+const SHA2_256: u8 = 0x12;
+const SHA2_256_LEN: u8 = 0x20;
+
+/// UnixFS `Data.DataType.File`
+const UNIXFS_FILE: u64 = 2;
+
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CidVersion {
+    V0,
+    V1,
+}
+
+/// The CID shapes we know how to recompute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CidFormat {
+    /// `Qm…`: base58btc dag-pb UnixFS file
+    V0,
+    /// `bafk…`: base32 raw block, the sha2-256 of the whole content
+    RawV1,
+    /// `bafy…`: base32 dag-pb UnixFS file with raw leaves
+    DagPbV1,
+}
+
+impl CidFormat {
+    pub fn parse(cid: &str) -> Result<Self> {
+        let invalid = || eyre!("Invalid or unsupported CID: {}", cid);
+
+        let bytes = if cid.starts_with("Qm") {
+            base58_decode(cid).ok_or_else(invalid)?
+        } else if let Some(encoded) = cid.strip_prefix('b') {
+            base32_lower_decode(encoded).ok_or_else(invalid)?
+        } else {
+            return Err(invalid());
+        };
+
+        match bytes.as_slice() {
+            [SHA2_256, SHA2_256_LEN, digest @ ..] if digest.len() == 32 => Ok(Self::V0),
+            [CID_V1, RAW_CODEC, SHA2_256, SHA2_256_LEN, digest @ ..] if digest.len() == 32 => {
+                Ok(Self::RawV1)
+            }
+            [CID_V1, DAG_PB_CODEC, SHA2_256, SHA2_256_LEN, digest @ ..] if digest.len() == 32 => {
+                Ok(Self::DagPbV1)
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    /// CID of `bytes` in this format
+    pub fn compute(self, bytes: &[u8]) -> String {
+        match self {
+            Self::V0 => unixfs_cid(bytes, CidVersion::V0),
+            Self::RawV1 => raw_cid_v1(bytes),
+            Self::DagPbV1 => unixfs_cid(bytes, CidVersion::V1),
+        }
+    }
+}
+
+/// Checks that `bytes` hash to `cid`
+pub fn verify_cid(cid: &str, bytes: &[u8]) -> Result<()> {
+    let computed = CidFormat::parse(cid)?.compute(bytes);
+    if computed != cid {
+        return Err(eyre!(
+            "CID mismatch: expected {}, content hashes to {}",
+            cid,
+            computed
+        ));
+    }
+    Ok(())
+}
+
+/// RFC 4648 base32, lowercase and unpadded, as used by multibase `b`
+pub fn base32_lower(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+
+    out
+}
+
+fn base32_lower_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(encoded.len() * 5 / 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+
+    for c in encoded.bytes() {
+        let value = BASE32_ALPHABET.iter().position(|&a| a == c)? as u16;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+
+    Some(out)
+}
+
+/// Bitcoin-alphabet base58, as used by CIDv0
+pub fn base58_encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+    // Little-endian base58 digits
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &byte in &bytes[zeros..] {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out = String::with_capacity(zeros + digits.len());
+    out.push_str(&"1".repeat(zeros));
+    out.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| BASE58_ALPHABET[d as usize] as char),
+    );
+    out
+}
+
+fn base58_decode(encoded: &str) -> Option<Vec<u8>> {
+    let zeros = encoded.bytes().take_while(|&c| c == b'1').count();
+
+    // Little-endian bytes
+    let mut bytes: Vec<u8> = Vec::with_capacity(encoded.len());
+    for c in encoded.bytes().skip(zeros) {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut out = vec![0; zeros];
+    out.extend(bytes.iter().rev());
+    Some(out)
+}
+
+/// CIDv1 of `bytes` stored as a single raw block (sha2-256, base32)
+pub fn raw_cid_v1(bytes: &[u8]) -> String {
+    format!("b{}", base32_lower(&raw_cid_bytes(bytes)))
+}
+
+fn raw_cid_bytes(bytes: &[u8]) -> Vec<u8> {
+    let digest = Sha256::digest(bytes);
+
+    let mut cid = Vec::with_capacity(4 + digest.len());
+    cid.extend_from_slice(&[CID_V1, RAW_CODEC, SHA2_256, SHA2_256_LEN]);
+    cid.extend_from_slice(&digest);
+    cid
+}
+
+/// CID `ipfs add` assigns to a file with the default chunker and balanced layout
+/// (`--cid-version 1` implies raw leaves).
+pub fn unixfs_cid(bytes: &[u8], version: CidVersion) -> String {
+    let mut chunks = bytes.chunks(CHUNK_SIZE).peekable();
+
+    // An empty file is a single empty leaf
+    let mut root = DagNode::leaf(chunks.next().unwrap_or_default(), version);
+    let mut depth = 0;
+    while chunks.peek().is_some() {
+        depth += 1;
+        let mut children = vec![root];
+        fill_node(&mut children, depth, &mut chunks, version);
+        root = DagNode::internal(&children, version);
+    }
+
+    match version {
+        CidVersion::V0 => base58_encode(&root.cid),
+        CidVersion::V1 => format!("b{}", base32_lower(&root.cid)),
+    }
+}
+
+fn fill_node(
+    children: &mut Vec<DagNode>,
+    depth: usize,
+    chunks: &mut std::iter::Peekable<std::slice::Chunks<'_, u8>>,
+    version: CidVersion,
+) {
+    while children.len() < MAX_LINKS {
+        let Some(chunk) = chunks.peek().copied() else {
+            break;
+        };
+        let child = if depth == 1 {
+            chunks.next();
+            DagNode::leaf(chunk, version)
+        } else {
+            let mut grandchildren = Vec::new();
+            fill_node(&mut grandchildren, depth - 1, chunks, version);
+            DagNode::internal(&grandchildren, version)
+        };
+        children.push(child);
+    }
+}
+
+struct DagNode {
+    /// Binary CID (a bare multihash for CIDv0)
+    cid: Vec<u8>,
+    /// Bytes of file content below this node
+    file_size: u64,
+    /// Encoded size of this node and everything it links to
+    cumulative_size: u64,
+}
+
+impl DagNode {
+    fn leaf(chunk: &[u8], version: CidVersion) -> Self {
+        let file_size = chunk.len() as u64;
+        match version {
+            CidVersion::V0 => {
+                let block = encode_pb_node(&[], &encode_unixfs(chunk, file_size, &[]));
+                Self {
+                    cid: dag_pb_cid(&block, version),
+                    file_size,
+                    cumulative_size: block.len() as u64,
+                }
+            }
+            CidVersion::V1 => Self {
+                cid: raw_cid_bytes(chunk),
+                file_size,
+                cumulative_size: file_size,
+            },
+        }
+    }
+
+    fn internal(children: &[Self], version: CidVersion) -> Self {
+        let block_sizes: Vec<u64> = children.iter().map(|c| c.file_size).collect();
+        let file_size = block_sizes.iter().sum();
+        let block = encode_pb_node(children, &encode_unixfs(&[], file_size, &block_sizes));
+        let cumulative_size =
+            block.len() as u64 + children.iter().map(|c| c.cumulative_size).sum::<u64>();
+
+        Self {
+            cid: dag_pb_cid(&block, version),
+            file_size,
+            cumulative_size,
+        }
+    }
+}
+
+fn dag_pb_cid(block: &[u8], version: CidVersion) -> Vec<u8> {
+    let mut cid = Vec::with_capacity(36);
+    if version == CidVersion::V1 {
+        cid.extend_from_slice(&[CID_V1, DAG_PB_CODEC]);
+    }
+    cid.extend_from_slice(&[SHA2_256, SHA2_256_LEN]);
+    cid.extend_from_slice(&Sha256::digest(block));
+    cid
+}
+
+/// dag-pb `PBNode`; links are written before data, as every IPFS implementation does
+fn encode_pb_node(links: &[DagNode], data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for link in links {
+        let mut encoded = Vec::new();
+        write_bytes_field(&mut encoded, 1, &link.cid);
+        write_bytes_field(&mut encoded, 2, b"");
+        write_varint_field(&mut encoded, 3, link.cumulative_size);
+        write_bytes_field(&mut out, 2, &encoded);
+    }
+    write_bytes_field(&mut out, 1, data);
+    out
+}
+
+/// UnixFS `Data` message of a file node; empty content is left out, as go-unixfs does
+fn encode_unixfs(data: &[u8], file_size: u64, block_sizes: &[u64]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + 16);
+    write_varint_field(&mut out, 1, UNIXFS_FILE);
+    if !data.is_empty() {
+        write_bytes_field(&mut out, 2, data);
+    }
+    write_varint_field(&mut out, 3, file_size);
+    for &size in block_sizes {
+        write_varint_field(&mut out, 4, size);
+    }
+    out
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_varint_field(out: &mut Vec<u8>, field: u64, value: u64) {
+    write_varint(out, field << 3);
+    write_varint(out, value);
+}
+
+fn write_bytes_field(out: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    write_varint(out, (field << 3) | 2);
+    write_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base32_lower() {
+        assert_eq!(base32_lower(b""), "");
+        assert_eq!(base32_lower(b"f"), "my");
+        assert_eq!(base32_lower(b"foobar"), "mzxw6ytboi");
+    }
+
+    #[test]
+    fn test_raw_cid_v1() {
+        // `ipfs add --raw-leaves --cid-version 1` of "hello world\n"
+        assert_eq!(
+            raw_cid_v1(b"hello world\n"),
+            "bafkreifjjcie6lypi6ny7amxnfftagclbuxndqonfipmb64f2km2devei4"
+        );
+    }
+
+    #[test]
+    fn test_base58_roundtrip() {
+        assert_eq!(base58_encode(b""), "");
+        assert_eq!(base58_encode(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(base58_encode(&[0, 0, 1]), "112");
+        assert_eq!(base58_decode("StV1DL6CwTryKyV").unwrap(), b"hello world");
+        assert_eq!(base58_decode("112").unwrap(), vec![0, 0, 1]);
+        assert!(base58_decode("0OIl").is_none());
+    }
+
+    #[test]
+    fn test_unixfs_cid_single_chunk() {
+        // `ipfs add` of "hello world\n" and of an empty file
+        assert_eq!(
+            unixfs_cid(b"hello world\n", CidVersion::V0),
+            "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o"
+        );
+        assert_eq!(
+            unixfs_cid(b"", CidVersion::V0),
+            "QmbFMke1KXqnYyBBWxB74N4c5SBnJMVAiMNRcGu6x1AwQH"
+        );
+        // A single raw leaf is its own root
+        assert_eq!(
+            unixfs_cid(b"", CidVersion::V1),
+            "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku"
+        );
+        assert_eq!(
+            unixfs_cid(b"hello world\n", CidVersion::V1),
+            raw_cid_v1(b"hello world\n")
+        );
+    }
+
+    #[test]
+    fn test_unixfs_cid_multi_chunk() {
+        let bytes: Vec<u8> = (0..600_000).map(|i| (i % 251) as u8).collect();
+        assert_eq!(
+            unixfs_cid(&bytes, CidVersion::V0),
+            "QmWKdZuiD9zqoZFnLYbpV2Q5YhRCJWpqiVeYA8ygYEjcEe"
+        );
+        assert_eq!(
+            unixfs_cid(&bytes, CidVersion::V1),
+            "bafybeicp64het67shnhxiyl3sg5mylxqop6pnqsqpfecb6pmni2ghoxzom"
+        );
+
+        // One chunk more than a single node can link to forces a second level
+        let bytes: Vec<u8> = (0..CHUNK_SIZE * (MAX_LINKS + 1) + 1)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        assert_eq!(
+            unixfs_cid(&bytes, CidVersion::V0),
+            "QmfSNfmSo1Gg885jm8qFWAEe14nkjYc7bXgXjuvKWjVoKw"
+        );
+        assert_eq!(
+            unixfs_cid(&bytes, CidVersion::V1),
+            "bafybeihzhgkxa5ea4r5cr73r73tsf3ppwpstcxnsuyqxio3vy7xpikkhmi"
+        );
+    }
+
+    #[test]
+    fn test_cid_format_parse() {
+        assert_eq!(
+            CidFormat::parse("QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o").unwrap(),
+            CidFormat::V0
+        );
+        assert_eq!(
+            CidFormat::parse("bafkreifjjcie6lypi6ny7amxnfftagclbuxndqonfipmb64f2km2devei4")
+                .unwrap(),
+            CidFormat::RawV1
+        );
+        assert_eq!(
+            CidFormat::parse("bafybeicp64het67shnhxiyl3sg5mylxqop6pnqsqpfecb6pmni2ghoxzom")
+                .unwrap(),
+            CidFormat::DagPbV1
+        );
+        assert!(CidFormat::parse("").is_err());
+        assert!(CidFormat::parse("QmInvalidHashThatDoesNotExist").is_err());
+        assert!(CidFormat::parse("zdj7WWeQ43G6JJvLWQWZpyHuAMq6uYWRjkBXFad11vE2LHhQ7").is_err());
+    }
+
+    #[test]
+    fn test_verify_cid() {
+        let v0 = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+        let v1 = "bafkreifjjcie6lypi6ny7amxnfftagclbuxndqonfipmb64f2km2devei4";
+
+        assert!(verify_cid(v0, b"hello world\n").is_ok());
+        assert!(verify_cid(v1, b"hello world\n").is_ok());
+
+        let err = verify_cid(v0, b"hello world!").unwrap_err();
+        assert!(err.to_string().starts_with("CID mismatch"));
+        assert!(verify_cid(v1, b"").is_err());
+    }
+}
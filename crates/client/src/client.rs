@@ -1,4 +1,6 @@
+use common::deployment::{contract_address, Contract};
 use common::get_env_var;
+use common::signer::KeySource;
 use eyre::{eyre, Result, WrapErr};
 use mmr_utils::{create_database_file, ensure_directory_exists};
 use starknet::{
@@ -6,10 +8,14 @@ use starknet::{
     macros::selector,
     providers::Provider as EventProvider,
 };
-use starknet_handler::provider::StarknetProvider;
+use starknet_handler::account::StarknetAccount;
+use starknet_handler::provider::{StarknetProvider, StarknetSnapshot};
+use std::sync::Arc;
 use tokio::time::Duration;
 use tracing::{debug, error, info, instrument};
 
+#[cfg(test)]
+use common::deployment::contract_address_from_vars;
 #[cfg(test)]
 use mockall::automock;
 
@@ -47,8 +53,8 @@ pub struct LightClient {
     chain_id: u64,
     latest_processed_events_block: u64,
     latest_processed_mmr_block: u64,
-    starknet_private_key: String,
-    starknet_account_address: String,
+    /// Shared by every update, so nonces are counted across them
+    starknet_account: Arc<StarknetAccount>,
     polling_interval: Duration,
     batch_size: u64,
     blocks_per_run: u64,
@@ -72,22 +78,28 @@ impl LightClient {
         // Load environment variables
         let starknet_rpc_url = get_env_var("STARKNET_RPC_URL")
             .wrap_err("Failed to get STARKNET_RPC_URL environment variable")?;
-        let l2_store_addr = get_env_var("FOSSIL_STORE")
-            .wrap_err("Failed to get FOSSIL_STORE environment variable")?;
-        let verifier_addr = get_env_var("FOSSIL_VERIFIER")
-            .wrap_err("Failed to get FOSSIL_VERIFIER environment variable")?;
-        let starknet_private_key = get_env_var("STARKNET_PRIVATE_KEY")
-            .wrap_err("Failed to get STARKNET_PRIVATE_KEY environment variable")?;
+        let l2_store_addr =
+            contract_address(Contract::Store).wrap_err("Failed to get the store address")?;
+        let verifier_addr = contract_address(Contract::FossilVerifier)
+            .wrap_err("Failed to get the verifier address")?;
         let starknet_account_address = get_env_var("STARKNET_ACCOUNT_ADDRESS")
             .wrap_err("Failed to get STARKNET_ACCOUNT_ADDRESS environment variable")?;
         let chain_id = get_env_var("CHAIN_ID")
             .wrap_err("Failed to get CHAIN_ID environment variable")?
             .parse::<u64>()
             .wrap_err("Failed to parse CHAIN_ID as u64")?;
+        let starknet_key_source =
+            KeySource::from_env("STARKNET").wrap_err("Failed to load the Starknet signing key")?;
 
         // Initialize providers
         let starknet_provider = StarknetProvider::new(&starknet_rpc_url)
             .wrap_err("Failed to initialize Starknet provider")?;
+        let starknet_account = StarknetAccount::from_key_source(
+            starknet_provider.provider(),
+            &starknet_key_source,
+            &starknet_account_address,
+        )
+        .wrap_err("Failed to create the Starknet account")?;
 
         // Set up the database file path
         let current_dir = ensure_directory_exists("../../db-instances")
@@ -106,8 +118,7 @@ impl LightClient {
             chain_id,
             latest_processed_events_block: start_block.saturating_sub(1),
             latest_processed_mmr_block: start_block.saturating_sub(1),
-            starknet_private_key,
-            starknet_account_address,
+            starknet_account: Arc::new(starknet_account),
             polling_interval: Duration::from_secs(polling_interval),
             batch_size,
             blocks_per_run,
@@ -116,13 +127,13 @@ impl LightClient {
 
     /// Processes new events from the Starknet store contract.
     pub async fn process_new_events(&mut self) -> Result<()> {
-        // Get the latest block number
-        let latest_block = self
+        // Pin the latest block, so the events and the store state read below agree
+        let snapshot = self
             .starknet_provider
-            .provider()
-            .block_number()
+            .snapshot()
             .await
-            .wrap_err("Failed to get latest block number from Starknet")?;
+            .wrap_err("Failed to get latest block from Starknet")?;
+        let latest_block = snapshot.block_number();
 
         // Don't process if we're already caught up with events
         if self.latest_processed_events_block >= latest_block {
@@ -173,25 +184,24 @@ impl LightClient {
         if !events.events.is_empty() {
             info!(event_count = events.events.len(), "Processing new events");
             // Process the events and update MMR
-            self.handle_events().await?;
+            self.handle_events(&snapshot).await?;
         }
 
         Ok(())
     }
 
-    /// Handles the events by updating the MMR and verifying proofs.
-    #[instrument(skip(self))]
-    pub async fn handle_events(&mut self) -> Result<()> {
+    /// Handles the events by updating the MMR and verifying proofs. Both the relayed
+    /// block and the MMR block are read at `snapshot`'s block.
+    #[instrument(skip(self, snapshot), fields(block_number = snapshot.block_number()))]
+    pub async fn handle_events(&mut self, snapshot: &StarknetSnapshot) -> Result<()> {
         // Fetch the latest stored blockhash from L1
-        let latest_relayed_block = self
-            .starknet_provider
+        let (latest_relayed_block, _) = snapshot
             .get_latest_relayed_block(&self.l2_store_addr)
             .await
             .wrap_err("Failed to get latest relayed block from Starknet")?;
 
         // Fetch latest MMR state from L2
-        let latest_mmr_block = self
-            .starknet_provider
+        let latest_mmr_block = snapshot
             .get_latest_mmr_block(&self.l2_store_addr)
             .await
             .wrap_err("Failed to get latest MMR block from Starknet")?;
@@ -225,13 +235,11 @@ impl LightClient {
 
         // Call the publisher function directly with all required parameters
         let _result = publisher::api::operations::update_mmr(
-            &get_env_var("STARKNET_RPC_URL")
-                .wrap_err("Failed to get STARKNET_RPC_URL environment variable")?,
+            &self.starknet_provider,
             self.chain_id,
             &self.verifier_addr,
             &self.l2_store_addr,
-            &self.starknet_private_key,
-            &self.starknet_account_address,
+            self.starknet_account.clone(),
             self.batch_size,
             start_block,
             end_block,
@@ -246,6 +254,8 @@ impl LightClient {
     }
 
     pub async fn run(&mut self) -> Result<()> {
+        self.starknet_provider.check_health().await?;
+
         info!(
             "Listening for events from block {} with polling interval {} seconds",
             self.latest_processed_events_block + 1,
@@ -276,18 +286,11 @@ impl LightClient {
             .env_reader
             .get_env_var("STARKNET_RPC_URL")
             .wrap_err("Failed to get STARKNET_RPC_URL environment variable")?;
-        let l2_store_addr = deps
-            .env_reader
-            .get_env_var("FOSSIL_STORE")
-            .wrap_err("Failed to get FOSSIL_STORE environment variable")?;
-        let verifier_addr = deps
-            .env_reader
-            .get_env_var("FOSSIL_VERIFIER")
-            .wrap_err("Failed to get FOSSIL_VERIFIER environment variable")?;
-        let starknet_private_key = deps
-            .env_reader
-            .get_env_var("STARKNET_PRIVATE_KEY")
-            .wrap_err("Failed to get STARKNET_PRIVATE_KEY environment variable")?;
+        let env_var = |name: &str| deps.env_reader.get_env_var(name).ok();
+        let l2_store_addr = contract_address_from_vars(Contract::Store, env_var)
+            .wrap_err("Failed to get the store address")?;
+        let verifier_addr = contract_address_from_vars(Contract::FossilVerifier, env_var)
+            .wrap_err("Failed to get the verifier address")?;
         let starknet_account_address = deps
             .env_reader
             .get_env_var("STARKNET_ACCOUNT_ADDRESS")
@@ -298,11 +301,20 @@ impl LightClient {
             .wrap_err("Failed to get CHAIN_ID environment variable")?
             .parse::<u64>()
             .wrap_err("Failed to parse CHAIN_ID as u64")?;
+        let starknet_key_source =
+            KeySource::from_vars("STARKNET", |name| deps.env_reader.get_env_var(name).ok())
+                .wrap_err("Failed to load the Starknet signing key")?;
 
         let starknet_provider = deps
             .provider_factory
             .create_provider(&starknet_rpc_url)
             .wrap_err("Failed to create Starknet provider")?;
+        let starknet_account = StarknetAccount::from_key_source(
+            starknet_provider.provider(),
+            &starknet_key_source,
+            &starknet_account_address,
+        )
+        .wrap_err("Failed to create the Starknet account")?;
         let current_dir = deps
             .db_utils
             .ensure_directory_exists("../../db-instances")
@@ -323,8 +335,7 @@ impl LightClient {
             chain_id,
             latest_processed_events_block: start_block.saturating_sub(1),
             latest_processed_mmr_block: start_block.saturating_sub(1),
-            starknet_private_key,
-            starknet_account_address,
+            starknet_account: Arc::new(starknet_account),
             polling_interval: Duration::from_secs(polling_interval),
             batch_size,
             blocks_per_run,
@@ -360,10 +371,10 @@ mod tests {
                 "STARKNET_RPC_URL" => "http://localhost:5050".to_string(),
                 "FOSSIL_STORE" => "0x1".to_string(),
                 "FOSSIL_VERIFIER" => "0x2".to_string(),
-                "STARKNET_PRIVATE_KEY" => "testkey".to_string(),
+                "STARKNET_PRIVATE_KEY" => "0x1".to_string(),
                 "STARKNET_ACCOUNT_ADDRESS" => "0xabc".to_string(),
                 "CHAIN_ID" => "5".to_string(),
-                _ => "dummy".to_string(),
+                _ => return Err(eyre!("{} not set", key)),
             })
         });
 
@@ -428,10 +439,10 @@ mod tests {
                 "STARKNET_RPC_URL" => "http://localhost:5050".to_string(),
                 "FOSSIL_STORE" => "0x1".to_string(),
                 "FOSSIL_VERIFIER" => "0x2".to_string(),
-                "STARKNET_PRIVATE_KEY" => "testkey".to_string(),
+                "STARKNET_PRIVATE_KEY" => "0x1".to_string(),
                 "STARKNET_ACCOUNT_ADDRESS" => "0xabc".to_string(),
                 "CHAIN_ID" => "5".to_string(),
-                _ => "dummy".to_string(),
+                _ => return Err(eyre!("{} not set", key)),
             })
         });
 
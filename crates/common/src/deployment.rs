@@ -0,0 +1,191 @@
+//! The deployment manifest: where the Starknet contracts of one deployment live.
+//!
+//! The `deploy` binary of starknet-handler writes it once the contracts are
+//! deployed and the store is initialized. Services find it through
+//! `DEPLOYMENT_MANIFEST`; without it each address is read from its own variable
+//! (`FOSSIL_STORE`, `FOSSIL_VERIFIER`, `STARKNET_VERIFIER`, `L2_MSG_PROXY`).
+//!
+//! Addresses and class hashes are `0x`-prefixed, zero-padded to 64 hex digits.
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Environment variable naming the manifest file
+pub const MANIFEST_VAR: &str = "DEPLOYMENT_MANIFEST";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Contract {
+    Store,
+    FossilVerifier,
+    /// The RISC Zero Groth16 verifier the Fossil verifier checks proofs with
+    Groth16Verifier,
+    L1MessageProxy,
+}
+
+impl Contract {
+    /// Variable the address is read from when there is no manifest
+    pub const fn env_var(self) -> &'static str {
+        match self {
+            Self::Store => "FOSSIL_STORE",
+            Self::FossilVerifier => "FOSSIL_VERIFIER",
+            Self::Groth16Verifier => "STARKNET_VERIFIER",
+            Self::L1MessageProxy => "L2_MSG_PROXY",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassHashes {
+    pub store: String,
+    pub fossil_verifier: String,
+    pub groth16_verifier: String,
+    pub universal_ecip: String,
+    pub l1_message_proxy: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deployment {
+    /// Chain id, as the short string the node reports (`SN_SEPOLIA`, `KATANA`)
+    pub chain_id: String,
+    /// Block the store was initialized in
+    pub block_number: u64,
+    pub store: String,
+    pub fossil_verifier: String,
+    pub groth16_verifier: String,
+    pub l1_message_proxy: String,
+    /// Minimum number of seconds between two relayed block hashes
+    pub min_update_interval: u64,
+    pub class_hashes: ClassHashes,
+}
+
+impl Deployment {
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read deployment manifest {}", path.display()))?;
+        serde_json::from_str(&json)
+            .wrap_err_with(|| format!("Invalid deployment manifest {}", path.display()))
+    }
+
+    /// Writes the manifest to `path`. The file is replaced in one step, so a
+    /// service starting meanwhile reads either the old manifest or the new one.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(self)?)
+            .wrap_err_with(|| format!("Failed to write {}", tmp.display()))?;
+        std::fs::rename(&tmp, path)
+            .wrap_err_with(|| format!("Failed to write deployment manifest {}", path.display()))
+    }
+
+    pub fn address(&self, contract: Contract) -> &str {
+        match contract {
+            Contract::Store => &self.store,
+            Contract::FossilVerifier => &self.fossil_verifier,
+            Contract::Groth16Verifier => &self.groth16_verifier,
+            Contract::L1MessageProxy => &self.l1_message_proxy,
+        }
+    }
+}
+
+/// Address of `contract`, from the manifest named by `DEPLOYMENT_MANIFEST` if set,
+/// otherwise from the contract's own variable
+pub fn contract_address(contract: Contract) -> Result<String> {
+    contract_address_from_vars(contract, |name| crate::get_env_var(name).ok())
+}
+
+/// Like [`contract_address`], reading variables through `var`
+pub fn contract_address_from_vars(
+    contract: Contract,
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<String> {
+    let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+
+    match var(MANIFEST_VAR) {
+        Some(path) => Ok(Deployment::load(Path::new(path.trim()))?
+            .address(contract)
+            .to_string()),
+        None => var(contract.env_var()).ok_or_else(|| {
+            eyre!(
+                "No {:?} address configured: set {} or {}",
+                contract,
+                MANIFEST_VAR,
+                contract.env_var()
+            )
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn address(n: u8) -> String {
+        format!("0x{:064x}", n)
+    }
+
+    fn deployment() -> Deployment {
+        Deployment {
+            chain_id: "KATANA".to_string(),
+            block_number: 12,
+            store: address(1),
+            fossil_verifier: address(2),
+            groth16_verifier: address(3),
+            l1_message_proxy: address(4),
+            min_update_interval: 40,
+            class_hashes: ClassHashes {
+                store: address(5),
+                fossil_verifier: address(6),
+                groth16_verifier: address(7),
+                universal_ecip: address(8),
+                l1_message_proxy: address(9),
+            },
+        }
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("deployment.json");
+
+        deployment().save(&path).unwrap();
+        assert_eq!(Deployment::load(&path).unwrap(), deployment());
+        assert!(!path.with_extension("tmp").exists());
+
+        std::fs::write(&path, "{}").unwrap();
+        assert!(Deployment::load(&path).is_err());
+        assert!(Deployment::load(&dir.path().join("missing.json")).is_err());
+    }
+
+    #[test]
+    fn test_contract_address() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("deployment.json");
+        deployment().save(&path).unwrap();
+
+        let mut vars = HashMap::from([
+            ("FOSSIL_STORE".to_string(), "0xabc".to_string()),
+            ("L2_MSG_PROXY".to_string(), " ".to_string()),
+        ]);
+        let lookup = |vars: &HashMap<String, String>, contract| -> Result<String> {
+            contract_address_from_vars(contract, |name| vars.get(name).cloned())
+        };
+
+        // Without a manifest, each contract has its own variable
+        assert_eq!(lookup(&vars, Contract::Store).unwrap(), "0xabc");
+        assert!(lookup(&vars, Contract::FossilVerifier).is_err());
+        assert!(lookup(&vars, Contract::L1MessageProxy).is_err());
+
+        // The manifest takes precedence
+        vars.insert(MANIFEST_VAR.to_string(), path.display().to_string());
+        assert_eq!(lookup(&vars, Contract::Store).unwrap(), address(1));
+        assert_eq!(lookup(&vars, Contract::FossilVerifier).unwrap(), address(2));
+        assert_eq!(
+            lookup(&vars, Contract::Groth16Verifier).unwrap(),
+            address(3)
+        );
+        assert_eq!(lookup(&vars, Contract::L1MessageProxy).unwrap(), address(4));
+
+        vars.insert(MANIFEST_VAR.to_string(), "/nonexistent.json".to_string());
+        assert!(lookup(&vars, Contract::Store).is_err());
+    }
+}
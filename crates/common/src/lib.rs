@@ -8,6 +8,9 @@ use std::{
     str::FromStr,
 };
 
+pub mod deployment;
+pub mod signer;
+
 /// Retrieves an environment variable or returns an error if not set.
 pub fn get_env_var(key: &str) -> Result<String> {
     Ok(dotenv::var(key)?)
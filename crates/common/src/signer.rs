@@ -0,0 +1,410 @@
+//! Where long-running services get their signing keys from.
+//!
+//! A key is configured per account with environment variables sharing a prefix
+//! (`STARKNET` for the publisher and client, `ACCOUNT` for the relayer). Exactly one
+//! of the following must be set:
+//!
+//! - `<PREFIX>_KEYSTORE`: path to an encrypted JSON keystore, as written by starkli
+//!   and foundry's `cast wallet`. The password is read from
+//!   `<PREFIX>_KEYSTORE_PASSWORD_FILE`, or prompted for on the terminal.
+//! - `<PREFIX>_REMOTE_SIGNER_URL`: base URL of a remote signer. A bearer token for it
+//!   can be put in the file named by `<PREFIX>_REMOTE_SIGNER_TOKEN_FILE`.
+//! - `<PREFIX>_PRIVATE_KEY`: the raw hex key, for local development only.
+//!
+//! The remote signer speaks two JSON endpoints:
+//!
+//! - `GET /public_key` returns `{"public_key": "0x..."}`: the Starknet public key, or
+//!   the Ethereum address.
+//! - `POST /sign` with `{"hash": "0x..."}` returns `{"signature": ["0x...", ...]}`:
+//!   `[r, s]` for Starknet, `[r, s, v]` for Ethereum, with `v` the y parity.
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+const REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone)]
+pub enum KeySource {
+    /// Raw secret key, hex encoded
+    Local(String),
+    Remote(RemoteSignerConfig),
+}
+
+/// Keeps secrets out of logs
+impl fmt::Debug for KeySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Local(_) => f.write_str("Local(..)"),
+            Self::Remote(config) => f.debug_tuple("Remote").field(config).finish(),
+        }
+    }
+}
+
+impl KeySource {
+    /// Reads the key configured with `prefix` from the environment
+    pub fn from_env(prefix: &str) -> Result<Self> {
+        Self::from_vars(prefix, |name| crate::get_env_var(name).ok())
+    }
+
+    /// Like [`Self::from_env`], reading variables through `var`
+    pub fn from_vars(prefix: &str, var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let var = |suffix: &str| {
+            var(&format!("{}_{}", prefix, suffix)).filter(|value| !value.trim().is_empty())
+        };
+
+        let private_key = var("PRIVATE_KEY");
+        let keystore = var("KEYSTORE");
+        let remote_signer = var("REMOTE_SIGNER_URL");
+        match (private_key, keystore, remote_signer) {
+            (Some(private_key), None, None) => Ok(Self::Local(private_key.trim().to_string())),
+            (None, Some(keystore), None) => {
+                let password = match var("KEYSTORE_PASSWORD_FILE") {
+                    Some(file) => read_secret_file(Path::new(&file))?,
+                    None => rpassword::prompt_password(format!(
+                        "Enter password for keystore {}: ",
+                        keystore
+                    ))
+                    .wrap_err("Failed to read keystore password")?,
+                };
+                Self::from_keystore(Path::new(&keystore), &password)
+            }
+            (None, None, Some(url)) => {
+                let token = var("REMOTE_SIGNER_TOKEN_FILE")
+                    .map(|file| read_secret_file(Path::new(&file)))
+                    .transpose()?;
+                Ok(Self::Remote(RemoteSignerConfig { url, token }))
+            }
+            (None, None, None) => Err(eyre!(
+                "No signing key configured: set {0}_KEYSTORE, {0}_REMOTE_SIGNER_URL or {0}_PRIVATE_KEY",
+                prefix
+            )),
+            _ => Err(eyre!(
+                "Only one of {0}_KEYSTORE, {0}_REMOTE_SIGNER_URL and {0}_PRIVATE_KEY may be set",
+                prefix
+            )),
+        }
+    }
+
+    /// Decrypts the keystore at `path`
+    pub fn from_keystore(path: &Path, password: &str) -> Result<Self> {
+        let secret = eth_keystore::decrypt_key(path, password)
+            .map_err(|e| eyre!("Failed to decrypt keystore {}: {}", path.display(), e))?;
+        Ok(Self::Local(format!("0x{}", hex::encode(secret))))
+    }
+}
+
+/// Reads a password or token from `path`, dropping the trailing newline
+fn read_secret_file(path: &Path) -> Result<String> {
+    let secret = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read {}", path.display()))?;
+    Ok(secret.trim_end_matches(['\r', '\n']).to_string())
+}
+
+#[derive(Clone)]
+pub struct RemoteSignerConfig {
+    pub url: String,
+    pub token: Option<String>,
+}
+
+impl fmt::Debug for RemoteSignerConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteSignerConfig")
+            .field("url", &self.url)
+            .field("token", &self.token.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct PublicKeyResponse {
+    public_key: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignRequest {
+    hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignResponse {
+    signature: Vec<String>,
+}
+
+/// Client for the remote signer protocol described in the module docs
+#[derive(Clone, Debug)]
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    config: RemoteSignerConfig,
+}
+
+impl RemoteSigner {
+    pub fn new(config: RemoteSignerConfig) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REMOTE_SIGNER_TIMEOUT)
+            .build()?;
+        Ok(Self { client, config })
+    }
+
+    fn endpoint(&self, path: &str) -> String {
+        format!("{}/{}", self.config.url.trim_end_matches('/'), path)
+    }
+
+    fn authorize(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.config.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    /// The public key, or address, the signer signs for
+    pub async fn public_key(&self) -> Result<String> {
+        let response: PublicKeyResponse = self
+            .authorize(self.client.get(self.endpoint("public_key")))
+            .send()
+            .await?
+            .error_for_status()
+            .wrap_err("Remote signer refused to return its public key")?
+            .json()
+            .await?;
+        Ok(response.public_key)
+    }
+
+    /// Signs `hash`, returning the signature's components as hex strings
+    pub async fn sign(&self, hash: &str) -> Result<Vec<String>> {
+        let response: SignResponse = self
+            .authorize(self.client.post(self.endpoint("sign")))
+            .json(&SignRequest {
+                hash: hash.to_string(),
+            })
+            .send()
+            .await?
+            .error_for_status()
+            .wrap_err("Remote signer refused to sign")?
+            .json()
+            .await?;
+        Ok(response.signature)
+    }
+}
+
+/// A local server speaking the remote signer protocol, for tests
+#[cfg(any(test, feature = "mock"))]
+pub mod mock {
+    use super::*;
+    use std::sync::Arc;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// Serves `public_key` and signs with `sign` until the runtime shuts down.
+    /// Requests must carry `token` as a bearer token, if one is given. Returns the
+    /// base URL.
+    pub async fn serve<F>(public_key: String, token: Option<String>, sign: F) -> String
+    where
+        F: Fn(&str) -> Vec<String> + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let sign = Arc::new(sign);
+
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let (public_key, token, sign) = (public_key.clone(), token.clone(), sign.clone());
+                tokio::spawn(async move {
+                    let _ = handle(socket, &public_key, token.as_deref(), sign.as_ref()).await;
+                });
+            }
+        });
+
+        url
+    }
+
+    async fn handle(
+        mut socket: TcpStream,
+        public_key: &str,
+        token: Option<&str>,
+        sign: &(dyn Fn(&str) -> Vec<String> + Send + Sync),
+    ) -> std::io::Result<()> {
+        let (head, body) = read_request(&mut socket).await?;
+        let request_line = head.lines().next().unwrap_or_default().to_string();
+        let authorized = token.is_none_or(|token| {
+            head.lines().any(|line| {
+                line.to_ascii_lowercase().starts_with("authorization:")
+                    && line.ends_with(&format!("Bearer {}", token))
+            })
+        });
+
+        let (status, body) = if !authorized {
+            ("401 Unauthorized", String::new())
+        } else if request_line.starts_with("GET /public_key ") {
+            let response = PublicKeyResponse {
+                public_key: public_key.to_string(),
+            };
+            ("200 OK", serde_json::to_string(&response).unwrap())
+        } else if request_line.starts_with("POST /sign ") {
+            match serde_json::from_slice::<SignRequest>(&body) {
+                Ok(request) => {
+                    let response = SignResponse {
+                        signature: sign(&request.hash),
+                    };
+                    ("200 OK", serde_json::to_string(&response).unwrap())
+                }
+                Err(_) => ("400 Bad Request", String::new()),
+            }
+        } else {
+            ("404 Not Found", String::new())
+        };
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        socket.write_all(response.as_bytes()).await?;
+        socket.shutdown().await
+    }
+
+    /// Reads the request head and a body of `Content-Length` bytes
+    async fn read_request(socket: &mut TcpStream) -> std::io::Result<(String, Vec<u8>)> {
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 1024];
+        loop {
+            if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+                let head = String::from_utf8_lossy(&buffer[..end]).to_string();
+                let length = head
+                    .lines()
+                    .filter_map(|line| line.split_once(':'))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                    .unwrap_or(0);
+                let body_start = end + 4;
+                while buffer.len() < body_start + length {
+                    let read = socket.read(&mut chunk).await?;
+                    if read == 0 {
+                        break;
+                    }
+                    buffer.extend_from_slice(&chunk[..read]);
+                }
+                let body_end = buffer.len().min(body_start + length);
+                return Ok((head, buffer[body_start..body_end].to_vec()));
+            }
+
+            let read = socket.read(&mut chunk).await?;
+            if read == 0 {
+                return Ok((String::from_utf8_lossy(&buffer).to_string(), Vec::new()));
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    const SECRET: [u8; 32] = [7u8; 32];
+
+    fn vars(pairs: &[(&str, String)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = pairs
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_private_key() {
+        let source = KeySource::from_vars(
+            "STARKNET",
+            vars(&[("STARKNET_PRIVATE_KEY", "0x1234".into())]),
+        )
+        .unwrap();
+        assert!(matches!(&source, KeySource::Local(key) if key == "0x1234"));
+        assert_eq!(format!("{:?}", source), "Local(..)");
+    }
+
+    #[test]
+    fn test_keystore_with_password_file() {
+        let dir = TempDir::new().unwrap();
+        let mut rng = rand::thread_rng();
+        let name =
+            eth_keystore::encrypt_key(dir.path(), &mut rng, SECRET, "hunter2", None).unwrap();
+        let password_file = dir.path().join("password");
+        std::fs::write(&password_file, "hunter2\n").unwrap();
+
+        let keystore_vars = || {
+            vars(&[
+                (
+                    "ACCOUNT_KEYSTORE",
+                    dir.path().join(&name).display().to_string(),
+                ),
+                (
+                    "ACCOUNT_KEYSTORE_PASSWORD_FILE",
+                    password_file.display().to_string(),
+                ),
+            ])
+        };
+
+        let source = KeySource::from_vars("ACCOUNT", keystore_vars()).unwrap();
+        assert!(
+            matches!(source, KeySource::Local(key) if key == format!("0x{}", hex::encode(SECRET)))
+        );
+
+        std::fs::write(&password_file, "wrong").unwrap();
+        let err = KeySource::from_vars("ACCOUNT", keystore_vars()).unwrap_err();
+        assert!(err.to_string().contains("Failed to decrypt keystore"));
+    }
+
+    #[test]
+    fn test_ambiguous_or_missing_key() {
+        let err = KeySource::from_vars(
+            "STARKNET",
+            vars(&[
+                ("STARKNET_PRIVATE_KEY", "0x1234".into()),
+                ("STARKNET_REMOTE_SIGNER_URL", "http://localhost".into()),
+            ]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Only one of"));
+
+        let err = KeySource::from_vars("STARKNET", vars(&[])).unwrap_err();
+        assert!(err.to_string().contains("No signing key configured"));
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer() {
+        let dir = TempDir::new().unwrap();
+        let token_file = dir.path().join("token");
+        std::fs::write(&token_file, "secret-token\n").unwrap();
+
+        let url = mock::serve("0xabc".into(), Some("secret-token".into()), |hash| {
+            vec![hash.to_string(), "0x2".to_string()]
+        })
+        .await;
+        let source = KeySource::from_vars(
+            "STARKNET",
+            vars(&[
+                ("STARKNET_REMOTE_SIGNER_URL", url.clone()),
+                (
+                    "STARKNET_REMOTE_SIGNER_TOKEN_FILE",
+                    token_file.display().to_string(),
+                ),
+            ]),
+        )
+        .unwrap();
+        let KeySource::Remote(config) = source else {
+            panic!("expected a remote signer");
+        };
+        assert!(!format!("{:?}", config).contains("secret-token"));
+
+        let signer = RemoteSigner::new(config).unwrap();
+        assert_eq!(signer.public_key().await.unwrap(), "0xabc");
+        assert_eq!(signer.sign("0x1").await.unwrap(), vec!["0x1", "0x2"]);
+
+        let unauthorized = RemoteSigner::new(RemoteSignerConfig { url, token: None }).unwrap();
+        assert!(unauthorized.public_key().await.is_err());
+    }
+}
@@ -0,0 +1,244 @@
+use crate::hour::HOUR_IN_SECONDS;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    Overflow,
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("fee arithmetic overflowed"),
+        }
+    }
+}
+
+/// Base fee, gas used and timestamp of one block that contributes to an hour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSample {
+    pub base_fee: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+}
+
+/// Fee statistics of one hour, in the same order as the journal's `AvgFees`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HourlyFees {
+    pub timestamp: u64,
+    pub data_points: u64,
+    pub avg_fee: u64,
+    pub min_fee: u64,
+    pub max_fee: u64,
+    pub median_fee: u64,
+    pub weighted_avg_fee: u64,
+    pub twap_fee: u64,
+    pub blob_base_fee: u64,
+}
+
+fn mean(total: u128, count: u128) -> Result<u64, MathError> {
+    u64::try_from(total / count).map_err(|_| MathError::Overflow)
+}
+
+/// Aggregates the samples of the hour starting at `hour`. `blob_fees` holds one blob
+/// base fee per block that has one. Returns `Ok(None)` when there are no samples.
+pub fn aggregate(
+    hour: u64,
+    samples: &[FeeSample],
+    blob_fees: &[u128],
+) -> Result<Option<HourlyFees>, MathError> {
+    if samples.is_empty() {
+        return Ok(None);
+    }
+
+    let mut total_fees: u128 = 0;
+    let mut total_gas: u128 = 0;
+    let mut gas_weighted: u128 = 0;
+    for sample in samples {
+        total_fees = total_fees
+            .checked_add(sample.base_fee as u128)
+            .ok_or(MathError::Overflow)?;
+        total_gas = total_gas
+            .checked_add(sample.gas_used as u128)
+            .ok_or(MathError::Overflow)?;
+        gas_weighted = (sample.base_fee as u128)
+            .checked_mul(sample.gas_used as u128)
+            .and_then(|weighted| gas_weighted.checked_add(weighted))
+            .ok_or(MathError::Overflow)?;
+    }
+    let avg_fee = mean(total_fees, samples.len() as u128)?;
+
+    let mut sorted_fees: Vec<u64> = samples.iter().map(|s| s.base_fee).collect();
+    sorted_fees.sort_unstable();
+    let len = sorted_fees.len();
+    let median_fee = if len % 2 == 1 {
+        sorted_fees[len / 2]
+    } else {
+        mean(
+            sorted_fees[len / 2 - 1] as u128 + sorted_fees[len / 2] as u128,
+            2,
+        )?
+    };
+
+    let weighted_avg_fee = if total_gas == 0 {
+        avg_fee
+    } else {
+        mean(gas_weighted, total_gas)?
+    };
+
+    // Each base fee holds until the next block, the last one until the end of the hour
+    let mut by_time: Vec<FeeSample> = samples.to_vec();
+    by_time.sort_by_key(|s| s.timestamp);
+    let hour_end = hour + HOUR_IN_SECONDS;
+    let mut time_weighted: u128 = 0;
+    let mut total_time: u128 = 0;
+    for (i, sample) in by_time.iter().enumerate() {
+        let until = by_time.get(i + 1).map_or(hour_end, |next| next.timestamp);
+        let duration = until.saturating_sub(sample.timestamp) as u128;
+        time_weighted = (sample.base_fee as u128)
+            .checked_mul(duration)
+            .and_then(|weighted| time_weighted.checked_add(weighted))
+            .ok_or(MathError::Overflow)?;
+        total_time += duration;
+    }
+    let twap_fee = if total_time == 0 {
+        avg_fee
+    } else {
+        mean(time_weighted, total_time)?
+    };
+
+    let blob_base_fee = if blob_fees.is_empty() {
+        0
+    } else {
+        let total = blob_fees
+            .iter()
+            .try_fold(0u128, |acc, fee| acc.checked_add(*fee))
+            .ok_or(MathError::Overflow)?;
+        mean(total, blob_fees.len() as u128)?
+    };
+
+    Ok(Some(HourlyFees {
+        timestamp: hour,
+        data_points: len as u64,
+        avg_fee,
+        min_fee: sorted_fees[0],
+        max_fee: sorted_fees[len - 1],
+        median_fee,
+        weighted_avg_fee,
+        twap_fee,
+        blob_base_fee,
+    }))
+}
+
+/// Integer approximation of `factor * e ** (numerator / denominator)` as specified in EIP-4844.
+/// Fails instead of clamping when an intermediate term does not fit in a `u128`.
+pub fn fake_exponential(
+    factor: u128,
+    numerator: u128,
+    denominator: u128,
+) -> Result<u128, MathError> {
+    let mut i: u128 = 1;
+    let mut output: u128 = 0;
+    let mut numerator_accum = factor.checked_mul(denominator).ok_or(MathError::Overflow)?;
+    while numerator_accum > 0 {
+        output = output
+            .checked_add(numerator_accum)
+            .ok_or(MathError::Overflow)?;
+        let divisor = denominator.checked_mul(i).ok_or(MathError::Overflow)?;
+        numerator_accum = numerator_accum
+            .checked_mul(numerator)
+            .ok_or(MathError::Overflow)?
+            / divisor;
+        i += 1;
+    }
+    Ok(output / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use proptest::prelude::*;
+
+    const HOUR: u64 = 1_739_984_400;
+
+    fn samples(entries: &[(u64, u64)]) -> Vec<FeeSample> {
+        entries
+            .iter()
+            .enumerate()
+            .map(|(i, (base_fee, gas_used))| FeeSample {
+                base_fee: *base_fee,
+                gas_used: *gas_used,
+                timestamp: HOUR + i as u64 * 12,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let fees = aggregate(HOUR, &samples(&[(100, 1), (200, 3), (300, 0)]), &[1, 3])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(fees.data_points, 3);
+        assert_eq!(fees.avg_fee, 200);
+        assert_eq!(fees.min_fee, 100);
+        assert_eq!(fees.max_fee, 300);
+        assert_eq!(fees.median_fee, 200);
+        assert_eq!(fees.weighted_avg_fee, 175);
+        assert_eq!(fees.blob_base_fee, 2);
+        assert_eq!(aggregate(HOUR, &[], &[1]), Ok(None));
+    }
+
+    #[test]
+    fn test_twap_weights_by_duration() {
+        let samples = vec![
+            FeeSample {
+                base_fee: 100,
+                gas_used: 1,
+                timestamp: HOUR,
+            },
+            FeeSample {
+                base_fee: 400,
+                gas_used: 1,
+                timestamp: HOUR + 2700,
+            },
+        ];
+        // 100 for 45 minutes, 400 for 15 minutes
+        let fees = aggregate(HOUR, &samples, &[]).unwrap().unwrap();
+        assert_eq!(fees.twap_fee, 175);
+    }
+
+    #[test]
+    fn test_overflow_is_reported() {
+        let entries = [(u64::MAX, u64::MAX); 3];
+        assert_eq!(
+            aggregate(HOUR, &samples(&entries), &[]),
+            Err(MathError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_fake_exponential() {
+        assert_eq!(fake_exponential(1, 0, 3_338_477), Ok(1));
+        assert_eq!(fake_exponential(1, 3_338_477, 3_338_477), Ok(2));
+        assert_eq!(fake_exponential(1000, 3_338_477, 3_338_477), Ok(2718));
+    }
+
+    #[test]
+    fn test_fake_exponential_overflow_is_reported() {
+        assert_eq!(
+            fake_exponential(1, u64::MAX as u128, 3_338_477),
+            Err(MathError::Overflow)
+        );
+        assert_eq!(fake_exponential(u128::MAX, 0, 2), Err(MathError::Overflow));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_quantity_roundtrip(value in any::<u128>()) {
+            prop_assert_eq!(crate::parse_quantity(&alloc::format!("0x{:x}", value)), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use alloc::vec::Vec;
+
+pub const HOUR_IN_SECONDS: u64 = 3600;
+
+/// Start of the hour containing `timestamp`
+pub const fn hour_start(timestamp: u64) -> u64 {
+    timestamp - timestamp % HOUR_IN_SECONDS
+}
+
+pub const fn is_hour_aligned(timestamp: u64) -> bool {
+    hour_start(timestamp) == timestamp
+}
+
+pub const fn same_hour(a: u64, b: u64) -> bool {
+    a / HOUR_IN_SECONDS == b / HOUR_IN_SECONDS
+}
+
+/// Groups consecutive `(timestamp, item)` pairs that fall in the same hour, keyed by
+/// the start of that hour. Input order is preserved, so an hour that reappears after a
+/// different one starts a new group.
+pub fn bucket_by_hour<T>(items: impl IntoIterator<Item = (u64, T)>) -> Vec<(u64, Vec<T>)> {
+    let mut buckets: Vec<(u64, Vec<T>)> = Vec::new();
+
+    for (timestamp, item) in items {
+        let hour = hour_start(timestamp);
+        match buckets.last_mut() {
+            Some((current, group)) if *current == hour => group.push(item),
+            _ => buckets.push((hour, alloc::vec![item])),
+        }
+    }
+
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_hour_start() {
+        assert_eq!(hour_start(0), 0);
+        assert_eq!(hour_start(3599), 0);
+        assert_eq!(hour_start(3600), 3600);
+        assert_eq!(hour_start(1_739_987_999), 1_739_984_400);
+        assert!(is_hour_aligned(1_739_984_400));
+        assert!(!is_hour_aligned(1_739_984_401));
+        assert!(same_hour(1_739_984_400, 1_739_987_999));
+        assert!(!same_hour(1_739_984_400, 1_739_988_000));
+    }
+
+    #[test]
+    fn test_bucket_by_hour() {
+        let buckets = bucket_by_hour(vec![(10, 'a'), (3599, 'b'), (3600, 'c'), (7300, 'd')]);
+        assert_eq!(
+            buckets,
+            vec![(0, vec!['a', 'b']), (3600, vec!['c']), (7200, vec!['d'])]
+        );
+        assert!(bucket_by_hour(Vec::<(u64, ())>::new()).is_empty());
+    }
+}
@@ -0,0 +1,14 @@
+//! Fee arithmetic shared by the `mmr-build` guest and the publisher, so both sides
+//! bucket blocks into hours and aggregate fees in exactly the same way.
+#![cfg_attr(not(test), no_std)]
+#![deny(unused_crate_dependencies)]
+
+extern crate alloc;
+
+pub mod aggregate;
+pub mod hour;
+pub mod quantity;
+
+pub use aggregate::{aggregate, fake_exponential, FeeSample, HourlyFees, MathError};
+pub use hour::{bucket_by_hour, hour_start, is_hour_aligned, same_hour, HOUR_IN_SECONDS};
+pub use quantity::{parse_quantity, QuantityError};
@@ -0,0 +1,59 @@
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantityError {
+    MissingPrefix,
+    Empty,
+    TooLong,
+    InvalidDigit,
+}
+
+impl fmt::Display for QuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => f.write_str("quantity is not 0x-prefixed"),
+            Self::Empty => f.write_str("quantity has no digits"),
+            Self::TooLong => f.write_str("quantity does not fit in 128 bits"),
+            Self::InvalidDigit => f.write_str("quantity contains a non-hex digit"),
+        }
+    }
+}
+
+/// Parses a `0x`-prefixed hex quantity of at most 128 bits.
+pub fn parse_quantity(raw: &str) -> Result<u128, QuantityError> {
+    let digits = raw.strip_prefix("0x").ok_or(QuantityError::MissingPrefix)?;
+    if digits.is_empty() {
+        return Err(QuantityError::Empty);
+    }
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(QuantityError::InvalidDigit);
+    }
+
+    let significant = digits.trim_start_matches('0');
+    if significant.len() > 32 {
+        return Err(QuantityError::TooLong);
+    }
+    if significant.is_empty() {
+        return Ok(0);
+    }
+    u128::from_str_radix(significant, 16).map_err(|_| QuantityError::InvalidDigit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quantity() {
+        assert_eq!(parse_quantity("0x0"), Ok(0));
+        assert_eq!(parse_quantity("0x3b9aca00"), Ok(1_000_000_000));
+        assert_eq!(parse_quantity("0x000000ff"), Ok(255));
+        assert_eq!(parse_quantity("0x"), Err(QuantityError::Empty));
+        assert_eq!(parse_quantity("ff"), Err(QuantityError::MissingPrefix));
+        assert_eq!(parse_quantity("0x+f"), Err(QuantityError::InvalidDigit));
+        assert_eq!(
+            parse_quantity("0x1ffffffffffffffffffffffffffffffff"),
+            Err(QuantityError::TooLong)
+        );
+    }
+}
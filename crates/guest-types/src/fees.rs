@@ -0,0 +1,485 @@
+use crate::{
+    fork::{blob_base_fee, ForkSchedule},
+    header::Header,
+    AvgFees,
+};
+use eth_rlp_types::BlockHeader;
+use fee_math::{FeeSample, HourlyFees, MathError};
+use std::fmt;
+
+pub use fee_math::HOUR_IN_SECONDS;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeeError {
+    UnsupportedChain(u64),
+    UnalignedHour {
+        timestamp: i64,
+    },
+    MissingField {
+        block_number: u64,
+        field: &'static str,
+    },
+    MalformedField {
+        block_number: u64,
+        field: &'static str,
+        value: String,
+    },
+    WrongHour {
+        block_number: u64,
+        hour: u64,
+    },
+    Overflow {
+        hour: u64,
+    },
+}
+
+impl fmt::Display for FeeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedChain(chain_id) => {
+                write!(f, "No fork schedule known for chain ID {}", chain_id)
+            }
+            Self::UnalignedHour { timestamp } => {
+                write!(
+                    f,
+                    "Claimed timestamp {} is not exactly on the hour",
+                    timestamp
+                )
+            }
+            Self::MissingField {
+                block_number,
+                field,
+            } => write!(f, "Block {} has no {}", block_number, field),
+            Self::MalformedField {
+                block_number,
+                field,
+                value,
+            } => write!(
+                f,
+                "Block {} has a malformed {}: {:?}",
+                block_number, field, value
+            ),
+            Self::WrongHour { block_number, hour } => write!(
+                f,
+                "Block {} does not belong to the hour starting at {}",
+                block_number, hour
+            ),
+            Self::Overflow { hour } => {
+                write!(f, "Fee arithmetic overflowed for hour {}", hour)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FeeError {}
+
+impl From<HourlyFees> for AvgFees {
+    fn from(fees: HourlyFees) -> Self {
+        Self::new(
+            fees.timestamp,
+            fees.data_points,
+            fees.avg_fee,
+            fees.min_fee,
+            fees.max_fee,
+            fees.median_fee,
+            fees.weighted_avg_fee,
+            fees.twap_fee,
+            fees.blob_base_fee,
+        )
+    }
+}
+
+/// Parses an optional header quantity. `None` and the empty string mean the field is
+/// absent; anything else must be a `0x`-prefixed hex value that fits in a u64.
+fn parse_field(
+    block_number: u64,
+    field: &'static str,
+    value: &Option<String>,
+) -> Result<Option<u64>, FeeError> {
+    let raw = match value.as_deref() {
+        None | Some("") => return Ok(None),
+        Some(raw) => raw,
+    };
+
+    fee_math::parse_quantity(raw)
+        .ok()
+        .and_then(|quantity| u64::try_from(quantity).ok())
+        .map(Some)
+        .ok_or_else(|| FeeError::MalformedField {
+            block_number,
+            field,
+            value: raw.to_string(),
+        })
+}
+
+fn header_timestamp(header: &BlockHeader) -> Result<u64, FeeError> {
+    let block_number = header.number as u64;
+    parse_field(block_number, "timestamp", &header.timestamp)?.ok_or(FeeError::MissingField {
+        block_number,
+        field: "timestamp",
+    })
+}
+
+/// Splits consecutive headers into hourly groups keyed by the start of their hour.
+/// This is the grouping the guest expects in `CombinedInput`.
+pub fn group_headers_by_hour(headers: Vec<Header>) -> Result<Vec<(i64, Vec<Header>)>, FeeError> {
+    let timestamped = headers
+        .into_iter()
+        .map(|header| Ok((header_timestamp(&header)?, header)))
+        .collect::<Result<Vec<_>, FeeError>>()?;
+
+    Ok(fee_math::bucket_by_hour(timestamped)
+        .into_iter()
+        .map(|(hour, group)| (hour as i64, group))
+        .collect())
+}
+
+/// Summarises the base and blob fees of the headers of one hour.
+///
+/// Every header must carry a well-formed timestamp inside the claimed hour. Headers
+/// without a base fee (pre-London) do not contribute, and `Ok(None)` is returned when
+/// none of the headers does. Malformed fee fields are rejected rather than skipped.
+pub fn hourly_fees(
+    chain_id: u64,
+    claimed_timestamp: i64,
+    hour_group: &[Header],
+) -> Result<Option<AvgFees>, FeeError> {
+    let schedule = ForkSchedule::for_chain(chain_id).ok_or(FeeError::UnsupportedChain(chain_id))?;
+
+    if claimed_timestamp < 0 || !fee_math::is_hour_aligned(claimed_timestamp as u64) {
+        return Err(FeeError::UnalignedHour {
+            timestamp: claimed_timestamp,
+        });
+    }
+    let hour = claimed_timestamp as u64;
+
+    let mut samples: Vec<FeeSample> = Vec::with_capacity(hour_group.len());
+    let mut blob_fees: Vec<u128> = Vec::new();
+
+    for header in hour_group {
+        let block_number = header.number as u64;
+        let timestamp = header_timestamp(header)?;
+        if !fee_math::same_hour(timestamp, hour) {
+            return Err(FeeError::WrongHour { block_number, hour });
+        }
+
+        if let Some(excess_blob_gas) =
+            parse_field(block_number, "excess_blob_gas", &header.excess_blob_gas)?
+        {
+            let fork = schedule.fork_at(block_number, timestamp);
+            let blob_fee = blob_base_fee(fork, excess_blob_gas)
+                .map_err(|MathError::Overflow| FeeError::Overflow { hour })?;
+            blob_fees.extend(blob_fee);
+        }
+
+        let Some(base_fee) =
+            parse_field(block_number, "base_fee_per_gas", &header.base_fee_per_gas)?
+        else {
+            continue;
+        };
+        let gas_used = u64::try_from(header.gas_used).map_err(|_| FeeError::MalformedField {
+            block_number,
+            field: "gas_used",
+            value: header.gas_used.to_string(),
+        })?;
+
+        samples.push(FeeSample {
+            base_fee,
+            gas_used,
+            timestamp,
+        });
+    }
+
+    fee_math::aggregate(hour, &samples, &blob_fees)
+        .map(|fees| fees.map(AvgFees::from))
+        .map_err(|MathError::Overflow| FeeError::Overflow { hour })
+}
+
+/// Computes the `avg_fees` the guest will commit for `headers`, so the host can reject
+/// bad input before proving and cross-check the journal afterwards.
+pub fn predict_avg_fees(
+    chain_id: u64,
+    headers: &[(i64, Vec<Header>)],
+) -> Result<Vec<AvgFees>, FeeError> {
+    let mut avg_fees = Vec::with_capacity(headers.len());
+    for (claimed_timestamp, hour_group) in headers {
+        avg_fees.extend(hourly_fees(chain_id, *claimed_timestamp, hour_group)?);
+    }
+    Ok(avg_fees)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fork::MAINNET_CHAIN_ID;
+    use proptest::prelude::*;
+    use std::collections::BTreeMap;
+
+    // An hour after Cancun on mainnet
+    const HOUR: u64 = 1_739_984_400;
+
+    fn header(number: i64, timestamp: u64, base_fee: Option<String>, gas_used: i64) -> Header {
+        BlockHeader {
+            block_hash: "0x01".to_string(),
+            number,
+            gas_limit: 30_000_000,
+            gas_used,
+            nonce: "0x0".to_string(),
+            transaction_root: None,
+            receipts_root: None,
+            state_root: None,
+            base_fee_per_gas: base_fee,
+            parent_hash: None,
+            ommers_hash: None,
+            miner: None,
+            logs_bloom: None,
+            difficulty: None,
+            totaldifficulty: None,
+            sha3_uncles: None,
+            timestamp: Some(format!("0x{:x}", timestamp)),
+            extra_data: None,
+            mix_hash: None,
+            withdrawals_root: Some("0x02".to_string()),
+            blob_gas_used: Some("0x0".to_string()),
+            excess_blob_gas: Some("0x0".to_string()),
+            parent_beacon_block_root: Some("0x03".to_string()),
+        }
+        .into()
+    }
+
+    fn hour_of(fees: &[(u64, i64)]) -> Vec<Header> {
+        fees.iter()
+            .enumerate()
+            .map(|(i, (fee, gas))| {
+                header(
+                    21_000_000 + i as i64,
+                    HOUR + i as u64 * 12,
+                    Some(format!("0x{:x}", fee)),
+                    *gas,
+                )
+            })
+            .collect()
+    }
+
+    /// Per-hour statistics of `(timestamp, base fee, gas used)` blocks sorted by
+    /// timestamp, computed without fee-math
+    fn reference_avg_fees(blocks: &[(u64, u64, u64)]) -> Vec<AvgFees> {
+        let mut hours: BTreeMap<u64, Vec<(u64, u128, u128)>> = BTreeMap::new();
+        for &(timestamp, fee, gas) in blocks {
+            hours
+                .entry(timestamp - timestamp % 3600)
+                .or_default()
+                .push((timestamp, fee as u128, gas as u128));
+        }
+
+        hours
+            .into_iter()
+            .map(|(hour, blocks)| {
+                let count = blocks.len() as u128;
+                let avg = blocks.iter().map(|b| b.1).sum::<u128>() / count;
+
+                let mut sorted: Vec<u128> = blocks.iter().map(|b| b.1).collect();
+                sorted.sort();
+                let mid = sorted.len() / 2;
+                let median = if sorted.len() % 2 == 1 {
+                    sorted[mid]
+                } else {
+                    (sorted[mid - 1] + sorted[mid]) / 2
+                };
+
+                let gas = blocks.iter().map(|b| b.2).sum::<u128>();
+                let weighted = blocks
+                    .iter()
+                    .map(|b| b.1 * b.2)
+                    .sum::<u128>()
+                    .checked_div(gas)
+                    .unwrap_or(avg);
+
+                // A fee holds until the next block, the last one until the end of the hour
+                let hour_end = hour + 3600;
+                let ends = blocks.iter().skip(1).map(|b| b.0).chain([hour_end]);
+                let twap = blocks
+                    .iter()
+                    .zip(ends)
+                    .map(|(b, end)| b.1 * (end - b.0) as u128)
+                    .sum::<u128>()
+                    / (hour_end - blocks[0].0) as u128;
+
+                AvgFees::new(
+                    hour,
+                    count as u64,
+                    avg as u64,
+                    sorted[0] as u64,
+                    sorted[sorted.len() - 1] as u64,
+                    median as u64,
+                    weighted as u64,
+                    twap as u64,
+                    // Zero excess blob gas
+                    1,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_hourly_fees() {
+        let headers = hour_of(&[(100, 1), (200, 3), (300, 0)]);
+        let fees = hourly_fees(MAINNET_CHAIN_ID, HOUR as i64, &headers)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(fees.timestamp(), HOUR);
+        assert_eq!(fees.data_points(), 3);
+        assert_eq!(fees.avg_fee(), 200);
+        assert_eq!(fees.min_fee(), 100);
+        assert_eq!(fees.max_fee(), 300);
+        assert_eq!(fees.median_fee(), 200);
+        assert_eq!(fees.weighted_avg_fee(), 175);
+        assert_eq!(fees.blob_base_fee(), 1);
+    }
+
+    #[test]
+    fn test_missing_base_fee_does_not_count() {
+        let mut headers = hour_of(&[(100, 1), (300, 1)]);
+        headers.push(header(21_000_002, HOUR + 24, Some(String::new()), 1));
+
+        let fees = hourly_fees(MAINNET_CHAIN_ID, HOUR as i64, &headers)
+            .unwrap()
+            .unwrap();
+        assert_eq!(fees.data_points(), 2);
+        assert_eq!(fees.avg_fee(), 200);
+    }
+
+    #[test]
+    fn test_hour_without_base_fees() {
+        let headers = vec![header(21_000_000, HOUR, None, 1)];
+        assert_eq!(
+            hourly_fees(MAINNET_CHAIN_ID, HOUR as i64, &headers),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_malformed_fee_is_rejected() {
+        for bad in ["0xzz", "12", "0x", "0x1ffffffffffffffff"] {
+            let headers = vec![header(21_000_000, HOUR, Some(bad.to_string()), 1)];
+            assert!(matches!(
+                hourly_fees(MAINNET_CHAIN_ID, HOUR as i64, &headers),
+                Err(FeeError::MalformedField {
+                    field: "base_fee_per_gas",
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_group_headers_by_hour() {
+        let mut headers = hour_of(&[(100, 1), (200, 1)]);
+        headers.push(header(
+            21_000_002,
+            HOUR + HOUR_IN_SECONDS,
+            Some("0x1".to_string()),
+            1,
+        ));
+
+        let grouped = group_headers_by_hour(headers).unwrap();
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].0, HOUR as i64);
+        assert_eq!(grouped[0].1.len(), 2);
+        assert_eq!(grouped[1].0, (HOUR + HOUR_IN_SECONDS) as i64);
+
+        let mut bad = hour_of(&[(100, 1)]).remove(0).into_block();
+        bad.timestamp = Some("not-hex".to_string());
+        assert!(matches!(
+            group_headers_by_hour(vec![bad.into()]),
+            Err(FeeError::MalformedField {
+                field: "timestamp",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_hour_checks() {
+        let headers = hour_of(&[(100, 1)]);
+        assert_eq!(
+            hourly_fees(MAINNET_CHAIN_ID, HOUR as i64 + 1, &headers),
+            Err(FeeError::UnalignedHour {
+                timestamp: HOUR as i64 + 1
+            })
+        );
+        assert!(matches!(
+            hourly_fees(MAINNET_CHAIN_ID, (HOUR - HOUR_IN_SECONDS) as i64, &headers),
+            Err(FeeError::WrongHour { .. })
+        ));
+    }
+
+    #[test]
+    fn test_overflow_is_reported() {
+        let headers = hour_of(&[
+            (u64::MAX, i64::MAX),
+            (u64::MAX, i64::MAX),
+            (u64::MAX, i64::MAX),
+        ]);
+        assert_eq!(
+            hourly_fees(MAINNET_CHAIN_ID, HOUR as i64, &headers),
+            Err(FeeError::Overflow { hour: HOUR })
+        );
+    }
+
+    proptest! {
+        // Grouping and aggregation must match a direct computation of each hour's
+        // statistics from the raw blocks
+        #[test]
+        fn prop_predicted_fees_match_reference(
+            blocks in prop::collection::vec((any::<u64>(), 0..60_000_000i64, 1u64..60), 1..400)
+        ) {
+            let mut timestamp = HOUR;
+            let headers: Vec<Header> = blocks
+                .iter()
+                .enumerate()
+                .map(|(i, (fee, gas, gap))| {
+                    timestamp += gap;
+                    header(21_000_000 + i as i64, timestamp, Some(format!("0x{:x}", fee)), *gas)
+                })
+                .collect();
+            let raw: Vec<(u64, u64, u64)> = headers
+                .iter()
+                .zip(&blocks)
+                .map(|(header, (fee, gas, _))| (header_timestamp(header).unwrap(), *fee, *gas as u64))
+                .collect();
+
+            let grouped = group_headers_by_hour(headers).unwrap();
+            let predicted = predict_avg_fees(MAINNET_CHAIN_ID, &grouped).unwrap();
+            prop_assert_eq!(predicted, reference_avg_fees(&raw));
+        }
+
+        #[test]
+        fn prop_only_contributing_headers_are_averaged(
+            fees in prop::collection::vec(any::<u64>(), 1..100),
+            missing in 1usize..20,
+        ) {
+            let entries: Vec<(u64, i64)> = fees.iter().map(|fee| (*fee, 1)).collect();
+            let mut headers = hour_of(&entries);
+            for i in 0..missing {
+                headers.push(header(22_000_000 + i as i64, HOUR + 3000, None, 1));
+            }
+
+            let with_missing = hourly_fees(MAINNET_CHAIN_ID, HOUR as i64, &headers).unwrap().unwrap();
+            prop_assert_eq!(with_missing.data_points(), fees.len() as u64);
+            let expected = fees.iter().map(|fee| *fee as u128).sum::<u128>() / fees.len() as u128;
+            prop_assert_eq!(with_missing.avg_fee() as u128, expected);
+        }
+
+        #[test]
+        fn prop_garbage_fee_is_rejected(fee in "[^0-9a-fA-F]{1,8}") {
+            let headers = vec![header(21_000_000, HOUR, Some(format!("0x{}", fee)), 1)];
+            let is_malformed = matches!(
+                hourly_fees(MAINNET_CHAIN_ID, HOUR as i64, &headers),
+                Err(FeeError::MalformedField { .. })
+            );
+            prop_assert!(is_malformed);
+        }
+    }
+}
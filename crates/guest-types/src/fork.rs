@@ -0,0 +1,419 @@
+use crate::header::Header;
+use fee_math::MathError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+pub const MAINNET_CHAIN_ID: u64 = 1;
+pub const SEPOLIA_CHAIN_ID: u64 = 11155111;
+
+const MIN_BLOB_BASE_FEE: u128 = 1;
+
+/// Hard forks that change the set of fields carried by a block header.
+///
+/// Paris (the merge) keeps the London header layout, so it is folded into `London`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum Fork {
+    PreLondon = 0,
+    London = 1,
+    Shanghai = 2,
+    Cancun = 3,
+    Prague = 4,
+}
+
+impl Fork {
+    pub const fn id(self) -> u8 {
+        self as u8
+    }
+
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Self::PreLondon),
+            1 => Some(Self::London),
+            2 => Some(Self::Shanghai),
+            3 => Some(Self::Cancun),
+            4 => Some(Self::Prague),
+            _ => None,
+        }
+    }
+
+    /// EIP-4844 `BLOB_BASE_FEE_UPDATE_FRACTION`, raised by EIP-7691 in Prague
+    pub const fn blob_base_fee_update_fraction(self) -> Option<u128> {
+        match self {
+            Self::Cancun => Some(3_338_477),
+            Self::Prague => Some(5_007_716),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Fork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::PreLondon => "pre-London",
+            Self::London => "London",
+            Self::Shanghai => "Shanghai",
+            Self::Cancun => "Cancun",
+            Self::Prague => "Prague",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Activation points of the header-changing forks for a given chain.
+/// London activates by block number, every later fork by timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForkSchedule {
+    london_block: u64,
+    shanghai_time: u64,
+    cancun_time: u64,
+    prague_time: u64,
+}
+
+impl ForkSchedule {
+    pub const fn for_chain(chain_id: u64) -> Option<Self> {
+        match chain_id {
+            MAINNET_CHAIN_ID => Some(Self {
+                london_block: 12_965_000,
+                shanghai_time: 1_681_338_455,
+                cancun_time: 1_710_338_135,
+                prague_time: 1_746_612_311,
+            }),
+            SEPOLIA_CHAIN_ID => Some(Self {
+                // Sepolia started with London
+                london_block: 0,
+                shanghai_time: 1_677_557_088,
+                cancun_time: 1_706_655_072,
+                prague_time: 1_741_159_776,
+            }),
+            _ => None,
+        }
+    }
+
+    pub const fn fork_at(&self, block_number: u64, timestamp: u64) -> Fork {
+        if block_number < self.london_block {
+            Fork::PreLondon
+        } else if timestamp < self.shanghai_time {
+            Fork::London
+        } else if timestamp < self.cancun_time {
+            Fork::Shanghai
+        } else if timestamp < self.prague_time {
+            Fork::Cancun
+        } else {
+            Fork::Prague
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForkError {
+    NoHeaders,
+    UnsupportedChain(u64),
+    InvalidTimestamp {
+        block_number: u64,
+    },
+    MissingField {
+        block_number: u64,
+        fork: Fork,
+        field: &'static str,
+    },
+    UnexpectedField {
+        block_number: u64,
+        fork: Fork,
+        field: &'static str,
+    },
+}
+
+impl fmt::Display for ForkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoHeaders => f.write_str("No headers to validate"),
+            Self::UnsupportedChain(chain_id) => {
+                write!(f, "No fork schedule known for chain ID {}", chain_id)
+            }
+            Self::InvalidTimestamp { block_number } => {
+                write!(
+                    f,
+                    "Block {} has a missing or malformed timestamp",
+                    block_number
+                )
+            }
+            Self::MissingField {
+                block_number,
+                fork,
+                field,
+            } => write!(
+                f,
+                "Block {} is a {} block but has no {}",
+                block_number, fork, field
+            ),
+            Self::UnexpectedField {
+                block_number,
+                fork,
+                field,
+            } => write!(
+                f,
+                "Block {} is a {} block but carries {}",
+                block_number, fork, field
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ForkError {}
+
+/// Returns the fork of `header` on `chain_id` after checking that the header carries
+/// exactly the optional fields that fork introduces.
+///
+/// Only the presence of fields is checked here; [`crate::header::verify_headers`]
+/// encodes each header by its fork and checks its hash.
+pub fn validate_header_fork(chain_id: u64, header: &Header) -> Result<Fork, ForkError> {
+    let schedule =
+        ForkSchedule::for_chain(chain_id).ok_or(ForkError::UnsupportedChain(chain_id))?;
+
+    let block_number = header.number as u64;
+    let timestamp = header
+        .timestamp
+        .as_ref()
+        .and_then(|ts| u64::from_str_radix(ts.trim_start_matches("0x"), 16).ok())
+        .ok_or(ForkError::InvalidTimestamp { block_number })?;
+
+    let fork = schedule.fork_at(block_number, timestamp);
+
+    let fields: [(&'static str, &Option<String>, Fork); 6] = [
+        ("base_fee_per_gas", &header.base_fee_per_gas, Fork::London),
+        ("withdrawals_root", &header.withdrawals_root, Fork::Shanghai),
+        ("blob_gas_used", &header.blob_gas_used, Fork::Cancun),
+        ("excess_blob_gas", &header.excess_blob_gas, Fork::Cancun),
+        (
+            "parent_beacon_block_root",
+            &header.parent_beacon_block_root,
+            Fork::Cancun,
+        ),
+        ("requests_hash", header.requests_hash(), Fork::Prague),
+    ];
+
+    for (field, value, introduced_in) in fields {
+        let present = value.as_deref().is_some_and(|v| !v.is_empty());
+        if fork >= introduced_in && !present {
+            return Err(ForkError::MissingField {
+                block_number,
+                fork,
+                field,
+            });
+        }
+        if fork < introduced_in && present {
+            return Err(ForkError::UnexpectedField {
+                block_number,
+                fork,
+                field,
+            });
+        }
+    }
+
+    Ok(fork)
+}
+
+/// Blob base fee implied by a header's `excess_blob_gas`, or `None` before Cancun
+pub fn blob_base_fee(fork: Fork, excess_blob_gas: u64) -> Result<Option<u128>, MathError> {
+    let Some(fraction) = fork.blob_base_fee_update_fraction() else {
+        return Ok(None);
+    };
+    fee_math::fake_exponential(MIN_BLOB_BASE_FEE, excess_blob_gas as u128, fraction).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eth_rlp_types::BlockHeader;
+
+    fn header(number: i64, timestamp: u64) -> BlockHeader {
+        BlockHeader {
+            block_hash: "0x01".to_string(),
+            number,
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            nonce: "0x0".to_string(),
+            transaction_root: None,
+            receipts_root: None,
+            state_root: None,
+            base_fee_per_gas: None,
+            parent_hash: None,
+            ommers_hash: None,
+            miner: None,
+            logs_bloom: None,
+            difficulty: None,
+            totaldifficulty: None,
+            sha3_uncles: None,
+            timestamp: Some(format!("0x{:x}", timestamp)),
+            extra_data: None,
+            mix_hash: None,
+            withdrawals_root: Some(String::new()),
+            blob_gas_used: Some(String::new()),
+            excess_blob_gas: Some(String::new()),
+            parent_beacon_block_root: Some(String::new()),
+        }
+    }
+
+    fn cancun_header(number: i64, timestamp: u64) -> BlockHeader {
+        let mut h = header(number, timestamp);
+        h.base_fee_per_gas = Some("0x3b9aca00".to_string());
+        h.withdrawals_root = Some("0x02".to_string());
+        h.blob_gas_used = Some("0x0".to_string());
+        h.excess_blob_gas = Some("0x0".to_string());
+        h.parent_beacon_block_root = Some("0x03".to_string());
+        h
+    }
+
+    #[test]
+    fn test_fork_id_roundtrip() {
+        for fork in [
+            Fork::PreLondon,
+            Fork::London,
+            Fork::Shanghai,
+            Fork::Cancun,
+            Fork::Prague,
+        ] {
+            assert_eq!(Fork::from_id(fork.id()), Some(fork));
+        }
+        assert_eq!(Fork::from_id(5), None);
+    }
+
+    #[test]
+    fn test_mainnet_schedule() {
+        let schedule = ForkSchedule::for_chain(MAINNET_CHAIN_ID).unwrap();
+        assert_eq!(schedule.fork_at(12_964_999, 1_628_000_000), Fork::PreLondon);
+        assert_eq!(schedule.fork_at(12_965_000, 1_628_000_000), Fork::London);
+        assert_eq!(schedule.fork_at(17_034_870, 1_681_338_455), Fork::Shanghai);
+        assert_eq!(schedule.fork_at(19_426_587, 1_710_338_135), Fork::Cancun);
+        assert_eq!(schedule.fork_at(22_431_084, 1_746_612_311), Fork::Prague);
+    }
+
+    #[test]
+    fn test_unsupported_chain() {
+        let h = cancun_header(20_000_000, 1_720_000_000);
+        assert_eq!(
+            validate_header_fork(42, &h.into()),
+            Err(ForkError::UnsupportedChain(42))
+        );
+    }
+
+    #[test]
+    fn test_valid_cancun_header() {
+        let h = cancun_header(20_000_000, 1_720_000_000);
+        assert_eq!(
+            validate_header_fork(MAINNET_CHAIN_ID, &h.into()),
+            Ok(Fork::Cancun)
+        );
+    }
+
+    #[test]
+    fn test_cancun_header_missing_blob_fields() {
+        let mut h = cancun_header(20_000_000, 1_720_000_000);
+        h.excess_blob_gas = Some(String::new());
+        assert_eq!(
+            validate_header_fork(MAINNET_CHAIN_ID, &h.into()),
+            Err(ForkError::MissingField {
+                block_number: 20_000_000,
+                fork: Fork::Cancun,
+                field: "excess_blob_gas",
+            })
+        );
+    }
+
+    #[test]
+    fn test_shanghai_header_with_cancun_fields() {
+        let h = cancun_header(17_100_000, 1_690_000_000);
+        assert_eq!(
+            validate_header_fork(MAINNET_CHAIN_ID, &h.into()),
+            Err(ForkError::UnexpectedField {
+                block_number: 17_100_000,
+                fork: Fork::Shanghai,
+                field: "blob_gas_used",
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_timestamp() {
+        let mut h = cancun_header(20_000_000, 1_720_000_000);
+        h.timestamp = None;
+        assert_eq!(
+            validate_header_fork(MAINNET_CHAIN_ID, &h.into()),
+            Err(ForkError::InvalidTimestamp {
+                block_number: 20_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_prague_header() {
+        let mut h = cancun_header(7_836_331, 1_741_159_776);
+        assert_eq!(
+            validate_header_fork(SEPOLIA_CHAIN_ID, &h.clone().into()),
+            Err(ForkError::MissingField {
+                block_number: 7_836_331,
+                fork: Fork::Prague,
+                field: "requests_hash",
+            })
+        );
+
+        let prague = Header::new(h.clone(), Some("0x04".to_string()));
+        assert_eq!(
+            validate_header_fork(SEPOLIA_CHAIN_ID, &prague),
+            Ok(Fork::Prague)
+        );
+
+        // A Cancun header must not carry it
+        h.timestamp = Some(format!("0x{:x}", 1_741_159_764));
+        let early = Header::new(h, Some("0x04".to_string()));
+        assert_eq!(
+            validate_header_fork(SEPOLIA_CHAIN_ID, &early),
+            Err(ForkError::UnexpectedField {
+                block_number: 7_836_331,
+                fork: Fork::Cancun,
+                field: "requests_hash",
+            })
+        );
+    }
+
+    #[test]
+    fn test_early_sepolia_header_is_london() {
+        // An early Sepolia block, long before the merge: Sepolia started with London
+        let mut h = header(1_000, 1_634_965_320);
+        h.base_fee_per_gas = Some("0x8".to_string());
+        h.withdrawals_root = None;
+        h.blob_gas_used = None;
+        h.excess_blob_gas = None;
+        h.parent_beacon_block_root = None;
+        assert_eq!(
+            validate_header_fork(SEPOLIA_CHAIN_ID, &h.clone().into()),
+            Ok(Fork::London)
+        );
+
+        h.base_fee_per_gas = None;
+        assert_eq!(
+            validate_header_fork(SEPOLIA_CHAIN_ID, &h.into()),
+            Err(ForkError::MissingField {
+                block_number: 1_000,
+                fork: Fork::London,
+                field: "base_fee_per_gas",
+            })
+        );
+    }
+
+    #[test]
+    fn test_blob_base_fee() {
+        assert_eq!(blob_base_fee(Fork::Shanghai, 0), Ok(None));
+        assert_eq!(blob_base_fee(Fork::Cancun, 0), Ok(Some(1)));
+        // One update fraction of excess gas approximates e
+        assert_eq!(blob_base_fee(Fork::Cancun, 3_338_477), Ok(Some(2)));
+        assert_eq!(blob_base_fee(Fork::Prague, 3_338_477), Ok(Some(1)));
+        assert!(blob_base_fee(Fork::Cancun, 100_000_000).unwrap().unwrap() > 1_000_000_000);
+        assert_eq!(blob_base_fee(Fork::Shanghai, u64::MAX), Ok(None));
+        assert_eq!(
+            blob_base_fee(Fork::Cancun, u64::MAX),
+            Err(MathError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,540 @@
+//! Block headers as the guest hashes them.
+//!
+//! `BlockHeader` predates Prague, so [`Header`] carries the fields added since next
+//! to it. A header's hash is recomputed from the RLP encoding of its fork: every fork
+//! appends the fields it introduces to the list of the previous one.
+use crate::fork::{validate_header_fork, Fork, ForkError};
+use eth_rlp_types::BlockHeader;
+use serde::{Deserialize, Serialize};
+use std::{fmt, ops::Deref};
+use tiny_keccak::{Hasher, Keccak};
+
+/// A block header with the fields `BlockHeader` has no room for
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Header {
+    block: BlockHeader,
+    /// EIP-7685 commitment to the execution layer requests, from Prague on
+    requests_hash: Option<String>,
+}
+
+impl Header {
+    pub const fn new(block: BlockHeader, requests_hash: Option<String>) -> Self {
+        Self {
+            block,
+            requests_hash,
+        }
+    }
+
+    pub const fn block(&self) -> &BlockHeader {
+        &self.block
+    }
+
+    pub fn into_block(self) -> BlockHeader {
+        self.block
+    }
+
+    pub const fn requests_hash(&self) -> &Option<String> {
+        &self.requests_hash
+    }
+}
+
+impl From<BlockHeader> for Header {
+    fn from(block: BlockHeader) -> Self {
+        Self::new(block, None)
+    }
+}
+
+impl Deref for Header {
+    type Target = BlockHeader;
+
+    fn deref(&self) -> &BlockHeader {
+        &self.block
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HeaderError {
+    Fork(ForkError),
+    MissingField {
+        block_number: u64,
+        field: &'static str,
+    },
+    MalformedField {
+        block_number: u64,
+        field: &'static str,
+        value: String,
+    },
+    HashMismatch {
+        block_number: u64,
+        claimed: String,
+        computed: String,
+    },
+    /// The header does not follow the one before it
+    BrokenChain {
+        block_number: u64,
+    },
+}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fork(e) => e.fmt(f),
+            Self::MissingField {
+                block_number,
+                field,
+            } => write!(f, "Block {} has no {}", block_number, field),
+            Self::MalformedField {
+                block_number,
+                field,
+                value,
+            } => write!(
+                f,
+                "Block {} has a malformed {}: {:?}",
+                block_number, field, value
+            ),
+            Self::HashMismatch {
+                block_number,
+                claimed,
+                computed,
+            } => write!(
+                f,
+                "Block {} claims hash {} but its header hashes to {}",
+                block_number, claimed, computed
+            ),
+            Self::BrokenChain { block_number } => write!(
+                f,
+                "Block {} is not the child of the header before it",
+                block_number
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HeaderError {}
+
+impl From<ForkError> for HeaderError {
+    fn from(e: ForkError) -> Self {
+        Self::Fork(e)
+    }
+}
+
+/// How a header field is RLP-encoded
+#[derive(Clone, Copy)]
+enum Kind {
+    /// An integer, without leading zero bytes
+    Quantity,
+    /// A byte string of any length
+    Bytes,
+    /// A byte string of exactly this many bytes, left-padded if shorter
+    Fixed(usize),
+}
+
+/// The fields of `header` in encoding order, as far as `fork` has them
+fn fields(fork: Fork, header: &Header) -> Vec<(&'static str, Option<String>, Kind)> {
+    let quantity = |value: i64| Some(format!("0x{:x}", value));
+    let block = header.block();
+
+    let mut fields = vec![
+        ("parent_hash", block.parent_hash.clone(), Kind::Fixed(32)),
+        (
+            "ommers_hash",
+            block.ommers_hash.clone().or(block.sha3_uncles.clone()),
+            Kind::Fixed(32),
+        ),
+        ("miner", block.miner.clone(), Kind::Fixed(20)),
+        ("state_root", block.state_root.clone(), Kind::Fixed(32)),
+        (
+            "transaction_root",
+            block.transaction_root.clone(),
+            Kind::Fixed(32),
+        ),
+        (
+            "receipts_root",
+            block.receipts_root.clone(),
+            Kind::Fixed(32),
+        ),
+        ("logs_bloom", block.logs_bloom.clone(), Kind::Fixed(256)),
+        ("difficulty", block.difficulty.clone(), Kind::Quantity),
+        ("number", quantity(block.number), Kind::Quantity),
+        ("gas_limit", quantity(block.gas_limit), Kind::Quantity),
+        ("gas_used", quantity(block.gas_used), Kind::Quantity),
+        ("timestamp", block.timestamp.clone(), Kind::Quantity),
+        ("extra_data", block.extra_data.clone(), Kind::Bytes),
+        ("mix_hash", block.mix_hash.clone(), Kind::Fixed(32)),
+        ("nonce", Some(block.nonce.clone()), Kind::Fixed(8)),
+    ];
+    if fork >= Fork::London {
+        fields.push((
+            "base_fee_per_gas",
+            block.base_fee_per_gas.clone(),
+            Kind::Quantity,
+        ));
+    }
+    if fork >= Fork::Shanghai {
+        fields.push((
+            "withdrawals_root",
+            block.withdrawals_root.clone(),
+            Kind::Fixed(32),
+        ));
+    }
+    if fork >= Fork::Cancun {
+        fields.extend([
+            ("blob_gas_used", block.blob_gas_used.clone(), Kind::Quantity),
+            (
+                "excess_blob_gas",
+                block.excess_blob_gas.clone(),
+                Kind::Quantity,
+            ),
+            (
+                "parent_beacon_block_root",
+                block.parent_beacon_block_root.clone(),
+                Kind::Fixed(32),
+            ),
+        ]);
+    }
+    if fork >= Fork::Prague {
+        fields.push((
+            "requests_hash",
+            header.requests_hash.clone(),
+            Kind::Fixed(32),
+        ));
+    }
+    fields
+}
+
+/// Decodes a `0x`-prefixed hex byte string; the empty string is no bytes and an odd
+/// number of digits is read with a leading zero (`0x0`)
+fn decode_hex(block_number: u64, field: &'static str, value: &str) -> Result<Vec<u8>, HeaderError> {
+    let digits = value.strip_prefix("0x").unwrap_or(value);
+    let digits = if digits.len() % 2 == 1 {
+        format!("0{}", digits)
+    } else {
+        digits.to_string()
+    };
+    hex::decode(digits).map_err(|_| HeaderError::MalformedField {
+        block_number,
+        field,
+        value: value.to_string(),
+    })
+}
+
+/// Decodes a field into the bytes its RLP item holds
+fn field_bytes(
+    block_number: u64,
+    field: &'static str,
+    value: &str,
+    kind: Kind,
+) -> Result<Vec<u8>, HeaderError> {
+    let malformed = || HeaderError::MalformedField {
+        block_number,
+        field,
+        value: value.to_string(),
+    };
+
+    match kind {
+        Kind::Quantity => {
+            let quantity = fee_math::parse_quantity(value).map_err(|_| malformed())?;
+            let bytes = quantity.to_be_bytes();
+            let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+            Ok(bytes[leading_zeros..].to_vec())
+        }
+        Kind::Bytes => decode_hex(block_number, field, value),
+        Kind::Fixed(len) => {
+            let bytes = decode_hex(block_number, field, value)?;
+            if bytes.len() > len {
+                return Err(malformed());
+            }
+            let mut padded = vec![0; len - bytes.len()];
+            padded.extend(bytes);
+            Ok(padded)
+        }
+    }
+}
+
+/// Appends the RLP length prefix of an item with `len` bytes of payload
+fn encode_length(out: &mut Vec<u8>, len: usize, offset: u8) {
+    if len <= 55 {
+        out.push(offset + len as u8);
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let leading_zeros = len_bytes.iter().take_while(|&&b| b == 0).count();
+        out.push(offset + 55 + (len_bytes.len() - leading_zeros) as u8);
+        out.extend_from_slice(&len_bytes[leading_zeros..]);
+    }
+}
+
+fn encode_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    if let [byte] = bytes {
+        if *byte < 0x80 {
+            out.push(*byte);
+            return;
+        }
+    }
+    encode_length(out, bytes.len(), 0x80);
+    out.extend_from_slice(bytes);
+}
+
+/// RLP encoding of `header` with the fields of `fork`
+pub fn encode_header(fork: Fork, header: &Header) -> Result<Vec<u8>, HeaderError> {
+    let block_number = header.number as u64;
+
+    let mut payload = Vec::new();
+    for (field, value, kind) in fields(fork, header) {
+        let value = value
+            .filter(|v| !v.is_empty() || matches!(kind, Kind::Bytes))
+            .ok_or(HeaderError::MissingField {
+                block_number,
+                field,
+            })?;
+        encode_bytes(
+            &mut payload,
+            &field_bytes(block_number, field, &value, kind)?,
+        );
+    }
+
+    let mut out = Vec::with_capacity(payload.len() + 3);
+    encode_length(&mut out, payload.len(), 0xc0);
+    out.extend(payload);
+    Ok(out)
+}
+
+/// Keccak hash of the RLP encoding of `header` with the fields of `fork`
+pub fn header_hash(fork: Fork, header: &Header) -> Result<[u8; 32], HeaderError> {
+    let mut hash = [0; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(&encode_header(fork, header)?);
+    keccak.finalize(&mut hash);
+    Ok(hash)
+}
+
+/// Checks that every header carries the fields of its fork on `chain_id`, hashes to
+/// its `block_hash` and is the child of the header before it. Returns the fork of the
+/// last header.
+pub fn verify_headers(chain_id: u64, headers: &[Header]) -> Result<Fork, HeaderError> {
+    let mut fork = None;
+    let mut parent: Option<(u64, [u8; 32])> = None;
+
+    for header in headers {
+        let block_number = header.number as u64;
+        let header_fork = validate_header_fork(chain_id, header)?;
+
+        let computed = header_hash(header_fork, header)?;
+        let claimed = field_bytes(
+            block_number,
+            "block_hash",
+            &header.block_hash,
+            Kind::Fixed(32),
+        )?;
+        if claimed != computed {
+            return Err(HeaderError::HashMismatch {
+                block_number,
+                claimed: header.block_hash.clone(),
+                computed: format!("0x{}", hex::encode(computed)),
+            });
+        }
+
+        if let Some((parent_number, parent_hash)) = parent {
+            let parent_hash_field = header.parent_hash.as_deref().unwrap_or_default();
+            let links = field_bytes(
+                block_number,
+                "parent_hash",
+                parent_hash_field,
+                Kind::Fixed(32),
+            )?;
+            if block_number != parent_number + 1 || links != parent_hash {
+                return Err(HeaderError::BrokenChain { block_number });
+            }
+        }
+
+        parent = Some((block_number, computed));
+        fork = Some(header_fork);
+    }
+
+    fork.ok_or(HeaderError::Fork(ForkError::NoHeaders))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fork::MAINNET_CHAIN_ID;
+
+    // Hashes of the Prague and Cancun headers below, as alloy-consensus computes them
+    const PRAGUE_HASH: &str = "0x8385eb8535587de11d0b2d2dd06ecd88c202aacd7614deda33e5c45365ec3e64";
+    const PRAGUE_CHILD_HASH: &str =
+        "0xa28c84c1e1a0484e0afb482a2443ab5d76cb101baf4fa5ddbcd636026b38c220";
+    const CANCUN_HASH: &str = "0x561fe8b630c02bd0fb7649f002719d59602e9072874c501f2dc9938c860825ad";
+
+    fn repeat(byte: &str) -> Option<String> {
+        Some(format!("0x{}", byte.repeat(32)))
+    }
+
+    fn prague_header() -> Header {
+        let block = BlockHeader {
+            block_hash: PRAGUE_HASH.to_string(),
+            number: 22_431_084,
+            gas_limit: 36_000_000,
+            gas_used: 12_345_678,
+            nonce: "0x0000000000000000".to_string(),
+            transaction_root: repeat("33"),
+            receipts_root: repeat("44"),
+            state_root: repeat("22"),
+            base_fee_per_gas: Some("0x3b9aca00".to_string()),
+            parent_hash: repeat("11"),
+            ommers_hash: Some(
+                "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347".to_string(),
+            ),
+            miner: Some("0x95222290dd7278aa3ddd389cc1e1d165cc4bafe5".to_string()),
+            logs_bloom: Some(format!("0x{}", "00".repeat(256))),
+            difficulty: Some("0x0".to_string()),
+            totaldifficulty: Some("0x0".to_string()),
+            sha3_uncles: None,
+            timestamp: Some(format!("0x{:x}", 1_746_612_311)),
+            extra_data: Some(format!("0x{}", hex::encode("beaverbuild.org"))),
+            mix_hash: repeat("55"),
+            withdrawals_root: repeat("66"),
+            blob_gas_used: Some("0x60000".to_string()),
+            excess_blob_gas: Some("0x1a0000".to_string()),
+            parent_beacon_block_root: repeat("77"),
+        };
+        Header::new(
+            block,
+            Some("0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855".to_string()),
+        )
+    }
+
+    fn prague_child() -> Header {
+        let header = prague_header();
+        let requests_hash = header.requests_hash().clone();
+        let mut block = header.into_block();
+        block.block_hash = PRAGUE_CHILD_HASH.to_string();
+        block.parent_hash = Some(PRAGUE_HASH.to_string());
+        block.number += 1;
+        block.timestamp = Some(format!("0x{:x}", 1_746_612_323));
+        block.gas_used = 30_000_000;
+        Header::new(block, requests_hash)
+    }
+
+    fn hex_hash(fork: Fork, header: &Header) -> String {
+        format!("0x{}", hex::encode(header_hash(fork, header).unwrap()))
+    }
+
+    #[test]
+    fn test_mainnet_genesis_hash() {
+        let genesis = BlockHeader {
+            block_hash: "0xd4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"
+                .to_string(),
+            number: 0,
+            gas_limit: 5_000,
+            gas_used: 0,
+            nonce: "0x0000000000000042".to_string(),
+            transaction_root: Some(
+                "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421".to_string(),
+            ),
+            receipts_root: Some(
+                "0x56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421".to_string(),
+            ),
+            state_root: Some(
+                "0xd7f8974fb5ac78d9ac099b9ad5018bedc2ce0a72dad1827a1709da30580f0544".to_string(),
+            ),
+            base_fee_per_gas: None,
+            parent_hash: repeat("00"),
+            ommers_hash: None,
+            miner: Some(format!("0x{}", "00".repeat(20))),
+            logs_bloom: Some(format!("0x{}", "00".repeat(256))),
+            difficulty: Some("0x400000000".to_string()),
+            totaldifficulty: None,
+            sha3_uncles: Some(
+                "0x1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347".to_string(),
+            ),
+            timestamp: Some("0x0".to_string()),
+            extra_data: Some(
+                "0x11bbe8db4e347b4e8c937c1c8370e4b5ed33adb3db69cbdb7a38e1e50b1b82fa".to_string(),
+            ),
+            mix_hash: repeat("00"),
+            withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
+        };
+        let genesis = Header::from(genesis);
+
+        assert_eq!(hex_hash(Fork::PreLondon, &genesis), genesis.block_hash);
+        assert_eq!(
+            verify_headers(MAINNET_CHAIN_ID, &[genesis]),
+            Ok(Fork::PreLondon)
+        );
+    }
+
+    #[test]
+    fn test_prague_hash() {
+        let header = prague_header();
+        assert_eq!(hex_hash(Fork::Prague, &header), PRAGUE_HASH);
+        assert_eq!(
+            verify_headers(MAINNET_CHAIN_ID, &[header, prague_child()]),
+            Ok(Fork::Prague)
+        );
+    }
+
+    #[test]
+    fn test_encoding_follows_fork() {
+        let header = prague_header();
+        // The Cancun encoding stops before requests_hash
+        assert_eq!(hex_hash(Fork::Cancun, &header), CANCUN_HASH);
+        assert_eq!(
+            encode_header(Fork::Prague, &header).unwrap().len(),
+            encode_header(Fork::Cancun, &header).unwrap().len() + 33
+        );
+    }
+
+    #[test]
+    fn test_prague_hash_mismatch() {
+        let other = Header::new(prague_header().into_block(), repeat("88"));
+        assert!(matches!(
+            verify_headers(MAINNET_CHAIN_ID, &[other]),
+            Err(HeaderError::HashMismatch {
+                block_number: 22_431_084,
+                ..
+            })
+        ));
+
+        let missing = Header::from(prague_header().into_block());
+        assert_eq!(
+            verify_headers(MAINNET_CHAIN_ID, &[missing]),
+            Err(HeaderError::Fork(ForkError::MissingField {
+                block_number: 22_431_084,
+                fork: Fork::Prague,
+                field: "requests_hash",
+            }))
+        );
+    }
+
+    #[test]
+    fn test_broken_chain() {
+        // Each header hashes correctly, but the second does not follow the first
+        let child = prague_child();
+        assert_eq!(
+            verify_headers(MAINNET_CHAIN_ID, &[child.clone(), prague_header()]),
+            Err(HeaderError::BrokenChain {
+                block_number: 22_431_084
+            })
+        );
+        assert_eq!(
+            verify_headers(MAINNET_CHAIN_ID, &[]),
+            Err(HeaderError::Fork(ForkError::NoHeaders))
+        );
+    }
+
+    #[test]
+    fn test_malformed_field() {
+        let mut block = prague_header().into_block();
+        block.state_root = Some("0xzz".to_string());
+        assert_eq!(
+            encode_header(Fork::Prague, &Header::new(block, repeat("00"))),
+            Err(HeaderError::MalformedField {
+                block_number: 22_431_084,
+                field: "state_root",
+                value: "0xzz".to_string(),
+            })
+        );
+    }
+}
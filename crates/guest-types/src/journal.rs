@@ -0,0 +1,416 @@
+//! Versioned, ABI-stable journal encoding for `GuestOutput`.
+//!
+//! Every integer is big-endian and every hash is 32 raw bytes, so the Cairo verifier can
+//! decode it without knowing anything about RISC Zero's serde codec. Layout of version 1:
+//!
+//! | field                     | size             |
+//! |---------------------------|------------------|
+//! | version                   | 1                |
+//! | method_id                 | 32 (8 x u32)     |
+//! | hasher_id                 | 1                |
+//! | chain_id                  | 8                |
+//! | batch_index               | 8                |
+//! | latest_mmr_block          | 8                |
+//! | latest_mmr_block_hash     | 32               |
+//! | root_hash                 | 32               |
+//! | leaves_count              | 8                |
+//! | first_block_parent_hash   | 32               |
+//! | fork_id                   | 1                |
+//! | avg_fees length           | 4                |
+//! | avg_fees                  | 72 (9 x u64) each|
+//!
+//! Any change to this layout must bump `JOURNAL_VERSION` and update the Cairo
+//! `decode_journal` alongside the test vectors in `test-vectors/`.
+use crate::{AvgFees, GuestOutput};
+use std::fmt;
+
+pub const JOURNAL_VERSION: u8 = 1;
+
+/// MMR hashes are SHA-256 (see `guest_mmr::helper::hasher`)
+pub const SHA256_HASHER_ID: u8 = 1;
+
+const HEADER_SIZE: usize = 1 + 32 + 1 + 8;
+const BODY_SIZE: usize = 8 + 8 + 32 + 32 + 8 + 32 + 1 + 4;
+const AVG_FEES_SIZE: usize = 9 * 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct JournalHeader {
+    version: u8,
+    method_id: [u32; 8],
+    hasher_id: u8,
+    chain_id: u64,
+}
+
+impl JournalHeader {
+    /// Header for the current journal version. `method_id` is the image ID the host
+    /// proves with; the guest cannot compute its own, so the host checks it against the
+    /// receipt when decoding.
+    pub const fn new(method_id: [u32; 8], chain_id: u64) -> Self {
+        Self {
+            version: JOURNAL_VERSION,
+            method_id,
+            hasher_id: SHA256_HASHER_ID,
+            chain_id,
+        }
+    }
+
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    pub const fn method_id(&self) -> [u32; 8] {
+        self.method_id
+    }
+
+    pub const fn hasher_id(&self) -> u8 {
+        self.hasher_id
+    }
+
+    pub const fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JournalError {
+    UnsupportedVersion(u8),
+    UnknownHasher(u8),
+    UnexpectedEnd { offset: usize, needed: usize },
+    TrailingBytes(usize),
+    InvalidHash { field: &'static str, value: String },
+    TooManyAvgFees(usize),
+}
+
+impl fmt::Display for JournalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion(version) => {
+                write!(f, "Unsupported journal version {}", version)
+            }
+            Self::UnknownHasher(id) => write!(f, "Unknown hasher ID {}", id),
+            Self::UnexpectedEnd { offset, needed } => write!(
+                f,
+                "Journal ends at offset {} but {} more bytes are needed",
+                offset, needed
+            ),
+            Self::TrailingBytes(count) => {
+                write!(f, "Journal has {} unexpected trailing bytes", count)
+            }
+            Self::InvalidHash { field, value } => {
+                write!(f, "{} is not a 32-byte hex hash: {:?}", field, value)
+            }
+            Self::TooManyAvgFees(count) => {
+                write!(f, "{} avg fee entries do not fit in a journal", count)
+            }
+        }
+    }
+}
+
+impl std::error::Error for JournalError {}
+
+fn hash_to_bytes(field: &'static str, value: &str) -> Result<[u8; 32], JournalError> {
+    let invalid = || JournalError::InvalidHash {
+        field,
+        value: value.to_string(),
+    };
+
+    let digits = value.strip_prefix("0x").ok_or_else(invalid)?;
+    if digits.len() != 64 || !digits.is_ascii() {
+        return Err(invalid());
+    }
+
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(bytes)
+}
+
+fn bytes_to_hash(bytes: [u8; 32]) -> String {
+    let mut hash = String::with_capacity(66);
+    hash.push_str("0x");
+    for byte in bytes {
+        hash.push_str(&format!("{:02x}", byte));
+    }
+    hash
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], JournalError> {
+        let end = self.offset + N;
+        let slice =
+            self.bytes
+                .get(self.offset..end)
+                .ok_or_else(|| JournalError::UnexpectedEnd {
+                    offset: self.bytes.len(),
+                    needed: end - self.bytes.len(),
+                })?;
+        self.offset = end;
+
+        let mut out = [0u8; N];
+        out.copy_from_slice(slice);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, JournalError> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, JournalError> {
+        Ok(u32::from_be_bytes(self.take()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, JournalError> {
+        Ok(u64::from_be_bytes(self.take()?))
+    }
+
+    fn hash(&mut self) -> Result<String, JournalError> {
+        Ok(bytes_to_hash(self.take()?))
+    }
+
+    const fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+}
+
+impl GuestOutput {
+    /// Encodes the output in the versioned journal layout described in this module.
+    pub fn encode(&self) -> Result<Vec<u8>, JournalError> {
+        let avg_fees_len = u32::try_from(self.avg_fees.len())
+            .map_err(|_| JournalError::TooManyAvgFees(self.avg_fees.len()))?;
+
+        let mut out =
+            Vec::with_capacity(HEADER_SIZE + BODY_SIZE + self.avg_fees.len() * AVG_FEES_SIZE);
+
+        out.push(self.header.version);
+        for word in self.header.method_id {
+            out.extend_from_slice(&word.to_be_bytes());
+        }
+        out.push(self.header.hasher_id);
+        out.extend_from_slice(&self.header.chain_id.to_be_bytes());
+
+        out.extend_from_slice(&self.batch_index.to_be_bytes());
+        out.extend_from_slice(&self.latest_mmr_block.to_be_bytes());
+        out.extend_from_slice(&hash_to_bytes(
+            "latest_mmr_block_hash",
+            &self.latest_mmr_block_hash,
+        )?);
+        out.extend_from_slice(&hash_to_bytes("root_hash", &self.root_hash)?);
+        out.extend_from_slice(&(self.leaves_count as u64).to_be_bytes());
+        out.extend_from_slice(&hash_to_bytes(
+            "first_block_parent_hash",
+            &self.first_block_parent_hash,
+        )?);
+        out.push(self.fork_id);
+
+        out.extend_from_slice(&avg_fees_len.to_be_bytes());
+        for fees in &self.avg_fees {
+            for value in [
+                fees.timestamp,
+                fees.data_points,
+                fees.avg_fee,
+                fees.min_fee,
+                fees.max_fee,
+                fees.median_fee,
+                fees.weighted_avg_fee,
+                fees.twap_fee,
+                fees.blob_base_fee,
+            ] {
+                out.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Decodes a journal produced by [`GuestOutput::encode`], rejecting unknown
+    /// versions and hashers as well as truncated or oversized input.
+    pub fn decode(bytes: &[u8]) -> Result<Self, JournalError> {
+        let mut reader = Reader::new(bytes);
+
+        let version = reader.u8()?;
+        if version != JOURNAL_VERSION {
+            return Err(JournalError::UnsupportedVersion(version));
+        }
+        let mut method_id = [0u32; 8];
+        for word in &mut method_id {
+            *word = reader.u32()?;
+        }
+        let hasher_id = reader.u8()?;
+        if hasher_id != SHA256_HASHER_ID {
+            return Err(JournalError::UnknownHasher(hasher_id));
+        }
+        let header = JournalHeader {
+            version,
+            method_id,
+            hasher_id,
+            chain_id: reader.u64()?,
+        };
+
+        let batch_index = reader.u64()?;
+        let latest_mmr_block = reader.u64()?;
+        let latest_mmr_block_hash = reader.hash()?;
+        let root_hash = reader.hash()?;
+        let leaves_count = reader.u64()? as usize;
+        let first_block_parent_hash = reader.hash()?;
+        let fork_id = reader.u8()?;
+
+        let avg_fees_len = reader.u32()? as usize;
+        if reader.remaining() < avg_fees_len.saturating_mul(AVG_FEES_SIZE) {
+            return Err(JournalError::UnexpectedEnd {
+                offset: bytes.len(),
+                needed: avg_fees_len * AVG_FEES_SIZE - reader.remaining(),
+            });
+        }
+        let mut avg_fees = Vec::with_capacity(avg_fees_len);
+        for _ in 0..avg_fees_len {
+            avg_fees.push(AvgFees::new(
+                reader.u64()?,
+                reader.u64()?,
+                reader.u64()?,
+                reader.u64()?,
+                reader.u64()?,
+                reader.u64()?,
+                reader.u64()?,
+                reader.u64()?,
+                reader.u64()?,
+            ));
+        }
+
+        if reader.remaining() != 0 {
+            return Err(JournalError::TrailingBytes(reader.remaining()));
+        }
+
+        Ok(Self {
+            header,
+            batch_index,
+            latest_mmr_block,
+            latest_mmr_block_hash,
+            root_hash,
+            leaves_count,
+            first_block_parent_hash,
+            avg_fees,
+            fork_id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fork::{Fork, SEPOLIA_CHAIN_ID};
+
+    const JOURNAL_V1: &str = include_str!("../test-vectors/journal_v1.hex");
+
+    fn vector_output() -> GuestOutput {
+        GuestOutput::new(
+            JournalHeader::new(
+                [
+                    0x11111111, 0x22222222, 0x33333333, 0x44444444, 0x55555555, 0x66666666,
+                    0x77777777, 0x88888888,
+                ],
+                SEPOLIA_CHAIN_ID,
+            ),
+            21369,
+            21882622,
+            "0x930046a42d2e9ae094e48890903f998d6edf12265aad7f5f620be4507e961d48".to_string(),
+            "0x930aa189e5be10188debe34e6df8930d0bce5ee7c61ff26222e5a33e2ce421fb".to_string(),
+            767,
+            "0x85350e4a9d2bd94d4b2bb6b9e2a09a3e8f6b85c7e39b04de6d8f5eb4fa32d0c3".to_string(),
+            vec![
+                AvgFees::new(
+                    1739984400, 210, 1356994173, 1210000000, 1520000000, 1350000000, 1361000000,
+                    1355000000, 1,
+                ),
+                AvgFees::new(
+                    1739988000, 297, 957746452, 880000000, 1050000000, 951000000, 962000000,
+                    955000000, 1,
+                ),
+                AvgFees::new(
+                    1739991600, 260, 864421784, 790000000, 940000000, 860000000, 870000000,
+                    866000000, 2,
+                ),
+            ],
+            Fork::Cancun.id(),
+        )
+    }
+
+    #[test]
+    fn test_encode_matches_vector() {
+        let expected = hex::decode(JOURNAL_V1.trim()).unwrap();
+        assert_eq!(vector_output().encode().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_decode_vector() {
+        let bytes = hex::decode(JOURNAL_V1.trim()).unwrap();
+        let output = GuestOutput::decode(&bytes).unwrap();
+
+        assert_eq!(output.header().version(), JOURNAL_VERSION);
+        assert_eq!(output.header().hasher_id(), SHA256_HASHER_ID);
+        assert_eq!(output.header().chain_id(), SEPOLIA_CHAIN_ID);
+        assert_eq!(output.header().method_id()[7], 0x88888888);
+        assert_eq!(output.batch_index(), 21369);
+        assert_eq!(output.leaves_count(), 767);
+        assert_eq!(
+            output.root_hash(),
+            "0x930aa189e5be10188debe34e6df8930d0bce5ee7c61ff26222e5a33e2ce421fb"
+        );
+        assert_eq!(output.fork(), Some(Fork::Cancun));
+        assert_eq!(output.avg_fees().len(), 3);
+        assert_eq!(output.avg_fees()[2].blob_base_fee(), 2);
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_input() {
+        let bytes = hex::decode(JOURNAL_V1.trim()).unwrap();
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[0] = 2;
+        assert_eq!(
+            GuestOutput::decode(&wrong_version).unwrap_err(),
+            JournalError::UnsupportedVersion(2)
+        );
+
+        let mut wrong_hasher = bytes.clone();
+        wrong_hasher[33] = 9;
+        assert_eq!(
+            GuestOutput::decode(&wrong_hasher).unwrap_err(),
+            JournalError::UnknownHasher(9)
+        );
+
+        assert!(matches!(
+            GuestOutput::decode(&bytes[..bytes.len() - 1]),
+            Err(JournalError::UnexpectedEnd { .. })
+        ));
+
+        let mut trailing = bytes;
+        trailing.push(0);
+        assert_eq!(
+            GuestOutput::decode(&trailing).unwrap_err(),
+            JournalError::TrailingBytes(1)
+        );
+    }
+
+    #[test]
+    fn test_encode_rejects_malformed_hash() {
+        let mut output = vector_output();
+        output.root_hash = "0x1234".to_string();
+        assert!(matches!(
+            output.encode(),
+            Err(JournalError::InvalidHash {
+                field: "root_hash",
+                ..
+            })
+        ));
+    }
+}
@@ -1,6 +1,12 @@
 #![deny(unused_crate_dependencies)]
 
+pub mod fees;
+pub mod fork;
+pub mod header;
+pub mod journal;
+
 use eth_rlp_types::BlockHeader;
+use header::Header;
 use risc0_zkvm::Receipt;
 use serde::{Deserialize, Serialize};
 
@@ -58,29 +64,116 @@ impl AppendResult {
     }
 }
 
+// AvgFees
+/// Fee summary for one hour of blocks. Field order is part of the journal layout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvgFees {
+    timestamp: u64,
+    data_points: u64,
+    avg_fee: u64,
+    min_fee: u64,
+    max_fee: u64,
+    median_fee: u64,
+    // Base fee weighted by gas used
+    weighted_avg_fee: u64,
+    // Base fee weighted by the time until the next block
+    twap_fee: u64,
+    // Mean blob base fee derived from `excess_blob_gas`, 0 before Cancun
+    blob_base_fee: u64,
+}
+
+impl AvgFees {
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        timestamp: u64,
+        data_points: u64,
+        avg_fee: u64,
+        min_fee: u64,
+        max_fee: u64,
+        median_fee: u64,
+        weighted_avg_fee: u64,
+        twap_fee: u64,
+        blob_base_fee: u64,
+    ) -> Self {
+        Self {
+            timestamp,
+            data_points,
+            avg_fee,
+            min_fee,
+            max_fee,
+            median_fee,
+            weighted_avg_fee,
+            twap_fee,
+            blob_base_fee,
+        }
+    }
+
+    pub const fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub const fn data_points(&self) -> u64 {
+        self.data_points
+    }
+
+    pub const fn avg_fee(&self) -> u64 {
+        self.avg_fee
+    }
+
+    pub const fn min_fee(&self) -> u64 {
+        self.min_fee
+    }
+
+    pub const fn max_fee(&self) -> u64 {
+        self.max_fee
+    }
+
+    pub const fn median_fee(&self) -> u64 {
+        self.median_fee
+    }
+
+    pub const fn weighted_avg_fee(&self) -> u64 {
+        self.weighted_avg_fee
+    }
+
+    pub const fn twap_fee(&self) -> u64 {
+        self.twap_fee
+    }
+
+    pub const fn blob_base_fee(&self) -> u64 {
+        self.blob_base_fee
+    }
+}
+
 // GuestOutput
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GuestOutput {
+    header: journal::JournalHeader,
     batch_index: u64,
     latest_mmr_block: u64,
     latest_mmr_block_hash: String,
     root_hash: String,
     leaves_count: usize,
     first_block_parent_hash: String,
-    avg_fees: Vec<(usize, usize, u64)>, // (timestamp, data_points, avg_fee)
+    avg_fees: Vec<AvgFees>,
+    fork_id: u8,
 }
 
 impl GuestOutput {
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
+        header: journal::JournalHeader,
         batch_index: u64,
         latest_mmr_block: u64,
         latest_mmr_block_hash: String,
         root_hash: String,
         leaves_count: usize,
         first_block_parent_hash: String,
-        avg_fees: Vec<(usize, usize, u64)>,
+        avg_fees: Vec<AvgFees>,
+        fork_id: u8,
     ) -> Self {
         Self {
+            header,
             batch_index,
             latest_mmr_block,
             latest_mmr_block_hash,
@@ -88,9 +181,14 @@ impl GuestOutput {
             leaves_count,
             first_block_parent_hash,
             avg_fees,
+            fork_id,
         }
     }
 
+    pub const fn header(&self) -> &journal::JournalHeader {
+        &self.header
+    }
+
     pub const fn latest_mmr_block(&self) -> u64 {
         self.latest_mmr_block
     }
@@ -114,6 +212,18 @@ impl GuestOutput {
     pub fn first_block_parent_hash(&self) -> &str {
         &self.first_block_parent_hash
     }
+
+    pub fn avg_fees(&self) -> &[AvgFees] {
+        &self.avg_fees
+    }
+
+    pub const fn fork_id(&self) -> u8 {
+        self.fork_id
+    }
+
+    pub const fn fork(&self) -> Option<fork::Fork> {
+        fork::Fork::from_id(self.fork_id)
+    }
 }
 
 // CombinedInput
@@ -121,7 +231,8 @@ impl GuestOutput {
 pub struct CombinedInput {
     chain_id: u64,
     batch_size: u64,
-    headers: Vec<(i64, Vec<BlockHeader>)>, // (representative_timestamp, headers)
+    method_id: [u32; 8],
+    headers: Vec<(i64, Vec<Header>)>, // (representative_timestamp, headers)
     mmr_input: MMRInput,
 }
 
@@ -129,12 +240,14 @@ impl CombinedInput {
     pub const fn new(
         chain_id: u64,
         batch_size: u64,
-        headers: Vec<(i64, Vec<BlockHeader>)>,
+        method_id: [u32; 8],
+        headers: Vec<(i64, Vec<Header>)>,
         mmr_input: MMRInput,
     ) -> Self {
         Self {
             chain_id,
             batch_size,
+            method_id,
             headers,
             mmr_input,
         }
@@ -148,7 +261,11 @@ impl CombinedInput {
         self.batch_size
     }
 
-    pub const fn headers(&self) -> &Vec<(i64, Vec<BlockHeader>)> {
+    pub const fn method_id(&self) -> [u32; 8] {
+        self.method_id
+    }
+
+    pub const fn headers(&self) -> &Vec<(i64, Vec<Header>)> {
         &self.headers
     }
 
@@ -303,13 +420,18 @@ mod tests {
     #[test]
     fn test_guest_output() {
         let output = GuestOutput::new(
+            journal::JournalHeader::new([1; 8], 1),
             1,
             100,
             "block_hash".to_string(),
             "root_hash".to_string(),
             50,
             "first_block_parent_hash".to_string(),
-            vec![(0, 0, 100), (1, 0, 200), (2, 0, 300), (3, 0, 400)],
+            vec![
+                AvgFees::new(0, 2, 100, 90, 110, 100, 105, 98, 1),
+                AvgFees::new(3600, 1, 200, 200, 200, 200, 200, 200, 1),
+            ],
+            fork::Fork::Cancun.id(),
         );
 
         assert_eq!(output.batch_index(), 1);
@@ -318,16 +440,21 @@ mod tests {
         assert_eq!(output.root_hash(), "root_hash");
         assert_eq!(output.leaves_count(), 50);
         assert_eq!(output.first_block_parent_hash(), "first_block_parent_hash");
+        assert_eq!(output.fork(), Some(fork::Fork::Cancun));
+        assert_eq!(output.avg_fees().len(), 2);
+        assert_eq!(output.avg_fees()[0].median_fee(), 100);
+        assert_eq!(output.avg_fees()[1].timestamp(), 3600);
     }
 
     #[test]
     fn test_combined_input() {
         let mmr_input = MMRInput::new(vec!["peak1".to_string()], 10, 5, vec!["elem1".to_string()]);
 
-        let input = CombinedInput::new(1, 100, Vec::new(), mmr_input.clone());
+        let input = CombinedInput::new(1, 100, [1; 8], Vec::new(), mmr_input.clone());
 
         assert_eq!(input.chain_id(), 1);
         assert_eq!(input.batch_size(), 100);
+        assert_eq!(input.method_id(), [1; 8]);
         assert!(input.headers().is_empty());
 
         // Test MMRInput getters
@@ -0,0 +1,433 @@
+//! Async HTTP plumbing shared by the storage backends: bounded timeouts, retries with
+//! exponential backoff, and streaming downloads that never hold a whole file in memory.
+use eyre::{eyre, Result};
+use reqwest::{multipart, Client, Method, StatusCode};
+use std::env;
+use std::path::Path;
+use std::time::Duration;
+use tokio::fs::{self, File};
+use tokio::io::AsyncWriteExt;
+use tracing::warn;
+
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(60);
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Timeouts and retry policy for batch store requests
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpConfig {
+    /// Time allowed to establish a connection
+    pub connect_timeout: Duration,
+    /// Longest silence tolerated while waiting for response bytes
+    pub read_timeout: Duration,
+    /// Extra attempts made after a transient failure
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on every further attempt
+    pub initial_backoff: Duration,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            read_timeout: DEFAULT_READ_TIMEOUT,
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+        }
+    }
+}
+
+impl HttpConfig {
+    /// Reads `BATCH_STORE_CONNECT_TIMEOUT_SECS`, `BATCH_STORE_READ_TIMEOUT_SECS`,
+    /// `BATCH_STORE_MAX_RETRIES` and `BATCH_STORE_RETRY_BACKOFF_MS`, falling back to
+    /// the defaults for unset variables.
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+        Ok(Self {
+            connect_timeout: env_u64("BATCH_STORE_CONNECT_TIMEOUT_SECS")?
+                .map_or(defaults.connect_timeout, Duration::from_secs),
+            read_timeout: env_u64("BATCH_STORE_READ_TIMEOUT_SECS")?
+                .map_or(defaults.read_timeout, Duration::from_secs),
+            max_retries: env_u64("BATCH_STORE_MAX_RETRIES")?
+                .map_or(Ok(defaults.max_retries), u32::try_from)
+                .map_err(|_| eyre!("BATCH_STORE_MAX_RETRIES is out of range"))?,
+            initial_backoff: env_u64("BATCH_STORE_RETRY_BACKOFF_MS")?
+                .map_or(defaults.initial_backoff, Duration::from_millis),
+        })
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_BACKOFF)
+    }
+}
+
+fn env_u64(name: &str) -> Result<Option<u64>> {
+    match env::var(name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| eyre!("{} must be a non-negative integer, got {:?}", name, value)),
+        Err(_) => Ok(None),
+    }
+}
+
+pub(crate) type Headers = Vec<(&'static str, String)>;
+
+pub(crate) enum Body {
+    Empty,
+    Bytes(Vec<u8>),
+    /// Multipart form with a single file part, preceded by the text `fields`
+    Multipart {
+        field: &'static str,
+        file_name: String,
+        bytes: Vec<u8>,
+        fields: Vec<(&'static str, String)>,
+    },
+}
+
+pub(crate) struct Response {
+    pub status: StatusCode,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn ensure_success(self, url: &str) -> Result<Self> {
+        if self.status.is_success() {
+            Ok(self)
+        } else {
+            Err(eyre!("HTTP error: {} for URL: {}", self.status, url))
+        }
+    }
+
+    pub fn json(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+/// Where a download can be served from; fallback gateways get their own headers so
+/// credentials for the primary endpoint are never sent to third parties.
+#[derive(Clone, Debug)]
+pub(crate) struct Source {
+    pub url: String,
+    pub headers: Headers,
+}
+
+/// Why an attempt failed, and whether trying again could help
+enum Failure {
+    Transient(eyre::Report),
+    Permanent(eyre::Report),
+}
+
+impl Failure {
+    fn into_report(self) -> eyre::Report {
+        match self {
+            Self::Transient(e) | Self::Permanent(e) => e,
+        }
+    }
+}
+
+fn classify_status(status: StatusCode, url: &str) -> Failure {
+    let error = eyre!("HTTP error: {} for URL: {}", status, url);
+    if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        Failure::Transient(error)
+    } else {
+        Failure::Permanent(error)
+    }
+}
+
+fn classify_error(error: reqwest::Error) -> Failure {
+    if error.is_timeout() || error.is_connect() || error.is_request() || error.is_body() {
+        Failure::Transient(error.into())
+    } else {
+        Failure::Permanent(error.into())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct HttpClient {
+    client: Client,
+    config: HttpConfig,
+}
+
+impl HttpClient {
+    pub fn new(config: HttpConfig) -> Result<Self> {
+        let client = Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .read_timeout(config.read_timeout)
+            .build()
+            .map_err(|e| eyre!("Failed to build HTTP client: {}", e))?;
+
+        Ok(Self { client, config })
+    }
+
+    /// Runs `attempt` until it succeeds, fails permanently or runs out of retries
+    async fn with_retries<T, F, Fut>(&self, url: &str, mut attempt: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, Failure>>,
+    {
+        let mut retry = 0;
+        loop {
+            match attempt().await {
+                Ok(value) => return Ok(value),
+                Err(Failure::Transient(e)) if retry < self.config.max_retries => {
+                    let delay = self.config.backoff(retry);
+                    warn!(
+                        url,
+                        error = %e,
+                        retry = retry + 1,
+                        delay_ms = delay.as_millis() as u64,
+                        "Request failed, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    retry += 1;
+                }
+                Err(failure) => return Err(failure.into_report()),
+            }
+        }
+    }
+
+    /// Sends a request and buffers the response. Only 5xx, 429 and transport errors
+    /// are retried; the response status is left for the caller to check.
+    pub async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        headers: &Headers,
+        body: Body,
+    ) -> Result<Response> {
+        self.with_retries(url, || async {
+            let mut request = self.client.request(method.clone(), url);
+            for (name, value) in headers {
+                request = request.header(*name, value);
+            }
+            request = match &body {
+                Body::Empty => request,
+                Body::Bytes(bytes) => request.body(bytes.clone()),
+                Body::Multipart {
+                    field,
+                    file_name,
+                    bytes,
+                    fields,
+                } => {
+                    let form = fields
+                        .iter()
+                        .fold(multipart::Form::new(), |form, (name, value)| {
+                            form.text(*name, value.clone())
+                        });
+                    let part = multipart::Part::bytes(bytes.clone()).file_name(file_name.clone());
+                    request.multipart(form.part(*field, part))
+                }
+            };
+
+            let response = request.send().await.map_err(classify_error)?;
+            let status = response.status();
+            if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+                return Err(classify_status(status, url));
+            }
+            let body = response.bytes().await.map_err(classify_error)?;
+
+            Ok(Response {
+                status,
+                body: body.to_vec(),
+            })
+        })
+        .await
+    }
+
+    /// Streams a GET of `source` into `output_path`, retrying transient failures.
+    /// Aborts once more than `max_bytes` arrive; the file is removed on any failure.
+    pub async fn download(
+        &self,
+        source: &Source,
+        output_path: &Path,
+        max_bytes: usize,
+    ) -> Result<()> {
+        let result = self
+            .with_retries(&source.url, || {
+                self.download_once(source, output_path, max_bytes)
+            })
+            .await;
+
+        if result.is_err() {
+            let _ = fs::remove_file(output_path).await;
+        }
+        result
+    }
+
+    async fn download_once(
+        &self,
+        source: &Source,
+        output_path: &Path,
+        max_bytes: usize,
+    ) -> Result<(), Failure> {
+        let mut request = self.client.get(&source.url);
+        for (name, value) in &source.headers {
+            request = request.header(*name, value);
+        }
+
+        let mut response = request.send().await.map_err(classify_error)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(classify_status(status, &source.url));
+        }
+
+        let io_error = |e: std::io::Error| Failure::Permanent(e.into());
+        let mut file = File::create(output_path).await.map_err(io_error)?;
+        let mut written = 0usize;
+        while let Some(chunk) = response.chunk().await.map_err(classify_error)? {
+            written += chunk.len();
+            if written > max_bytes {
+                return Err(Failure::Permanent(eyre!(
+                    "Download from {} exceeds maximum allowed size {} bytes",
+                    source.url,
+                    max_bytes
+                )));
+            }
+            file.write_all(&chunk).await.map_err(io_error)?;
+        }
+        file.sync_all().await.map_err(io_error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    /// Serves the canned `responses` in order, one per connection, then repeats the
+    /// last one. Returns the base URL and a counter of requests served.
+    pub(crate) async fn serve(responses: Vec<&'static str>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let served = Arc::new(AtomicUsize::new(0));
+
+        let counter = served.clone();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let index = counter.fetch_add(1, Ordering::SeqCst);
+                let response = responses[index.min(responses.len() - 1)];
+                let mut request = [0u8; 1024];
+                let _ = socket.read(&mut request).await;
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (url, served)
+    }
+
+    pub(crate) fn fast_config() -> HttpConfig {
+        HttpConfig {
+            connect_timeout: Duration::from_secs(1),
+            read_timeout: Duration::from_millis(200),
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+        }
+    }
+
+    pub(crate) const OK: &str =
+        "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello";
+    const UNAVAILABLE: &str =
+        "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+    pub(crate) const NOT_FOUND: &str =
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let config = HttpConfig::default();
+        assert_eq!(config.backoff(0), Duration::from_millis(500));
+        assert_eq!(config.backoff(1), Duration::from_millis(1000));
+        assert_eq!(config.backoff(3), Duration::from_millis(4000));
+        assert_eq!(config.backoff(40), MAX_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn test_download_retries_transient_errors() {
+        let (url, served) = serve(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("out");
+
+        let client = HttpClient::new(fast_config()).unwrap();
+        let source = Source {
+            url,
+            headers: Vec::new(),
+        };
+        client.download(&source, &output, 1024).await.unwrap();
+
+        assert_eq!(std::fs::read(&output).unwrap(), b"hello");
+        assert_eq!(served.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_download_does_not_retry_client_errors() {
+        let (url, served) = serve(vec![NOT_FOUND, OK]).await;
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("out");
+
+        let client = HttpClient::new(fast_config()).unwrap();
+        let source = Source {
+            url,
+            headers: Vec::new(),
+        };
+        assert!(client.download(&source, &output, 1024).await.is_err());
+        assert_eq!(served.load(Ordering::SeqCst), 1);
+        assert!(!output.exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_enforces_size_limit() {
+        let (url, _) = serve(vec![OK]).await;
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("out");
+
+        let client = HttpClient::new(fast_config()).unwrap();
+        let source = Source {
+            url,
+            headers: Vec::new(),
+        };
+        let err = client.download(&source, &output, 4).await.unwrap_err();
+        assert!(err.to_string().contains("exceeds maximum allowed size"));
+        assert!(!output.exists());
+    }
+
+    #[tokio::test]
+    async fn test_stalled_server_times_out() {
+        // Accepts connections but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut sockets = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                sockets.push(socket);
+            }
+        });
+
+        let client = HttpClient::new(HttpConfig {
+            max_retries: 0,
+            ..fast_config()
+        })
+        .unwrap();
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.send(Method::GET, &url, &Vec::new(), Body::Empty),
+        )
+        .await
+        .expect("request should time out on its own");
+        assert!(result.is_err());
+    }
+}
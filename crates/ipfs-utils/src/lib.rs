@@ -1,14 +1,29 @@
 #![deny(unused_crate_dependencies)]
 
+pub use cid_utils as cid;
+mod http;
+pub mod local;
+pub mod pinata;
+pub mod s3;
+pub mod snapshot;
+pub mod store;
+
+pub use http::HttpConfig;
+pub use local::LocalStore;
+pub use pinata::PinataStore;
+pub use s3::S3Store;
+pub use snapshot::Compression;
+pub use store::{batch_store_from_env, BatchPin, BatchStore, PinScope};
+
+use async_trait::async_trait;
 use dotenv::dotenv;
 use eyre::{eyre, Result};
+use http::{Body, HttpClient, Source};
+use reqwest::Method;
 use std::env;
-use std::fs;
-use std::io::Write;
 use std::path::Path;
-use std::str;
-use tokio::task;
-use tracing::{info, warn};
+use tracing::info;
+use url::Url;
 
 // Define constant for max file size (50MB)
 pub const DEFAULT_MAX_FILE_SIZE: usize = 50 * 1024 * 1024;
@@ -18,7 +33,10 @@ pub struct IpfsManager {
     add_url: String,
     fetch_base_url: String,
     token: String,
+    fallback_gateways: Vec<Source>,
+    http: HttpClient,
     pub max_file_size: usize,
+    pub compression: Compression,
 }
 
 impl IpfsManager {
@@ -41,131 +59,76 @@ impl IpfsManager {
             add_url,
             fetch_base_url,
             token,
+            fallback_gateways: store::fallback_gateways_from_env(),
+            http: HttpClient::new(HttpConfig::from_env()?)?,
             max_file_size: DEFAULT_MAX_FILE_SIZE,
+            compression: Compression::from_env()?,
         })
     }
 
-    pub async fn upload_db(&self, file_path: &Path) -> Result<String> {
-        let metadata = fs::metadata(file_path).map_err(|_| eyre!("File operation failed"))?;
-
-        let contents = fs::read(file_path)?;
-        if !contents.starts_with(b"SQLite format 3\0") {
-            return Err(eyre!("File is not a valid SQLite database"));
-        }
-
-        if metadata.len() as usize > self.max_file_size {
-            warn!(
-                "File size exceeds limit: {} bytes > {} bytes",
-                metadata.len(),
-                self.max_file_size
-            );
-            return Err(eyre!(
-                "File size {} bytes exceeds maximum allowed size {} bytes",
-                metadata.len(),
-                self.max_file_size,
-            ));
-        }
-
-        let mut easy = curl::easy::Easy::new();
-        easy.url(&self.add_url)?;
-
-        // Set up authorization header exactly like the curl command
-        let mut list = curl::easy::List::new();
-        list.append(&format!("Authorization: Bearer {}", self.token))?;
-        easy.http_headers(list)?;
+    /// Replaces the timeouts and retry policy read from the environment
+    pub fn with_http_config(mut self, config: HttpConfig) -> Result<Self> {
+        self.http = HttpClient::new(config)?;
+        Ok(self)
+    }
 
-        // Set POST method
-        easy.post(true)?;
+    fn auth_headers(&self) -> http::Headers {
+        vec![("Authorization", format!("Bearer {}", self.token))]
+    }
 
-        // Set up the form data
-        let mut form = curl::easy::Form::new();
-        form.part("file")
-            .file(
-                file_path
-                    .to_str()
-                    .ok_or_else(|| eyre!("Invalid file path (non-UTF8)"))?,
+    pub async fn upload_db(&self, file_path: &Path, scope: &PinScope) -> Result<String> {
+        let contents =
+            store::prepare_upload(file_path, self.max_file_size, self.compression).await?;
+
+        // Named pins are what `list_batch_pins` recognises as ours
+        let mut url = Url::parse(&self.add_url)
+            .map_err(|e| eyre!("Invalid IPFS_ADD_URL {}: {}", self.add_url, e))?;
+        url.query_pairs_mut()
+            .append_pair("pin-name", &scope.pin_name());
+
+        let response = self
+            .http
+            .send(
+                Method::POST,
+                url.as_str(),
+                &self.auth_headers(),
+                Body::Multipart {
+                    field: "file",
+                    file_name: store::upload_file_name(file_path, self.compression),
+                    bytes: contents.clone(),
+                    fields: Vec::new(),
+                },
             )
-            .add()
-            .map_err(|e| eyre!(e.to_string()))?;
-        easy.httppost(form)?;
-
-        // Capture the response
-        let mut response_data = Vec::new();
-        {
-            let mut transfer = easy.transfer();
-            transfer.write_function(|data| {
-                response_data.extend_from_slice(data);
-                Ok(data.len())
-            })?;
-            transfer.perform()?;
-        }
-
-        // Check response code
-        let response_code = easy.response_code()?;
-        if response_code != 200 {
-            return Err(eyre!(
-                "HTTP error: {} for URL: {}",
-                response_code,
-                self.add_url
-            ));
-        }
-
-        // Parse response
-        let response_str = str::from_utf8(&response_data)
-            .map_err(|e| eyre!(format!("Invalid UTF-8 sequence: {}", e)))?;
-        let response_json: serde_json::Value = serde_json::from_str(response_str)?;
+            .await?
+            .ensure_success(url.as_str())?;
 
-        let hash = response_json["Hash"]
+        let hash = response.json()?["Hash"]
             .as_str()
             .ok_or_else(|| eyre!("No hash in response"))?
             .to_string();
 
+        // The node chunks with its defaults, which `verify_cid` mirrors
+        cid::verify_cid(&hash, &contents)
+            .map_err(|e| eyre!("IPFS node returned an unexpected CID: {}", e))?;
+
         info!("IPFS upload completed successfully, CID: {}", hash);
         Ok(hash)
     }
 
+    /// Fetches `hash` from the configured gateway, then from `IPFS_FALLBACK_GATEWAYS`
+    /// in order, keeping the first copy whose contents hash to `hash`.
     pub async fn fetch_db(&self, hash: &str, output_path: &Path) -> Result<()> {
-        let fetch_url = format!("{}{}", self.fetch_base_url, hash);
-
-        let mut easy = curl::easy::Easy::new();
-        easy.url(&fetch_url)?;
-        easy.follow_location(true)?;
-
-        // Set up authorization header exactly like the curl command
-        let mut list = curl::easy::List::new();
-        list.append(&format!("Authorization: Bearer {}", self.token))?;
-        easy.http_headers(list)?;
-
-        // Create output file
-        let mut file = std::fs::File::create(output_path)?;
-
-        // Set up the transfer to write directly to file
-        {
-            let mut transfer = easy.transfer();
-            transfer.write_function(|data| {
-                file.write_all(data)
-                    .map_err(|_| curl::easy::WriteError::Pause)?;
-                Ok(data.len())
-            })?;
-            transfer.perform()?;
-        }
-
-        // Check response code after transfer
-        let response_code = easy.response_code()?;
-        if response_code != 200 {
-            // Clean up failed download
-            std::fs::remove_file(output_path)?;
-            return Err(eyre!(
-                "HTTP error: {} for URL: {}",
-                response_code,
-                fetch_url
-            ));
-        }
+        let mut gateways = vec![Source {
+            url: self.fetch_base_url.clone(),
+            headers: self.auth_headers(),
+        }];
+        gateways.extend(self.fallback_gateways.iter().cloned());
 
-        Ok(())
+        store::fetch_verified(&self.http, &gateways, hash, output_path, self.max_file_size).await
     }
 
-    pub async fn check_connection(&self) -> Result<()> {
+    /// URL of a Kubo RPC command on the node behind `IPFS_ADD_URL`
+    fn api_url(&self, command: &str) -> Result<String> {
         // Extract the base URL from add_url (remove the "/api/v0/add" part)
         let base_url = self
             .add_url
@@ -173,34 +136,85 @@ impl IpfsManager {
             .next()
             .ok_or_else(|| eyre!("Invalid IPFS_ADD_URL format: {}", self.add_url))?;
 
-        let version_url = format!("{}/api/v0/version", base_url);
-        let token = self.token.clone();
-
-        task::spawn_blocking(move || -> Result<()> {
-            let mut easy = curl::easy::Easy::new();
-            easy.url(&version_url).map_err(|e| eyre!(e.to_string()))?;
-            let header_value = format!("Authorization: Bearer {}", token);
-            let mut list = curl::easy::List::new();
-            list.append(&header_value)
-                .map_err(|e| eyre!(e.to_string()))?;
-            easy.http_headers(list).map_err(|e| eyre!(e.to_string()))?;
-            let mut response_data = Vec::new();
-            {
-                let mut transfer = easy.transfer();
-                transfer
-                    .write_function(|data| {
-                        response_data.extend_from_slice(data);
-                        Ok(data.len())
-                    })
-                    .map_err(|e| eyre!(e.to_string()))?;
-                transfer.perform().map_err(|e| eyre!(e.to_string()))?;
-            }
-            Ok(())
-        })
-        .await
-        .map_err(|e| eyre!(e.to_string()))??;
+        Ok(format!("{}/api/v0/{}", base_url, command))
+    }
+
+    /// Runs a Kubo RPC command. The RPC API only accepts POST.
+    async fn rpc(&self, command: &str) -> Result<http::Response> {
+        let url = self.api_url(command)?;
+        self.http
+            .send(Method::POST, &url, &self.auth_headers(), Body::Empty)
+            .await?
+            .ensure_success(&url)
+    }
+
+    pub async fn check_connection(&self) -> Result<()> {
+        self.rpc("version").await?;
+        Ok(())
+    }
+
+    /// Pins `cid` and everything it links to on the node
+    pub async fn pin(&self, cid: &str) -> Result<()> {
+        self.rpc(&format!("pin/add?arg={}", cid)).await?;
+        info!("Pinned {}", cid);
         Ok(())
     }
+
+    /// Removes the recursive pin on `cid`, letting the node garbage collect it
+    pub async fn unpin(&self, cid: &str) -> Result<()> {
+        self.rpc(&format!("pin/rm?arg={}", cid)).await?;
+        info!("Unpinned {}", cid);
+        Ok(())
+    }
+
+    /// Lists the recursive pins `upload_db` made for `scope`, sorted by CID. Pins
+    /// without a batch pin name of that scope belong to someone else and are skipped.
+    pub async fn list_batch_pins(&self, scope: &PinScope) -> Result<Vec<BatchPin>> {
+        let response = self.rpc("pin/ls?type=recursive&names=true").await?.json()?;
+        let keys = response["Keys"]
+            .as_object()
+            .ok_or_else(|| eyre!("No Keys in pin list response"))?;
+
+        let mut pins: Vec<BatchPin> = keys
+            .iter()
+            .filter_map(|(cid, pin)| {
+                let pinned_at = scope.parse_pin_name(pin["Name"].as_str()?)?;
+                Some(BatchPin {
+                    cid: cid.clone(),
+                    pinned_at,
+                })
+            })
+            .collect();
+        pins.sort_by(|a, b| a.cid.cmp(&b.cid));
+        Ok(pins)
+    }
+}
+
+#[async_trait]
+impl BatchStore for IpfsManager {
+    fn name(&self) -> &'static str {
+        "kubo"
+    }
+
+    async fn upload_db(&self, file_path: &Path, scope: &PinScope) -> Result<String> {
+        Self::upload_db(self, file_path, scope).await
+    }
+
+    async fn fetch_db(&self, cid: &str, output_path: &Path) -> Result<()> {
+        Self::fetch_db(self, cid, output_path).await
+    }
+
+    async fn check_connection(&self) -> Result<()> {
+        Self::check_connection(self).await
+    }
+
+    async fn list_batch_pins(&self, scope: &PinScope) -> Result<Vec<BatchPin>> {
+        Self::list_batch_pins(self, scope).await
+    }
+
+    async fn unpin(&self, cid: &str) -> Result<()> {
+        Self::unpin(self, cid).await
+    }
 }
 
 #[cfg(test)]
@@ -212,6 +226,10 @@ mod tests {
     use tokio::io::AsyncWriteExt;
 
     // Setup environment variables for tests
+    fn scope() -> PinScope {
+        PinScope::new(1, "0x123")
+    }
+
     fn setup_test_env() {
         env::set_var("IPFS_ADD_URL", "http://localhost:5001/api/v0/add");
         env::set_var("IPFS_FETCH_BASE_URL", "http://localhost/ipfs/");
@@ -249,12 +267,15 @@ mod tests {
         let temp_file = NamedTempFile::new().unwrap();
         let path = temp_file.path();
 
-        let result = manager.upload_db(path).await;
+        let result = manager.upload_db(path, &scope()).await;
         assert!(result.is_err());
 
         match result {
             Err(e) => {
-                assert_eq!(e.to_string(), "File is not a valid SQLite database");
+                assert_eq!(
+                    e.to_string(),
+                    "File is neither a SQLite database nor an MMR batch snapshot"
+                );
             }
             _ => panic!("Expected FileError with InvalidData kind"),
         }
@@ -270,7 +291,7 @@ mod tests {
         let temp_file = create_temp_db_file().await.unwrap();
         let path = temp_file.path();
 
-        let result = manager.upload_db(path).await;
+        let result = manager.upload_db(path, &scope()).await;
         assert!(result.is_err());
 
         match result {
@@ -292,7 +313,7 @@ mod tests {
 
         // Upload the file
         let hash = manager
-            .upload_db(upload_path)
+            .upload_db(upload_path, &scope())
             .await
             .expect("Failed to upload file");
         assert!(!hash.is_empty(), "Hash should not be empty");
@@ -354,6 +375,64 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_list_batch_pins() {
+        setup_test_env();
+        let body = r#"{"Keys":{
+            "QmB":{"Type":"recursive","Name":"fossil-batch-1-0x123-20"},
+            "QmA":{"Type":"recursive","Name":"fossil-batch-1-0x123-10"},
+            "QmOtherStore":{"Type":"recursive","Name":"fossil-batch-1-0x456-10"},
+            "QmOtherChain":{"Type":"recursive","Name":"fossil-batch-11155111-0x123-10"},
+            "QmOther":{"Type":"recursive","Name":"website"},
+            "QmUnnamed":{"Type":"recursive"}
+        }}"#;
+        let response: &'static str = Box::leak(
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .into_boxed_str(),
+        );
+        let (url, served) = http::tests::serve(vec![response]).await;
+
+        let mut manager = IpfsManager::with_endpoint()
+            .unwrap()
+            .with_http_config(http::tests::fast_config())
+            .unwrap();
+        manager.add_url = format!("{}/api/v0/add", url);
+
+        assert_eq!(
+            manager.list_batch_pins(&scope()).await.unwrap(),
+            vec![
+                BatchPin {
+                    cid: "QmA".to_string(),
+                    pinned_at: 10,
+                },
+                BatchPin {
+                    cid: "QmB".to_string(),
+                    pinned_at: 20,
+                },
+            ]
+        );
+        assert_eq!(served.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unpin_reports_node_errors() {
+        setup_test_env();
+        let (url, _) = http::tests::serve(vec![http::tests::NOT_FOUND]).await;
+
+        let mut manager = IpfsManager::with_endpoint()
+            .unwrap()
+            .with_http_config(http::tests::fast_config())
+            .unwrap();
+        manager.add_url = format!("{}/api/v0/add", url);
+
+        let err = manager.unpin("QmA").await.unwrap_err();
+        assert!(err.to_string().contains("/api/v0/pin/rm?arg=QmA"));
+    }
+
     #[tokio::test]
     async fn test_error_handling() {
         // Test IpfsError Display implementation
@@ -0,0 +1,190 @@
+use crate::{
+    cid::{raw_cid_v1, verify_cid},
+    snapshot::Compression,
+    store::{finish_fetch, partial_path, prepare_upload},
+    BatchStore, PinScope, DEFAULT_MAX_FILE_SIZE,
+};
+use async_trait::async_trait;
+use eyre::{eyre, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::info;
+
+/// Stores batch databases in a local directory, as files named by the raw-codec CIDv1
+/// of their content. Meant for tests and air-gapped deployments.
+#[derive(Clone, Debug)]
+pub struct LocalStore {
+    root: PathBuf,
+    pub max_file_size: usize,
+    pub compression: Compression,
+}
+
+impl LocalStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            compression: Compression::default(),
+        }
+    }
+
+    pub fn from_env() -> Result<Self> {
+        let root = env::var("LOCAL_STORE_DIR")
+            .map_err(|_| eyre!("LOCAL_STORE_DIR not found in environment"))?;
+        let mut store = Self::new(root);
+        store.compression = Compression::from_env()?;
+        Ok(store)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn path_for(&self, cid: &str) -> Result<PathBuf> {
+        if cid.is_empty() || !cid.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(eyre!("Invalid CID: {}", cid));
+        }
+        Ok(self.root.join(cid))
+    }
+}
+
+#[async_trait]
+impl BatchStore for LocalStore {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn upload_db(&self, file_path: &Path, _scope: &PinScope) -> Result<String> {
+        let contents = prepare_upload(file_path, self.max_file_size, self.compression).await?;
+        let cid = raw_cid_v1(&contents);
+
+        fs::create_dir_all(&self.root).await?;
+        let target = self.path_for(&cid)?;
+        // Write under a temporary name first so readers never see a partial file
+        let partial = partial_path(&target);
+        fs::write(&partial, &contents).await?;
+        fs::rename(&partial, &target).await?;
+
+        info!("Stored batch database locally, CID: {}", cid);
+        Ok(cid)
+    }
+
+    async fn fetch_db(&self, cid: &str, output_path: &Path) -> Result<()> {
+        let source = self.path_for(cid)?;
+        let contents = fs::read(&source)
+            .await
+            .map_err(|e| eyre!("Failed to read {} from local store: {}", cid, e))?;
+        verify_cid(cid, &contents)?;
+
+        let partial = partial_path(output_path);
+        fs::write(&partial, &contents).await?;
+        finish_fetch(&partial, output_path).await
+    }
+
+    async fn check_connection(&self) -> Result<()> {
+        fs::create_dir_all(&self.root).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn db_bytes() -> Vec<u8> {
+        let mut bytes = b"SQLite format 3\0".to_vec();
+        bytes.extend_from_slice(&[7; 1024]);
+        bytes
+    }
+
+    #[tokio::test]
+    async fn test_local_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let store = LocalStore::new(dir.path().join("store"));
+
+        let source = dir.path().join("batch.db");
+        std::fs::write(&source, db_bytes()).unwrap();
+
+        let cid = store
+            .upload_db(&source, &PinScope::new(1, "0x1"))
+            .await
+            .unwrap();
+        assert_eq!(cid, raw_cid_v1(&db_bytes()));
+        // Same content, same key
+        assert_eq!(
+            store
+                .upload_db(&source, &PinScope::new(1, "0x1"))
+                .await
+                .unwrap(),
+            cid
+        );
+
+        let output = dir.path().join("fetched.db");
+        store.fetch_db(&cid, &output).await.unwrap();
+        assert_eq!(std::fs::read(&output).unwrap(), db_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_local_rejects_bad_input() {
+        let dir = TempDir::new().unwrap();
+        let store = LocalStore::new(dir.path());
+
+        let source = dir.path().join("not-a-db");
+        std::fs::write(&source, b"hello").unwrap();
+        assert!(store
+            .upload_db(&source, &PinScope::new(1, "0x1"))
+            .await
+            .is_err());
+
+        let output = dir.path().join("out.db");
+        assert!(store.fetch_db("../etc/passwd", &output).await.is_err());
+        assert!(store.fetch_db("bafkmissing", &output).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_local_compressed_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let mut store = LocalStore::new(dir.path().join("store"));
+        store.compression = Compression::Zstd { level: 3 };
+
+        let source = dir.path().join("batch.db");
+        std::fs::write(&source, db_bytes()).unwrap();
+
+        let cid = store
+            .upload_db(&source, &PinScope::new(1, "0x1"))
+            .await
+            .unwrap();
+        let stored = std::fs::read(store.root().join(&cid)).unwrap();
+        assert!(stored.starts_with(crate::snapshot::SNAPSHOT_MAGIC));
+        assert!(stored.len() < db_bytes().len());
+
+        // Readers do not need to know the store compresses
+        let reader = LocalStore::new(store.root());
+        let output = dir.path().join("fetched.db");
+        reader.fetch_db(&cid, &output).await.unwrap();
+        assert_eq!(std::fs::read(&output).unwrap(), db_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_local_fetch_detects_corruption() {
+        let dir = TempDir::new().unwrap();
+        let store = LocalStore::new(dir.path());
+
+        let source = dir.path().join("batch.db");
+        std::fs::write(&source, db_bytes()).unwrap();
+        let cid = store
+            .upload_db(&source, &PinScope::new(1, "0x1"))
+            .await
+            .unwrap();
+
+        // Tamper with the stored copy
+        std::fs::write(dir.path().join(&cid), b"SQLite format 3\0tampered").unwrap();
+
+        let output = dir.path().join("fetched.db");
+        let err = store.fetch_db(&cid, &output).await.unwrap_err();
+        assert!(err.to_string().starts_with("CID mismatch"));
+        assert!(!output.exists());
+    }
+}
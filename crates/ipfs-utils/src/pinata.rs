@@ -0,0 +1,241 @@
+use crate::{
+    cid::verify_cid,
+    http::{Body, Headers, HttpClient, HttpConfig, Source},
+    snapshot::Compression,
+    store::{fallback_gateways_from_env, fetch_verified, prepare_upload, upload_file_name},
+    BatchPin, BatchStore, PinScope, DEFAULT_MAX_FILE_SIZE,
+};
+use async_trait::async_trait;
+use eyre::{eyre, Result};
+use reqwest::Method;
+use std::env;
+use std::path::Path;
+use tracing::info;
+use url::Url;
+
+const DEFAULT_PINATA_API_URL: &str = "https://api.pinata.cloud";
+const DEFAULT_PINATA_GATEWAY_URL: &str = "https://gateway.pinata.cloud/ipfs/";
+/// Largest page `data/pinList` returns
+const PIN_LIST_PAGE_SIZE: usize = 1000;
+
+/// Pinning service with a Pinata-style API: uploads go to `pinning/pinFileToIPFS`
+/// and content is read back through a gateway.
+#[derive(Clone, Debug)]
+pub struct PinataStore {
+    api_url: String,
+    gateway_url: String,
+    jwt: String,
+    fallback_gateways: Vec<Source>,
+    http: HttpClient,
+    pub max_file_size: usize,
+    pub compression: Compression,
+}
+
+impl PinataStore {
+    pub fn new(api_url: String, gateway_url: String, jwt: String) -> Result<Self> {
+        Ok(Self {
+            api_url: api_url.trim_end_matches('/').to_string(),
+            gateway_url,
+            jwt,
+            fallback_gateways: Vec::new(),
+            http: HttpClient::new(HttpConfig::default())?,
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            compression: Compression::default(),
+        })
+    }
+
+    pub fn from_env() -> Result<Self> {
+        let jwt =
+            env::var("PINATA_JWT").map_err(|_| eyre!("PINATA_JWT not found in environment"))?;
+        let api_url =
+            env::var("PINATA_API_URL").unwrap_or_else(|_| DEFAULT_PINATA_API_URL.to_string());
+        let gateway_url = env::var("PINATA_GATEWAY_URL")
+            .unwrap_or_else(|_| DEFAULT_PINATA_GATEWAY_URL.to_string());
+
+        let mut store =
+            Self::new(api_url, gateway_url, jwt)?.with_http_config(HttpConfig::from_env()?)?;
+        store.fallback_gateways = fallback_gateways_from_env();
+        store.compression = Compression::from_env()?;
+        Ok(store)
+    }
+
+    /// Replaces the default timeouts and retry policy
+    pub fn with_http_config(mut self, config: HttpConfig) -> Result<Self> {
+        self.http = HttpClient::new(config)?;
+        Ok(self)
+    }
+
+    fn auth_headers(&self) -> Headers {
+        vec![("Authorization", format!("Bearer {}", self.jwt))]
+    }
+
+    /// One page of the account's pins whose name contains the pin prefix of `scope`.
+    /// The name filter matches substrings, so other pins can still show up.
+    async fn pin_list_page(
+        &self,
+        scope: &PinScope,
+        offset: usize,
+    ) -> Result<Vec<serde_json::Value>> {
+        let mut url = Url::parse(&format!("{}/data/pinList", self.api_url))
+            .map_err(|e| eyre!("Invalid PINATA_API_URL {}: {}", self.api_url, e))?;
+        url.query_pairs_mut()
+            .append_pair("status", "pinned")
+            .append_pair("metadata[name]", &scope.pin_prefix())
+            .append_pair("pageLimit", &PIN_LIST_PAGE_SIZE.to_string())
+            .append_pair("pageOffset", &offset.to_string());
+
+        let response = self
+            .http
+            .send(Method::GET, url.as_str(), &self.auth_headers(), Body::Empty)
+            .await?
+            .ensure_success(url.as_str())?
+            .json()?;
+        response["rows"]
+            .as_array()
+            .cloned()
+            .ok_or_else(|| eyre!("No rows in pin list response"))
+    }
+}
+
+#[async_trait]
+impl BatchStore for PinataStore {
+    fn name(&self) -> &'static str {
+        "pinata"
+    }
+
+    async fn upload_db(&self, file_path: &Path, scope: &PinScope) -> Result<String> {
+        let contents = prepare_upload(file_path, self.max_file_size, self.compression).await?;
+
+        let url = format!("{}/pinning/pinFileToIPFS", self.api_url);
+        let response = self
+            .http
+            .send(
+                Method::POST,
+                &url,
+                &self.auth_headers(),
+                Body::Multipart {
+                    field: "file",
+                    file_name: upload_file_name(file_path, self.compression),
+                    bytes: contents.clone(),
+                    fields: vec![(
+                        "pinataMetadata",
+                        serde_json::json!({ "name": scope.pin_name() }).to_string(),
+                    )],
+                },
+            )
+            .await?
+            .ensure_success(&url)?;
+
+        let hash = response.json()?["IpfsHash"]
+            .as_str()
+            .ok_or_else(|| eyre!("No IpfsHash in response"))?
+            .to_string();
+        verify_cid(&hash, &contents)
+            .map_err(|e| eyre!("Pinata returned an unexpected CID: {}", e))?;
+
+        info!("Pinata upload completed successfully, CID: {}", hash);
+        Ok(hash)
+    }
+
+    async fn fetch_db(&self, cid: &str, output_path: &Path) -> Result<()> {
+        let mut gateways = vec![Source {
+            url: self.gateway_url.clone(),
+            headers: self.auth_headers(),
+        }];
+        gateways.extend(self.fallback_gateways.iter().cloned());
+
+        fetch_verified(&self.http, &gateways, cid, output_path, self.max_file_size).await
+    }
+
+    async fn check_connection(&self) -> Result<()> {
+        let url = format!("{}/data/testAuthentication", self.api_url);
+        self.http
+            .send(Method::GET, &url, &self.auth_headers(), Body::Empty)
+            .await?
+            .ensure_success(&url)?;
+        Ok(())
+    }
+
+    async fn list_batch_pins(&self, scope: &PinScope) -> Result<Vec<BatchPin>> {
+        let mut pins = Vec::new();
+        let mut offset = 0;
+        loop {
+            let rows = self.pin_list_page(scope, offset).await?;
+            pins.extend(rows.iter().filter_map(|row| {
+                let pinned_at = scope.parse_pin_name(row["metadata"]["name"].as_str()?)?;
+                Some(BatchPin {
+                    cid: row["ipfs_pin_hash"].as_str()?.to_string(),
+                    pinned_at,
+                })
+            }));
+            if rows.len() < PIN_LIST_PAGE_SIZE {
+                break;
+            }
+            offset += rows.len();
+        }
+
+        pins.sort_by(|a, b| a.cid.cmp(&b.cid));
+        Ok(pins)
+    }
+
+    async fn unpin(&self, cid: &str) -> Result<()> {
+        let url = format!("{}/pinning/unpin/{}", self.api_url, cid);
+        self.http
+            .send(Method::DELETE, &url, &self.auth_headers(), Body::Empty)
+            .await?
+            .ensure_success(&url)?;
+        info!("Unpinned {}", cid);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::{fast_config, serve};
+
+    fn store(url: String) -> PinataStore {
+        PinataStore::new(url, String::new(), "jwt".to_string())
+            .unwrap()
+            .with_http_config(fast_config())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_list_batch_pins() {
+        let body = r#"{"count":4,"rows":[
+            {"ipfs_pin_hash":"QmB","metadata":{"name":"fossil-batch-1-0x123-20"}},
+            {"ipfs_pin_hash":"QmOther","metadata":{"name":"not-fossil-batch-1-0x123-1x"}},
+            {"ipfs_pin_hash":"QmOtherStore","metadata":{"name":"fossil-batch-1-0x1234-10"}},
+            {"ipfs_pin_hash":"QmA","metadata":{"name":"fossil-batch-1-0x123-10"}}
+        ]}"#;
+        let response: &'static str = Box::leak(
+            format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .into_boxed_str(),
+        );
+        let (url, served) = serve(vec![response]).await;
+
+        let pins = store(url)
+            .list_batch_pins(&PinScope::new(1, "0x123"))
+            .await
+            .unwrap();
+        assert_eq!(
+            pins,
+            vec![
+                BatchPin {
+                    cid: "QmA".to_string(),
+                    pinned_at: 10,
+                },
+                BatchPin {
+                    cid: "QmB".to_string(),
+                    pinned_at: 20,
+                },
+            ]
+        );
+        assert_eq!(served.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+}
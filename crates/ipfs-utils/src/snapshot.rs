@@ -0,0 +1,309 @@
+//! Compressed batch database snapshots.
+//!
+//! A snapshot is a fixed header followed by a single zstd frame of the SQLite file:
+//!
+//! | bytes  | field                                  |
+//! |--------|----------------------------------------|
+//! | 0..8   | magic `FSLSNAP\0`                      |
+//! | 8..10  | format version, big-endian (1)         |
+//! | 10..18 | size of the database, big-endian bytes |
+//! | 18..50 | sha2-256 of the database               |
+//! | 50..   | zstd frame                             |
+//!
+//! Plain SQLite files are still accepted wherever a snapshot is, so stores can hold
+//! both and readers never need to know which one a CID points at.
+use eyre::{eyre, Result};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
+
+pub const SNAPSHOT_MAGIC: &[u8; 8] = b"FSLSNAP\0";
+pub const SNAPSHOT_VERSION: u16 = 1;
+pub const SNAPSHOT_HEADER_LEN: usize = 50;
+
+pub const DEFAULT_ZSTD_LEVEL: i32 = 19;
+
+/// Largest database a snapshot may expand to, whatever its header claims (4 GiB)
+pub const DEFAULT_MAX_DB_SIZE: u64 = 4 * 1024 * 1024 * 1024;
+
+/// How batch databases are encoded before upload
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// Upload the SQLite file as is
+    #[default]
+    None,
+    /// Upload a zstd snapshot
+    Zstd { level: i32 },
+}
+
+impl Compression {
+    /// Reads `BATCH_STORE_COMPRESSION` (`none` or `zstd`) and `BATCH_STORE_ZSTD_LEVEL`
+    pub fn from_env() -> Result<Self> {
+        let mode = env::var("BATCH_STORE_COMPRESSION").unwrap_or_default();
+        match mode.trim().to_lowercase().as_str() {
+            "" | "none" => Ok(Self::None),
+            "zstd" => {
+                let level = match env::var("BATCH_STORE_ZSTD_LEVEL") {
+                    Ok(level) => level
+                        .trim()
+                        .parse()
+                        .map_err(|_| eyre!("Invalid BATCH_STORE_ZSTD_LEVEL: {}", level))?,
+                    Err(_) => DEFAULT_ZSTD_LEVEL,
+                };
+                if !zstd::compression_level_range().contains(&level) {
+                    return Err(eyre!("zstd level {} is out of range", level));
+                }
+                Ok(Self::Zstd { level })
+            }
+            other => Err(eyre!("Unknown BATCH_STORE_COMPRESSION: {}", other)),
+        }
+    }
+
+    /// Encodes `db` for upload
+    pub fn encode(self, db: Vec<u8>) -> Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(db),
+            Self::Zstd { level } => encode_snapshot(&db, level),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotHeader {
+    pub version: u16,
+    pub original_size: u64,
+    pub sha256: [u8; 32],
+}
+
+impl SnapshotHeader {
+    pub fn encode(&self) -> [u8; SNAPSHOT_HEADER_LEN] {
+        let mut out = [0u8; SNAPSHOT_HEADER_LEN];
+        out[..8].copy_from_slice(SNAPSHOT_MAGIC);
+        out[8..10].copy_from_slice(&self.version.to_be_bytes());
+        out[10..18].copy_from_slice(&self.original_size.to_be_bytes());
+        out[18..].copy_from_slice(&self.sha256);
+        out
+    }
+
+    /// Parses a header, or returns `None` if `bytes` does not start with the magic
+    pub fn decode(bytes: &[u8]) -> Result<Option<Self>> {
+        if !bytes.starts_with(SNAPSHOT_MAGIC) {
+            return Ok(None);
+        }
+        let header: &[u8; SNAPSHOT_HEADER_LEN] = bytes
+            .get(..SNAPSHOT_HEADER_LEN)
+            .and_then(|h| h.try_into().ok())
+            .ok_or_else(|| eyre!("Truncated snapshot header"))?;
+
+        let version = u16::from_be_bytes([header[8], header[9]]);
+        if version != SNAPSHOT_VERSION {
+            return Err(eyre!("Unsupported snapshot version {}", version));
+        }
+
+        let mut original_size = [0u8; 8];
+        original_size.copy_from_slice(&header[10..18]);
+        let mut sha256 = [0u8; 32];
+        sha256.copy_from_slice(&header[18..]);
+
+        Ok(Some(Self {
+            version,
+            original_size: u64::from_be_bytes(original_size),
+            sha256,
+        }))
+    }
+}
+
+pub fn encode_snapshot(db: &[u8], level: i32) -> Result<Vec<u8>> {
+    let header = SnapshotHeader {
+        version: SNAPSHOT_VERSION,
+        original_size: db.len() as u64,
+        sha256: Sha256::digest(db).into(),
+    };
+
+    let mut out = header.encode().to_vec();
+    zstd::stream::copy_encode(db, &mut out, level)
+        .map_err(|e| eyre!("Failed to compress snapshot: {}", e))?;
+    Ok(out)
+}
+
+/// Writes the database held in `input` to `output`, decompressing it on the fly if
+/// `input` is a snapshot. The expanded size and hash are checked against the header,
+/// and a snapshot may not expand beyond `max_size` bytes. Returns whether `input` was
+/// a snapshot; plain files are left in place and nothing is written.
+pub fn restore_snapshot(input: &Path, output: &Path, max_size: u64) -> Result<bool> {
+    let mut file = File::open(input)?;
+    let mut prefix = [0u8; SNAPSHOT_HEADER_LEN];
+    let read = read_up_to(&mut file, &mut prefix)?;
+    let Some(header) = SnapshotHeader::decode(&prefix[..read])? else {
+        return Ok(false);
+    };
+
+    if header.original_size > max_size {
+        return Err(eyre!(
+            "Snapshot expands to {} bytes, more than the allowed {} bytes",
+            header.original_size,
+            max_size
+        ));
+    }
+
+    let result = decode_body(file, output, &header);
+    if result.is_err() {
+        let _ = std::fs::remove_file(output);
+    }
+    result.map(|()| true)
+}
+
+fn decode_body(file: File, output: &Path, header: &SnapshotHeader) -> Result<()> {
+    let mut decoder = zstd::stream::read::Decoder::with_buffer(BufReader::new(file))
+        .map_err(|e| eyre!("Failed to open snapshot: {}", e))?;
+    let mut out = File::create(output)?;
+    let mut hasher = Sha256::new();
+    let mut written = 0u64;
+    let mut buffer = vec![0u8; 64 * 1024];
+
+    loop {
+        let n = decoder
+            .read(&mut buffer)
+            .map_err(|e| eyre!("Corrupt snapshot: {}", e))?;
+        if n == 0 {
+            break;
+        }
+        written += n as u64;
+        if written > header.original_size {
+            return Err(eyre!(
+                "Snapshot expands past its declared size of {} bytes",
+                header.original_size
+            ));
+        }
+        hasher.update(&buffer[..n]);
+        out.write_all(&buffer[..n])?;
+    }
+
+    if written != header.original_size {
+        return Err(eyre!(
+            "Snapshot expanded to {} bytes, expected {}",
+            written,
+            header.original_size
+        ));
+    }
+    if hasher.finalize().as_slice() != header.sha256 {
+        return Err(eyre!("Snapshot hash does not match its header"));
+    }
+
+    out.sync_all()?;
+    Ok(())
+}
+
+/// Like `read_exact`, but stops quietly at end of file
+fn read_up_to(reader: &mut impl Read, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn db_bytes() -> Vec<u8> {
+        let mut bytes = b"SQLite format 3\0".to_vec();
+        bytes.extend((0..200_000u32).map(|i| (i % 7) as u8));
+        bytes
+    }
+
+    #[test]
+    fn test_header_roundtrip() {
+        let header = SnapshotHeader {
+            version: SNAPSHOT_VERSION,
+            original_size: 0x0102_0304_0506_0708,
+            sha256: [0xab; 32],
+        };
+        let encoded = header.encode();
+        assert_eq!(&encoded[..10], b"FSLSNAP\0\x00\x01");
+        assert_eq!(SnapshotHeader::decode(&encoded).unwrap(), Some(header));
+
+        assert_eq!(SnapshotHeader::decode(b"SQLite format 3\0").unwrap(), None);
+        assert!(SnapshotHeader::decode(&encoded[..20]).is_err());
+
+        let mut future = encoded;
+        future[9] = 2;
+        assert!(SnapshotHeader::decode(&future).is_err());
+    }
+
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let input = dir.path().join("snapshot");
+        let output = dir.path().join("restored.db");
+
+        let snapshot = encode_snapshot(&db_bytes(), 3).unwrap();
+        assert!(snapshot.len() < db_bytes().len() / 10);
+        std::fs::write(&input, &snapshot).unwrap();
+
+        assert!(restore_snapshot(&input, &output, DEFAULT_MAX_DB_SIZE).unwrap());
+        assert_eq!(std::fs::read(&output).unwrap(), db_bytes());
+    }
+
+    #[test]
+    fn test_plain_database_is_left_alone() {
+        let dir = TempDir::new().unwrap();
+        let input = dir.path().join("batch.db");
+        let output = dir.path().join("restored.db");
+        std::fs::write(&input, db_bytes()).unwrap();
+
+        assert!(!restore_snapshot(&input, &output, DEFAULT_MAX_DB_SIZE).unwrap());
+        assert!(!output.exists());
+
+        // Shorter than a header
+        std::fs::write(&input, b"SQLite").unwrap();
+        assert!(!restore_snapshot(&input, &output, DEFAULT_MAX_DB_SIZE).unwrap());
+    }
+
+    #[test]
+    fn test_restore_rejects_bad_snapshots() {
+        let dir = TempDir::new().unwrap();
+        let input = dir.path().join("snapshot");
+        let output = dir.path().join("restored.db");
+        let snapshot = encode_snapshot(&db_bytes(), 3).unwrap();
+
+        // Larger than the caller allows
+        std::fs::write(&input, &snapshot).unwrap();
+        assert!(restore_snapshot(&input, &output, 1024).is_err());
+
+        // Hash in the header does not match the content
+        let mut wrong_hash = snapshot.clone();
+        wrong_hash[18] ^= 1;
+        std::fs::write(&input, &wrong_hash).unwrap();
+        let err = restore_snapshot(&input, &output, DEFAULT_MAX_DB_SIZE).unwrap_err();
+        assert_eq!(err.to_string(), "Snapshot hash does not match its header");
+        assert!(!output.exists());
+
+        // Declared size smaller than the content
+        let mut wrong_size = snapshot.clone();
+        wrong_size[17] = 1;
+        wrong_size[10..17].fill(0);
+        std::fs::write(&input, &wrong_size).unwrap();
+        assert!(restore_snapshot(&input, &output, DEFAULT_MAX_DB_SIZE).is_err());
+
+        // Truncated frame
+        std::fs::write(&input, &snapshot[..snapshot.len() / 2]).unwrap();
+        assert!(restore_snapshot(&input, &output, DEFAULT_MAX_DB_SIZE).is_err());
+        assert!(!output.exists());
+    }
+
+    #[test]
+    fn test_compression_encode() {
+        assert_eq!(Compression::None.encode(db_bytes()).unwrap(), db_bytes());
+
+        let encoded = Compression::Zstd { level: 1 }.encode(db_bytes()).unwrap();
+        assert!(encoded.starts_with(SNAPSHOT_MAGIC));
+    }
+}
@@ -0,0 +1,402 @@
+use crate::{
+    cid::{verify_cid, CidFormat},
+    http::{HttpClient, Source},
+    local::LocalStore,
+    pinata::PinataStore,
+    s3::S3Store,
+    snapshot::{restore_snapshot, Compression, DEFAULT_MAX_DB_SIZE},
+    IpfsManager,
+};
+use async_trait::async_trait;
+use eyre::{eyre, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::task;
+use tracing::warn;
+
+/// Content-addressed storage for batch databases. Every backend returns a CID the file
+/// can later be fetched and verified by, but the CID itself depends on the backend:
+/// Kubo and Pinata return the UnixFS CID IPFS assigns, while S3 and the local store
+/// return a raw-codec CIDv1 of the whole file. The same database therefore gets a
+/// different on-chain value per backend; readers check either kind with
+/// [`verify_cid`].
+#[async_trait]
+pub trait BatchStore: Send + Sync {
+    /// Backend name used in logs
+    fn name(&self) -> &'static str;
+
+    /// Stores the database at `file_path` and returns its CID. Backends that pin
+    /// name the pin after `scope`.
+    async fn upload_db(&self, file_path: &Path, scope: &PinScope) -> Result<String>;
+
+    /// Writes the database identified by `cid` to `output_path`
+    async fn fetch_db(&self, cid: &str, output_path: &Path) -> Result<()>;
+
+    async fn check_connection(&self) -> Result<()>;
+
+    /// Batch databases this backend pinned through [`BatchStore::upload_db`] for
+    /// `scope`. Pins made for other deployments, or by anything else on the same
+    /// node or account, are left out.
+    async fn list_batch_pins(&self, _scope: &PinScope) -> Result<Vec<BatchPin>> {
+        Err(eyre!(
+            "The {} batch store does not pin uploads",
+            self.name()
+        ))
+    }
+
+    /// Removes the pin on `cid`, letting the backend garbage collect it
+    async fn unpin(&self, cid: &str) -> Result<()> {
+        Err(eyre!(
+            "The {} batch store does not pin uploads, cannot unpin {}",
+            self.name(),
+            cid
+        ))
+    }
+}
+
+/// A batch database pinned by [`BatchStore::upload_db`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchPin {
+    pub cid: String,
+    /// Unix time of the upload, in seconds
+    pub pinned_at: u64,
+}
+
+/// Prefix of the names batch uploads are pinned under
+const BATCH_PIN_PREFIX: &str = "fossil-batch-";
+
+/// The deployment batch uploads belong to: the chain the batches are built from and
+/// the store contract that references them. Several deployments can share a node
+/// or pinning account, and each must only collect its own pins.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PinScope {
+    chain_id: u64,
+    store_address: String,
+}
+
+impl PinScope {
+    pub fn new(chain_id: u64, store_address: &str) -> Self {
+        // Addresses are compared as written in pin names, so padding and case
+        // must not matter
+        let digits = store_address
+            .trim_start_matches("0x")
+            .trim_start_matches('0')
+            .to_lowercase();
+        Self {
+            chain_id,
+            store_address: format!("0x{}", digits),
+        }
+    }
+
+    pub const fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    pub fn store_address(&self) -> &str {
+        &self.store_address
+    }
+
+    /// Prefix of the names this scope's uploads are pinned under
+    pub(crate) fn pin_prefix(&self) -> String {
+        format!(
+            "{}{}-{}-",
+            BATCH_PIN_PREFIX, self.chain_id, self.store_address
+        )
+    }
+
+    /// Name to pin an upload made now under. It records the upload time, so a pin
+    /// can be told apart from the node's other pins and aged without a separate
+    /// index.
+    pub(crate) fn pin_name(&self) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        format!("{}{}", self.pin_prefix(), now)
+    }
+
+    /// Upload time recorded in a pin name, `None` if the pin is not a batch upload
+    /// of this scope
+    pub(crate) fn parse_pin_name(&self, name: &str) -> Option<u64> {
+        name.strip_prefix(&self.pin_prefix())?.parse().ok()
+    }
+}
+
+/// Leading bytes of an MMR batch snapshot, see `mmr_utils::snapshot`
+pub const BATCH_SNAPSHOT_MAGIC: &[u8; 8] = b"FSLBATCH";
+
+/// Checks that `file_path` is a SQLite database or MMR batch snapshot no larger than
+/// `max_file_size` and returns its contents.
+pub fn read_db_file(file_path: &Path, max_file_size: usize) -> Result<Vec<u8>> {
+    let contents = std::fs::read(file_path).map_err(|_| eyre!("File operation failed"))?;
+    if !contents.starts_with(b"SQLite format 3\0") && !contents.starts_with(BATCH_SNAPSHOT_MAGIC) {
+        return Err(eyre!(
+            "File is neither a SQLite database nor an MMR batch snapshot"
+        ));
+    }
+
+    check_file_size(contents.len(), max_file_size)?;
+    Ok(contents)
+}
+
+fn check_file_size(size: usize, max_file_size: usize) -> Result<()> {
+    if size > max_file_size {
+        warn!(
+            "File size exceeds limit: {} bytes > {} bytes",
+            size, max_file_size
+        );
+        return Err(eyre!(
+            "File size {} bytes exceeds maximum allowed size {} bytes",
+            size,
+            max_file_size,
+        ));
+    }
+    Ok(())
+}
+
+/// Reads the database at `file_path` and encodes it with `compression`. When
+/// compressing, `max_file_size` limits the snapshot rather than the raw database.
+pub async fn prepare_upload(
+    file_path: &Path,
+    max_file_size: usize,
+    compression: Compression,
+) -> Result<Vec<u8>> {
+    let raw_limit = match compression {
+        Compression::None => max_file_size,
+        Compression::Zstd { .. } => usize::try_from(DEFAULT_MAX_DB_SIZE).unwrap_or(usize::MAX),
+    };
+    let contents = read_db_file(file_path, raw_limit)?;
+
+    let payload = task::spawn_blocking(move || compression.encode(contents))
+        .await
+        .map_err(|e| eyre!(e.to_string()))??;
+    check_file_size(payload.len(), max_file_size)?;
+    Ok(payload)
+}
+
+/// Name for the multipart part carrying an upload of `file_path`
+pub(crate) fn upload_file_name(file_path: &Path, compression: Compression) -> String {
+    let name = file_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("batch.db");
+    match compression {
+        Compression::None => name.to_string(),
+        Compression::Zstd { .. } => format!("{}.zst", name),
+    }
+}
+
+/// Sibling of `path` with `suffix` appended to its file name
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Sibling of `path` that downloads are written to until they have been verified
+pub(crate) fn partial_path(path: &Path) -> PathBuf {
+    with_suffix(path, ".partial")
+}
+
+/// Moves the verified download at `verified` to `output_path`, expanding it first if
+/// it is a compressed snapshot.
+pub(crate) async fn finish_fetch(verified: &Path, output_path: &Path) -> Result<()> {
+    let restoring = with_suffix(output_path, ".restoring");
+
+    let (input, output) = (verified.to_path_buf(), restoring.clone());
+    let was_snapshot =
+        task::spawn_blocking(move || restore_snapshot(&input, &output, DEFAULT_MAX_DB_SIZE))
+            .await
+            .map_err(|e| eyre!(e.to_string()))?;
+
+    match was_snapshot {
+        Ok(true) => {
+            tokio::fs::rename(&restoring, output_path).await?;
+            tokio::fs::remove_file(verified).await?;
+        }
+        Ok(false) => tokio::fs::rename(verified, output_path).await?,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(verified).await;
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+/// Ordered public gateways from `IPFS_FALLBACK_GATEWAYS` (comma separated), tried in
+/// turn when the primary endpoint fails. They receive no credentials.
+pub(crate) fn fallback_gateways_from_env() -> Vec<Source> {
+    env::var("IPFS_FALLBACK_GATEWAYS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .map(|url| Source {
+            url: url.to_string(),
+            headers: Vec::new(),
+        })
+        .collect()
+}
+
+/// Downloads `cid` from each of `gateways` in turn (each is a base URL the CID is
+/// appended to) and moves it to `output_path` once a copy hashes to `cid`, so a stuck
+/// or misbehaving gateway never leaves unverified bytes behind. Snapshots are expanded
+/// on the way.
+pub(crate) async fn fetch_verified(
+    client: &HttpClient,
+    gateways: &[Source],
+    cid: &str,
+    output_path: &Path,
+    max_bytes: usize,
+) -> Result<()> {
+    // Reject malformed CIDs before spending a download on them
+    CidFormat::parse(cid)?;
+
+    let partial = partial_path(output_path);
+    let mut last_error = eyre!("No gateway configured to fetch {}", cid);
+    for gateway in gateways {
+        let source = Source {
+            url: format!("{}{}", gateway.url, cid),
+            headers: gateway.headers.clone(),
+        };
+
+        let result = match client.download(&source, &partial, max_bytes).await {
+            Ok(()) => verify_file(cid, &partial).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            // Every gateway serves the same verified bytes, so a bad snapshot is final
+            Ok(()) => return finish_fetch(&partial, output_path).await,
+            Err(e) => {
+                warn!(gateway = %gateway.url, error = %e, "Fetch failed, trying next gateway");
+                let _ = tokio::fs::remove_file(&partial).await;
+                last_error = e;
+            }
+        }
+    }
+
+    Err(last_error)
+}
+
+async fn verify_file(cid: &str, path: &Path) -> Result<()> {
+    let contents = tokio::fs::read(path).await?;
+    verify_cid(cid, &contents)
+}
+
+/// Builds the backend selected by `BATCH_STORE_BACKEND` (`kubo`, `pinata`, `s3` or
+/// `local`), defaulting to the Kubo HTTP API.
+pub fn batch_store_from_env() -> Result<Arc<dyn BatchStore>> {
+    dotenv::dotenv().ok();
+
+    let backend = env::var("BATCH_STORE_BACKEND").unwrap_or_else(|_| "kubo".to_string());
+    let store: Arc<dyn BatchStore> = match backend.to_lowercase().as_str() {
+        "kubo" | "ipfs" => Arc::new(IpfsManager::with_endpoint()?),
+        "pinata" => Arc::new(PinataStore::from_env()?),
+        "s3" => Arc::new(S3Store::from_env()?),
+        "local" => Arc::new(LocalStore::from_env()?),
+        other => return Err(eyre!("Unknown BATCH_STORE_BACKEND: {}", other)),
+    };
+
+    Ok(store)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cid::raw_cid_v1;
+    use crate::http::tests::{fast_config, serve, NOT_FOUND, OK};
+    use tempfile::TempDir;
+
+    const TAMPERED: &str = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nHELLO";
+
+    fn gateway(url: String) -> Source {
+        Source {
+            url: format!("{}/ipfs/", url),
+            headers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_read_db_file_accepts_batch_snapshots() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("batch.mmr");
+
+        std::fs::write(&path, b"FSLBATCH\0\x01").unwrap();
+        assert_eq!(read_db_file(&path, 1024).unwrap(), b"FSLBATCH\0\x01");
+
+        std::fs::write(&path, b"FSLBATC").unwrap();
+        assert!(read_db_file(&path, 1024).is_err());
+    }
+
+    #[test]
+    fn test_batch_pin_name() {
+        let scope = PinScope::new(1, "0x0ABC");
+        assert_eq!(scope.store_address(), "0xabc");
+        assert_eq!(scope, PinScope::new(1, "0xabc"));
+
+        let name = scope.pin_name();
+        assert!(name.starts_with("fossil-batch-1-0xabc-"));
+        let pinned_at = scope.parse_pin_name(&name).unwrap();
+        assert!(pinned_at > 1_700_000_000);
+
+        assert_eq!(scope.parse_pin_name("fossil-batch-1-0xabc-42"), Some(42));
+        assert_eq!(scope.parse_pin_name("fossil-batch-1-0xabc-"), None);
+        assert_eq!(scope.parse_pin_name("fossil-batch-1-0xabc-x"), None);
+        assert_eq!(scope.parse_pin_name("42"), None);
+        assert_eq!(scope.parse_pin_name(""), None);
+
+        // Other deployments and the unscoped names of earlier uploads
+        assert_eq!(scope.parse_pin_name("fossil-batch-2-0xabc-42"), None);
+        assert_eq!(scope.parse_pin_name("fossil-batch-1-0xabcd-42"), None);
+        assert_eq!(scope.parse_pin_name("fossil-batch-42"), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_verified_falls_back_to_next_gateway() {
+        let (missing, _) = serve(vec![NOT_FOUND]).await;
+        let (tampered, _) = serve(vec![TAMPERED]).await;
+        let (good, _) = serve(vec![OK]).await;
+
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("out.db");
+        let client = HttpClient::new(fast_config()).unwrap();
+
+        fetch_verified(
+            &client,
+            &[gateway(missing), gateway(tampered), gateway(good)],
+            &raw_cid_v1(b"hello"),
+            &output,
+            1024,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(std::fs::read(&output).unwrap(), b"hello");
+        assert!(!partial_path(&output).exists());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_verified_reports_last_error() {
+        let (tampered, _) = serve(vec![TAMPERED]).await;
+
+        let dir = TempDir::new().unwrap();
+        let output = dir.path().join("out.db");
+        let client = HttpClient::new(fast_config()).unwrap();
+
+        let err = fetch_verified(
+            &client,
+            &[gateway(tampered)],
+            &raw_cid_v1(b"hello"),
+            &output,
+            1024,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(err.to_string().starts_with("CID mismatch"));
+        assert!(!output.exists());
+        assert!(!partial_path(&output).exists());
+    }
+}
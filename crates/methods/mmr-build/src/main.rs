@@ -1,26 +1,29 @@
 // main.rs
-use eth_rlp_verify::are_blocks_and_chain_valid;
 use risc0_zkvm::guest::env;
 use guest_mmr::core::GuestMMR;
-use guest_types::{CombinedInput, GuestOutput};
-use eth_rlp_types::BlockHeader;
-
-const HOUR_IN_SECONDS: i64 = 3600;
+use guest_types::{
+    fees::hourly_fees,
+    header::{verify_headers, Header},
+    journal::JournalHeader,
+    AvgFees, CombinedInput, GuestOutput,
+};
 
 fn main() {
     // Read combined input
     let input: CombinedInput = env::read();
     // Flatten headers for validation
-    let flattened_headers: Vec<BlockHeader> = input.headers()
+    let flattened_headers: Vec<Header> = input.headers()
         .iter()
         .flat_map(|(_, headers)| headers.iter())
         .cloned()
         .collect();
 
-    assert!(
-        are_blocks_and_chain_valid(&flattened_headers, input.chain_id()),
-        "Invalid block headers"
-    );
+    // Every header must carry exactly the fields of its fork, hash to its block hash
+    // in that fork's encoding and follow the header before it
+    let fork = match verify_headers(input.chain_id(), &flattened_headers) {
+        Ok(fork) => fork,
+        Err(e) => panic!("Invalid block headers: {}", e),
+    };
 
     // Initialize MMR with previous state
     let mut mmr = GuestMMR::new(
@@ -57,46 +60,16 @@ fn main() {
 
     assert!(first_batch_index == last_batch_index, "Batch index mismatch");
 
-    // Calculate fee averages for hourly groups
-    let mut avg_fees: Vec<(usize, usize, u64)> = Vec::new(); // (timestamp, data_points, avg_fee)
+    // Calculate fee statistics for hourly groups
+    let mut avg_fees: Vec<AvgFees> = Vec::new();
 
     for (claimed_timestamp, hour_group) in input.headers() {
-        if hour_group.is_empty() {
-            continue;
+        match hourly_fees(input.chain_id(), *claimed_timestamp, hour_group) {
+            Ok(Some(fees)) => avg_fees.push(fees),
+            // No header in this hour carries a base fee
+            Ok(None) => {}
+            Err(e) => panic!("Invalid fee data: {}", e),
         }
-
-        // Verify the claimed timestamp is valid for this group
-        let group_timestamps: Vec<i64> = hour_group.iter()
-            .filter_map(|header| {
-                header.timestamp.as_ref()
-                    .and_then(|ts| i64::from_str_radix(ts.trim_start_matches("0x"), 16).ok())
-            })
-            .collect();
-
-        // Verify all timestamps are within the same hour as claimed_timestamp
-        assert!(
-            group_timestamps.iter().all(|ts| ts / HOUR_IN_SECONDS == claimed_timestamp / HOUR_IN_SECONDS),
-            "Timestamps in group don't belong to claimed hour"
-        );
-
-        // Verify claimed_timestamp is exactly on the hour
-        assert!(
-            claimed_timestamp % HOUR_IN_SECONDS == 0,
-            "Claimed timestamp is not exactly on the hour"
-        );
-
-        let total_fees: u64 = hour_group
-            .iter()
-            .filter_map(|header| {
-                header.base_fee_per_gas.as_ref()
-                    .and_then(|fee| u64::from_str_radix(fee.trim_start_matches("0x"), 16).ok())
-            })
-            .sum();
-
-        let avg_fee = total_fees / hour_group.len() as u64;
-        let data_points = hour_group.len();
-
-        avg_fees.push((*claimed_timestamp as usize, data_points, avg_fee));
     }
 
     let first_block_parent_hash = if first_batch_index == 0 {
@@ -107,6 +80,7 @@ fn main() {
 
     // Create output with avg_fees
     let output = GuestOutput::new(
+        JournalHeader::new(input.method_id(), input.chain_id()),
         first_batch_index,
         last_block_number,
         last_block_hash,
@@ -114,8 +88,13 @@ fn main() {
         mmr.get_leaves_count(),
         first_block_parent_hash,
         avg_fees,
+        fork.id(),
     );
 
-    // Commit the output
-    env::commit(&output);
-}
\ No newline at end of file
+    // Commit the output in the versioned journal encoding
+    let journal = match output.encode() {
+        Ok(journal) => journal,
+        Err(e) => panic!("Failed to encode journal: {}", e),
+    };
+    env::commit_slice(&journal);
+}
@@ -0,0 +1,228 @@
+//! Persistent local copies of batch databases.
+//!
+//! Each batch is kept as `batch_{index}.db` in the cache directory, so updating a batch
+//! only goes to the batch store when the local copy is missing or stale. Recency is
+//! tracked through file modification times, and the least recently used batches are
+//! evicted once the cache outgrows its size limit. The cache knows nothing about
+//! on-chain state; callers must check a cached batch's root before trusting it.
+use crate::ensure_directory_exists;
+use eyre::{eyre, Result};
+use std::env;
+use std::fs::{self, File};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+pub const DEFAULT_CACHE_DIR: &str = "mmr-cache";
+
+/// Default size limit of the cache (1 GiB)
+pub const DEFAULT_CACHE_MAX_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Files SQLite may keep next to a database
+const SIDECAR_SUFFIXES: [&str; 3] = ["-journal", "-wal", "-shm"];
+
+#[derive(Debug, Clone)]
+pub struct MmrCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+impl MmrCache {
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir, max_bytes })
+    }
+
+    /// Reads `MMR_CACHE_DIR` (default `./mmr-cache`) and `MMR_CACHE_MAX_BYTES`
+    pub fn from_env() -> Result<Self> {
+        let dir = match env::var("MMR_CACHE_DIR") {
+            Ok(dir) if !dir.trim().is_empty() => PathBuf::from(dir.trim()),
+            _ => ensure_directory_exists(DEFAULT_CACHE_DIR)?,
+        };
+        let max_bytes = match env::var("MMR_CACHE_MAX_BYTES") {
+            Ok(value) => value
+                .trim()
+                .parse()
+                .map_err(|_| eyre!("Invalid MMR_CACHE_MAX_BYTES: {}", value))?,
+            Err(_) => DEFAULT_CACHE_MAX_BYTES,
+        };
+
+        Self::new(dir, max_bytes)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub const fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    /// Where the database of `batch_index` lives, whether or not it is cached
+    pub fn batch_path(&self, batch_index: u64) -> PathBuf {
+        self.dir.join(format!("batch_{}.db", batch_index))
+    }
+
+    /// Returns the cached database of `batch_index`, marking it as recently used
+    pub fn get(&self, batch_index: u64) -> Result<Option<PathBuf>> {
+        let path = self.batch_path(batch_index);
+        match File::options().write(true).open(&path) {
+            Ok(file) => {
+                file.set_modified(SystemTime::now())?;
+                Ok(Some(path))
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Replaces whatever is cached for `batch_index` with an empty file, ready to be
+    /// initialized as a new database
+    pub fn create(&self, batch_index: u64) -> Result<PathBuf> {
+        self.remove(batch_index)?;
+        let path = self.batch_path(batch_index);
+        File::create(&path)?;
+        Ok(path)
+    }
+
+    /// Drops the cached database of `batch_index`, if any
+    pub fn remove(&self, batch_index: u64) -> Result<()> {
+        let path = self.batch_path(batch_index);
+        remove_if_exists(&path)?;
+        for suffix in SIDECAR_SUFFIXES {
+            let mut sidecar = path.clone().into_os_string();
+            sidecar.push(suffix);
+            remove_if_exists(Path::new(&sidecar))?;
+        }
+        Ok(())
+    }
+
+    /// Evicts least recently used batches until the cache fits in its size limit,
+    /// never evicting `keep`. Returns the evicted batch indices.
+    pub fn evict(&self, keep: Option<u64>) -> Result<Vec<u64>> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|entry| entry.size).sum();
+
+        // Oldest first; ties broken by index so eviction order is stable
+        entries.sort_by_key(|entry| (entry.modified, entry.batch_index));
+
+        let mut evicted = Vec::new();
+        for entry in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            if Some(entry.batch_index) == keep {
+                continue;
+            }
+            self.remove(entry.batch_index)?;
+            total -= entry.size;
+            evicted.push(entry.batch_index);
+        }
+        Ok(evicted)
+    }
+
+    fn entries(&self) -> Result<Vec<CacheEntry>> {
+        let mut entries = Vec::new();
+        for dir_entry in fs::read_dir(&self.dir)? {
+            let dir_entry = dir_entry?;
+            let name = dir_entry.file_name();
+            let Some(batch_index) = name
+                .to_str()
+                .and_then(|name| name.strip_prefix("batch_"))
+                .and_then(|name| name.strip_suffix(".db"))
+                .and_then(|index| index.parse().ok())
+            else {
+                continue;
+            };
+
+            let metadata = dir_entry.metadata()?;
+            entries.push(CacheEntry {
+                batch_index,
+                size: metadata.len(),
+                modified: metadata.modified()?,
+            });
+        }
+        Ok(entries)
+    }
+}
+
+struct CacheEntry {
+    batch_index: u64,
+    size: u64,
+    modified: SystemTime,
+}
+
+fn remove_if_exists(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    /// Caches `size` bytes for `batch_index`, last used `age` seconds ago
+    fn put(cache: &MmrCache, batch_index: u64, size: usize, age: u64) {
+        let path = cache.create(batch_index).unwrap();
+        fs::write(&path, vec![0u8; size]).unwrap();
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(age))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_get_and_remove() {
+        let dir = TempDir::new().unwrap();
+        let cache = MmrCache::new(dir.path(), 1024).unwrap();
+
+        assert_eq!(cache.get(7).unwrap(), None);
+        put(&cache, 7, 10, 0);
+        fs::write(dir.path().join("batch_7.db-wal"), b"wal").unwrap();
+        assert_eq!(cache.get(7).unwrap(), Some(dir.path().join("batch_7.db")));
+
+        cache.remove(7).unwrap();
+        assert_eq!(cache.get(7).unwrap(), None);
+        assert!(!dir.path().join("batch_7.db-wal").exists());
+        // Removing twice is fine
+        cache.remove(7).unwrap();
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let dir = TempDir::new().unwrap();
+        let cache = MmrCache::new(dir.path(), 250).unwrap();
+        put(&cache, 1, 100, 30);
+        put(&cache, 2, 100, 20);
+        put(&cache, 3, 100, 10);
+        fs::write(dir.path().join("unrelated.txt"), vec![0u8; 1000]).unwrap();
+
+        // Using batch 1 makes batch 2 the oldest
+        cache.get(1).unwrap();
+        assert_eq!(cache.evict(None).unwrap(), vec![2]);
+        assert!(cache.get(2).unwrap().is_none());
+        assert!(dir.path().join("unrelated.txt").exists());
+
+        // Already within the limit
+        assert!(cache.evict(None).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_evict_keeps_current_batch() {
+        let dir = TempDir::new().unwrap();
+        let cache = MmrCache::new(dir.path(), 150).unwrap();
+        put(&cache, 1, 100, 30);
+        put(&cache, 2, 100, 20);
+        put(&cache, 3, 100, 10);
+
+        assert_eq!(cache.evict(Some(1)).unwrap(), vec![2, 3]);
+        assert!(cache.get(1).unwrap().is_some());
+    }
+}
@@ -9,10 +9,17 @@ use std::{
     env,
     fs::{self, File},
     path::{Path, PathBuf},
-    sync::{Arc, Mutex},
+    sync::Arc,
 };
 use store::sqlite::SQLiteStore;
 
+pub mod cache;
+pub mod pool;
+pub mod schema;
+pub mod snapshot;
+
+use pool::{open_pool, BatchWrite, PooledStore};
+
 #[allow(dead_code)]
 pub struct StoreFactory;
 
@@ -23,48 +30,35 @@ impl StoreFactory {
     }
 }
 
-#[allow(dead_code)]
 pub struct StoreManager {
-    stores: Mutex<HashMap<String, Arc<SQLiteStore>>>,
+    store: Option<Arc<PooledStore>>,
 }
 
 impl StoreManager {
     pub async fn new(path: &str) -> Result<Self> {
-        let pool = SqlitePool::connect(path).await?;
-
-        // Create the mmr_metadata table if it doesn't exist
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS mmr_metadata (
-                mmr_id TEXT PRIMARY KEY
-            )
-            "#,
-        )
-        .execute(&pool)
-        .await?;
-
-        let manager = Self {
-            stores: Mutex::new(HashMap::new()),
-        };
+        let pool = open_pool(path).await?;
+        Self::with_pool(&pool).await
+    }
 
-        // Initialize the value-to-index table
-        manager.initialize_value_index_table(&pool).await?;
+    /// Migrates the database behind an already open pool to the current schema.
+    /// Fails with [`schema::UnsupportedSchemaVersion`] if it was written by a newer
+    /// publisher.
+    pub async fn with_pool(pool: &SqlitePool) -> Result<Self> {
+        schema::migrate(pool).await?;
+        Ok(Self { store: None })
+    }
 
-        Ok(manager)
+    /// Uses `store` as the MMR's store, so that batches also cover the MMR's writes
+    pub fn with_store(mut self, store: Arc<PooledStore>) -> Self {
+        self.store = Some(store);
+        self
     }
 
-    pub async fn initialize_value_index_table(&self, pool: &SqlitePool) -> Result<()> {
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS value_index_map (
-                value TEXT PRIMARY KEY,
-                element_index INTEGER NOT NULL
-            )
-            "#,
-        )
-        .execute(pool)
-        .await?;
-        Ok(())
+    /// Opens a batch of appends on `pool`. MMR writes made before the batch is
+    /// committed are only atomic with its leaf inserts when the MMR uses this
+    /// manager's store, as it does when it comes from `initialize_mmr`.
+    pub fn begin_batch<'a>(&'a self, pool: &'a SqlitePool) -> Result<BatchWrite<'a>> {
+        BatchWrite::begin(pool, self.store.as_deref())
     }
 
     #[allow(dead_code)]
@@ -74,21 +68,35 @@ impl StoreManager {
         value: &str,
         element_index: usize,
     ) -> Result<()> {
+        self.insert_leaf(pool, value, element_index, None).await
+    }
+
+    /// Records the leaf appended at `element_index`, and the block it holds if known
+    pub async fn insert_leaf(
+        &self,
+        pool: &SqlitePool,
+        value: &str,
+        element_index: usize,
+        block_number: Option<u64>,
+    ) -> Result<()> {
+        let leaf_index = element_index_to_leaf_index(element_index)?;
         sqlx::query(
             r#"
-            INSERT OR REPLACE INTO value_index_map (value, element_index)
-            VALUES (?, ?)
+            INSERT OR REPLACE INTO value_index_map (value, element_index, leaf_index, block_number)
+            VALUES (?, ?, ?, ?)
             "#,
         )
         .bind(value)
         .bind(element_index as i64) // SQLite uses i64 for integers
+        .bind(leaf_index as i64)
+        .bind(block_number.map(|n| n as i64))
         .execute(pool)
         .await?;
         Ok(())
     }
 
     pub async fn get_all_elements(&self, pool: &SqlitePool) -> Result<Vec<String>> {
-        let rows = sqlx::query("SELECT value FROM value_index_map")
+        let rows = sqlx::query("SELECT value FROM value_index_map ORDER BY element_index")
             .fetch_all(pool)
             .await?;
         Ok(rows.iter().map(|r| r.get("value")).collect())
@@ -118,39 +126,207 @@ impl StoreManager {
         }
     }
 
-    /// Retrieves the stored value for the given element index, abstracting away the MMR ID
+    /// Retrieves the stored value for the given element index, abstracting away the MMR ID.
+    /// Works for leaves and internal nodes alike.
     #[allow(dead_code)]
     pub async fn get_value_for_element_index(
         &self,
         pool: &SqlitePool,
         element_index: usize,
     ) -> Result<Option<String>> {
-        let element_index_str = element_index.to_string();
+        Ok(self
+            .get_values_for_element_indices(pool, &[element_index])
+            .await?
+            .remove(&element_index))
+    }
 
-        // Query the store for the value associated with the given element_index
-        let row = sqlx::query(
-            r#"
-            SELECT value FROM store WHERE key LIKE ?
-            "#,
-        )
-        .bind(format!("%:hashes:{}", element_index_str)) // Match the key pattern using LIKE
-        .fetch_optional(pool)
-        .await?;
+    /// Looks up the leaf holding `block_number`
+    pub async fn get_leaf_for_block_number(
+        &self,
+        pool: &SqlitePool,
+        block_number: u64,
+    ) -> Result<Option<LeafEntry>> {
+        Ok(self
+            .get_leaves_for_block_numbers(pool, &[block_number])
+            .await?
+            .pop())
+    }
 
-        if let Some(row) = row {
-            let stored_value: String = row.get("value");
-            Ok(Some(stored_value))
-        } else {
-            Ok(None)
+    /// Batch form of [`Self::get_element_index_for_value`]. Values that are not leaves
+    /// of this MMR are left out of the result.
+    pub async fn get_element_indices_for_values(
+        &self,
+        pool: &SqlitePool,
+        values: &[String],
+    ) -> Result<HashMap<String, usize>> {
+        let mut indices = HashMap::with_capacity(values.len());
+        for chunk in values.chunks(MAX_BOUND_PARAMETERS) {
+            let sql = format!(
+                "SELECT value, element_index FROM value_index_map WHERE value IN ({})",
+                placeholders(chunk.len())
+            );
+            let mut query = sqlx::query(&sql);
+            for value in chunk {
+                query = query.bind(value);
+            }
+            for row in query.fetch_all(pool).await? {
+                let element_index: i64 = row.get("element_index");
+                indices.insert(row.get("value"), element_index as usize);
+            }
+        }
+        Ok(indices)
+    }
+
+    /// Batch form of [`Self::get_value_for_element_index`]. Leaves are read from the
+    /// value index, and only internal nodes are looked up in the MMR store, by exact key.
+    pub async fn get_values_for_element_indices(
+        &self,
+        pool: &SqlitePool,
+        element_indices: &[usize],
+    ) -> Result<HashMap<usize, String>> {
+        let mut values = HashMap::with_capacity(element_indices.len());
+        for chunk in element_indices.chunks(MAX_BOUND_PARAMETERS) {
+            let sql = format!(
+                "SELECT value, element_index FROM value_index_map WHERE element_index IN ({})",
+                placeholders(chunk.len())
+            );
+            let mut query = sqlx::query(&sql);
+            for element_index in chunk {
+                query = query.bind(*element_index as i64);
+            }
+            for row in query.fetch_all(pool).await? {
+                let element_index: i64 = row.get("element_index");
+                values.insert(element_index as usize, row.get("value"));
+            }
+        }
+
+        let missing: Vec<usize> = element_indices
+            .iter()
+            .copied()
+            .filter(|index| !values.contains_key(index))
+            .collect();
+        if missing.is_empty() {
+            return Ok(values);
+        }
+        let Some(mmr_id) = get_mmr_id(pool).await? else {
+            return Ok(values);
+        };
+
+        for chunk in missing.chunks(MAX_BOUND_PARAMETERS) {
+            let sql = format!(
+                "SELECT key, value FROM store WHERE key IN ({})",
+                placeholders(chunk.len())
+            );
+            let mut query = sqlx::query(&sql);
+            for element_index in chunk {
+                query = query.bind(format!("{}:hashes:{}", mmr_id, element_index));
+            }
+            for row in query.fetch_all(pool).await? {
+                let key: String = row.get("key");
+                let element_index = key
+                    .rsplit(':')
+                    .next()
+                    .and_then(|index| index.parse().ok())
+                    .ok_or_else(|| eyre!("Malformed store key {}", key))?;
+                values.insert(element_index, row.get("value"));
+            }
+        }
+        Ok(values)
+    }
+
+    /// Looks up the leaves holding `block_numbers`, ordered by block number. Blocks that
+    /// are not in this MMR are left out of the result.
+    pub async fn get_leaves_for_block_numbers(
+        &self,
+        pool: &SqlitePool,
+        block_numbers: &[u64],
+    ) -> Result<Vec<LeafEntry>> {
+        let mut leaves = Vec::with_capacity(block_numbers.len());
+        for chunk in block_numbers.chunks(MAX_BOUND_PARAMETERS) {
+            let sql = format!(
+                "SELECT value, element_index, leaf_index, block_number FROM value_index_map \
+                 WHERE block_number IN ({})",
+                placeholders(chunk.len())
+            );
+            let mut query = sqlx::query(&sql);
+            for block_number in chunk {
+                query = query.bind(*block_number as i64);
+            }
+            for row in query.fetch_all(pool).await? {
+                leaves.push(LeafEntry::from_row(&row));
+            }
         }
+        leaves.sort_by_key(|leaf| leaf.block_number);
+        Ok(leaves)
     }
 }
 
-/// Initializes the MMR by retrieving or creating the MMR ID and setting up the hasher and store
+/// A leaf of the MMR as recorded in `value_index_map`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeafEntry {
+    pub value: String,
+    pub element_index: usize,
+    pub leaf_index: usize,
+    /// `None` for leaves recorded before block numbers were tracked
+    pub block_number: Option<u64>,
+}
+
+impl LeafEntry {
+    fn from_row(row: &sqlx::sqlite::SqliteRow) -> Self {
+        let element_index: i64 = row.get("element_index");
+        let leaf_index: i64 = row.get("leaf_index");
+        let block_number: Option<i64> = row.get("block_number");
+        Self {
+            value: row.get("value"),
+            element_index: element_index as usize,
+            leaf_index: leaf_index as usize,
+            block_number: block_number.map(|n| n as u64),
+        }
+    }
+}
+
+/// Keeps `IN (...)` lists well under SQLite's limit on bound parameters
+pub(crate) const MAX_BOUND_PARAMETERS: usize = 500;
+
+pub(crate) fn placeholders(count: usize) -> String {
+    vec!["?"; count].join(", ")
+}
+
+/// Zero-based leaf index of the leaf at `element_index`
+pub fn element_index_to_leaf_index(element_index: usize) -> Result<usize> {
+    // The leaves before this one fill an MMR of `element_index - 1` elements, which
+    // decomposes into perfect mountains of 2 * leaves - 1 elements each
+    let mut remaining = element_index
+        .checked_sub(1)
+        .ok_or_else(|| eyre!("Invalid element index: {}", element_index))?;
+    let mut leaves = 0;
+    let mut mountain_leaves = 1usize
+        .checked_shl(usize::BITS - remaining.leading_zeros())
+        .unwrap_or(0);
+
+    while mountain_leaves > 0 {
+        let mountain_size = 2 * mountain_leaves - 1;
+        if mountain_size <= remaining {
+            leaves += mountain_leaves;
+            remaining -= mountain_size;
+        }
+        mountain_leaves >>= 1;
+    }
+
+    if remaining == 0 {
+        Ok(leaves)
+    } else {
+        Err(eyre!("Element {} is not a leaf", element_index))
+    }
+}
+
+/// Initializes the MMR by retrieving or creating the MMR ID and setting up the hasher and store.
+/// The store, the value index and the returned pool all share a single connection pool.
 pub async fn initialize_mmr(store_path: &str) -> Result<(StoreManager, MMR, SqlitePool)> {
-    let pool = SqlitePool::connect(store_path).await?;
-    let store_manager = StoreManager::new(store_path).await?;
-    let store = Arc::new(SQLiteStore::new(store_path, Some(true), None).await?);
+    let pool = open_pool(store_path).await?;
+    let store_manager = StoreManager::with_pool(&pool).await?;
+    let store = Arc::new(PooledStore::new(pool.clone()).await?);
+    let store_manager = store_manager.with_store(store.clone());
 
     // Retrieve or generate a new MMR ID
     let mmr_id = if let Some(id) = get_mmr_id(&pool).await? {
@@ -168,7 +344,7 @@ pub async fn initialize_mmr(store_path: &str) -> Result<(StoreManager, MMR, Sqli
 }
 
 /// Retrieves the MMR ID from the `mmr_metadata` table
-async fn get_mmr_id(pool: &SqlitePool) -> Result<Option<String>> {
+pub(crate) async fn get_mmr_id(pool: &SqlitePool) -> Result<Option<String>> {
     let row = sqlx::query("SELECT mmr_id FROM mmr_metadata LIMIT 1")
         .fetch_optional(pool)
         .await?;
@@ -222,25 +398,7 @@ mod tests {
         let db_url = "sqlite::memory:";
         let pool = SqlitePool::connect(db_url).await.unwrap();
 
-        // Create mmr_metadata table first
-        sqlx::query(
-            r#"
-            CREATE TABLE IF NOT EXISTS mmr_metadata (
-                mmr_id TEXT PRIMARY KEY
-            )
-            "#,
-        )
-        .execute(&pool)
-        .await
-        .unwrap();
-
-        // Now create the manager which will create value_index_map table
-        let manager = StoreManager::new(&format!("sqlite:{}", db_url))
-            .await
-            .unwrap();
-
-        // Initialize the value-to-index table explicitly with the same pool
-        manager.initialize_value_index_table(&pool).await.unwrap();
+        let manager = StoreManager::with_pool(&pool).await.unwrap();
 
         (manager, pool)
     }
@@ -294,7 +452,7 @@ mod tests {
             .await
             .unwrap();
         manager
-            .insert_value_index_mapping(&pool, "test_hash3", 44)
+            .insert_value_index_mapping(&pool, "test_hash3", 47)
             .await
             .unwrap();
         let elements = manager.get_all_elements(&pool).await.unwrap();
@@ -323,15 +481,22 @@ mod tests {
             .await
             .unwrap();
 
-        // Insert a test value
+        save_mmr_id(&pool, "test").await.unwrap();
+
+        // Insert a test value, and one for the same index under another MMR ID
         let test_index = 123;
         let test_value = "test_stored_value";
-        sqlx::query("INSERT INTO store (key, value) VALUES (?, ?)")
-            .bind(format!("test:hashes:{}", test_index))
-            .bind(test_value)
-            .execute(&pool)
-            .await
-            .unwrap();
+        for (key, value) in [
+            (format!("other:hashes:{}", test_index), "other_value"),
+            (format!("test:hashes:{}", test_index), test_value),
+        ] {
+            sqlx::query("INSERT INTO store (key, value) VALUES (?, ?)")
+                .bind(key)
+                .bind(value)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
 
         // Test retrieving the value
         let result = manager
@@ -359,4 +524,83 @@ mod tests {
 
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_element_index_to_leaf_index() {
+        let leaves = [1, 2, 4, 5, 8, 9, 11, 12, 16, 17, 19, 20, 23, 24, 26, 27];
+        for (leaf_index, element_index) in leaves.into_iter().enumerate() {
+            assert_eq!(
+                element_index_to_leaf_index(element_index).unwrap(),
+                leaf_index
+            );
+        }
+        for internal in [0, 3, 6, 7, 10, 13, 14, 15] {
+            assert!(element_index_to_leaf_index(internal).is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_leaf_lookups() {
+        let (manager, pool) = setup_test_db().await;
+        sqlx::query("CREATE TABLE IF NOT EXISTS store (key TEXT PRIMARY KEY, value TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        save_mmr_id(&pool, "test").await.unwrap();
+        sqlx::query("INSERT INTO store (key, value) VALUES ('test:hashes:3', 'parent')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        for (element_index, block_number) in [(1, 100), (2, 101), (4, 102)] {
+            manager
+                .insert_leaf(
+                    &pool,
+                    &format!("hash{}", block_number),
+                    element_index,
+                    Some(block_number),
+                )
+                .await
+                .unwrap();
+        }
+
+        let leaf = manager.get_leaf_for_block_number(&pool, 102).await.unwrap();
+        assert_eq!(
+            leaf,
+            Some(LeafEntry {
+                value: "hash102".to_string(),
+                element_index: 4,
+                leaf_index: 2,
+                block_number: Some(102),
+            })
+        );
+        assert_eq!(
+            manager.get_leaf_for_block_number(&pool, 99).await.unwrap(),
+            None
+        );
+
+        let leaves = manager
+            .get_leaves_for_block_numbers(&pool, &[102, 100, 99])
+            .await
+            .unwrap();
+        assert_eq!(
+            leaves.iter().map(|l| l.element_index).collect::<Vec<_>>(),
+            vec![1, 4]
+        );
+
+        let indices = manager
+            .get_element_indices_for_values(&pool, &["hash101".to_string(), "nope".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(indices, HashMap::from([("hash101".to_string(), 2)]));
+
+        let values = manager
+            .get_values_for_element_indices(&pool, &[1, 3, 5])
+            .await
+            .unwrap();
+        assert_eq!(
+            values,
+            HashMap::from([(1, "hash100".to_string()), (3, "parent".to_string())])
+        );
+    }
 }
@@ -0,0 +1,370 @@
+//! One SQLite pool per batch database, shared by the MMR store and the value index.
+//!
+//! Connections run in WAL mode with `synchronous = NORMAL` and a busy timeout, so
+//! readers never block the writer and a crash loses at most the last transaction.
+//! Appends are made atomic by buffering the MMR's writes in [`PooledStore`] and
+//! flushing them, together with the leaf index rows, in one transaction through
+//! [`BatchWrite`].
+use crate::element_index_to_leaf_index;
+use async_trait::async_trait;
+use eyre::{eyre, Result};
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+use store::{Store, StoreError};
+
+/// How long a connection waits for a lock before failing with `SQLITE_BUSY`
+pub const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Opens the batch database at `path`, creating it if needed
+pub async fn open_pool(path: &str) -> Result<SqlitePool> {
+    let options = SqliteConnectOptions::from_str(path)?
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal)
+        .synchronous(SqliteSynchronous::Normal)
+        .busy_timeout(BUSY_TIMEOUT);
+
+    Ok(SqlitePoolOptions::new().connect_with(options).await?)
+}
+
+/// Writes buffered while a batch is open; `None` marks a deletion
+type Pending = HashMap<String, Option<String>>;
+
+/// MMR key-value store on a shared pool, using the same `store` table as
+/// `store::sqlite::SQLiteStore`
+#[derive(Debug)]
+pub struct PooledStore {
+    pool: SqlitePool,
+    pending: Mutex<Option<Pending>>,
+}
+
+impl PooledStore {
+    pub async fn new(pool: SqlitePool) -> Result<Self> {
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS store (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        Ok(Self {
+            pool,
+            pending: Mutex::new(None),
+        })
+    }
+
+    pub const fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
+    fn pending(&self) -> MutexGuard<'_, Option<Pending>> {
+        // The map is never left half-updated, so a poisoned lock is still usable
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Starts buffering writes in memory instead of sending them to the database
+    fn begin(&self) -> Result<()> {
+        let mut pending = self.pending();
+        if pending.is_some() {
+            return Err(eyre!("A batch write is already open on this store"));
+        }
+        *pending = Some(HashMap::new());
+        Ok(())
+    }
+
+    /// Stops buffering and returns what was buffered
+    fn take_pending(&self) -> Pending {
+        self.pending().take().unwrap_or_default()
+    }
+
+    async fn get_from_db(&self, keys: &[String]) -> Result<HashMap<String, String>, StoreError> {
+        let mut values = HashMap::with_capacity(keys.len());
+        for chunk in keys.chunks(crate::MAX_BOUND_PARAMETERS) {
+            let sql = format!(
+                "SELECT key, value FROM store WHERE key IN ({})",
+                crate::placeholders(chunk.len())
+            );
+            let mut query = sqlx::query(&sql);
+            for key in chunk {
+                query = query.bind(key);
+            }
+            let rows = query
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|_| StoreError::GetError)?;
+            for row in rows {
+                values.insert(row.get("key"), row.get("value"));
+            }
+        }
+        Ok(values)
+    }
+
+    async fn write_to_db(&self, entries: Pending) -> Result<(), sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        write_entries(&mut tx, entries).await?;
+        tx.commit().await
+    }
+
+    /// Buffers `entries` if a batch is open, otherwise returns them to be written
+    fn buffer(&self, entries: Pending) -> Option<Pending> {
+        match self.pending().as_mut() {
+            Some(pending) => {
+                pending.extend(entries);
+                None
+            }
+            None => Some(entries),
+        }
+    }
+}
+
+#[async_trait]
+impl Store for PooledStore {
+    async fn get(&self, key: &str) -> Result<Option<String>, StoreError> {
+        Ok(self.get_many(vec![key]).await?.remove(key))
+    }
+
+    async fn get_many(&self, keys: Vec<&str>) -> Result<HashMap<String, String>, StoreError> {
+        let mut values = HashMap::with_capacity(keys.len());
+        let mut missing = Vec::new();
+        {
+            let pending = self.pending();
+            for key in keys {
+                match pending.as_ref().and_then(|pending| pending.get(key)) {
+                    Some(Some(value)) => {
+                        values.insert(key.to_string(), value.clone());
+                    }
+                    Some(None) => {}
+                    None => missing.push(key.to_string()),
+                }
+            }
+        }
+
+        if !missing.is_empty() {
+            values.extend(self.get_from_db(&missing).await?);
+        }
+        Ok(values)
+    }
+
+    async fn set(&self, key: &str, value: &str) -> Result<(), StoreError> {
+        self.set_many(HashMap::from([(key.to_string(), value.to_string())]))
+            .await
+    }
+
+    async fn set_many(&self, entries: HashMap<String, String>) -> Result<(), StoreError> {
+        let entries = entries.into_iter().map(|(k, v)| (k, Some(v))).collect();
+        match self.buffer(entries) {
+            Some(entries) => self
+                .write_to_db(entries)
+                .await
+                .map_err(|_| StoreError::SetError),
+            None => Ok(()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StoreError> {
+        self.delete_many(vec![key]).await
+    }
+
+    async fn delete_many(&self, keys: Vec<&str>) -> Result<(), StoreError> {
+        let entries = keys.into_iter().map(|k| (k.to_string(), None)).collect();
+        match self.buffer(entries) {
+            Some(entries) => self
+                .write_to_db(entries)
+                .await
+                .map_err(|_| StoreError::DeleteError),
+            None => Ok(()),
+        }
+    }
+}
+
+async fn write_entries(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    entries: Pending,
+) -> Result<(), sqlx::Error> {
+    for (key, value) in entries {
+        match value {
+            Some(value) => {
+                sqlx::query("INSERT OR REPLACE INTO store (key, value) VALUES (?, ?)")
+                    .bind(key)
+                    .bind(value)
+                    .execute(&mut **tx)
+                    .await?;
+            }
+            None => {
+                sqlx::query("DELETE FROM store WHERE key = ?")
+                    .bind(key)
+                    .execute(&mut **tx)
+                    .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A group of MMR appends and leaf index inserts that is committed atomically.
+///
+/// While it is open, the MMR's writes to the [`PooledStore`] are held in memory and
+/// visible to its reads. [`Self::commit`] writes them and the recorded leaves in a
+/// single transaction; dropping the batch without committing discards both.
+pub struct BatchWrite<'a> {
+    pool: &'a SqlitePool,
+    store: Option<&'a PooledStore>,
+    leaves: Vec<(String, usize, usize, Option<u64>)>,
+}
+
+impl<'a> BatchWrite<'a> {
+    /// Opens a batch on `pool`. Without a `store`, only the leaf index inserts are
+    /// atomic and the MMR writes wherever its own store does.
+    pub fn begin(pool: &'a SqlitePool, store: Option<&'a PooledStore>) -> Result<Self> {
+        if let Some(store) = store {
+            store.begin()?;
+        }
+        Ok(Self {
+            pool,
+            store,
+            leaves: Vec::new(),
+        })
+    }
+
+    /// Records the leaf appended at `element_index`, and the block it holds if known
+    pub fn insert_leaf(
+        &mut self,
+        value: &str,
+        element_index: usize,
+        block_number: Option<u64>,
+    ) -> Result<()> {
+        let leaf_index = element_index_to_leaf_index(element_index)?;
+        self.leaves
+            .push((value.to_string(), element_index, leaf_index, block_number));
+        Ok(())
+    }
+
+    pub async fn commit(mut self) -> Result<()> {
+        let entries = self.store.take().map(PooledStore::take_pending);
+        let leaves = std::mem::take(&mut self.leaves);
+
+        let mut tx = self.pool.begin().await?;
+        if let Some(entries) = entries {
+            write_entries(&mut tx, entries).await?;
+        }
+        for (value, element_index, leaf_index, block_number) in leaves {
+            sqlx::query(
+                r#"
+                INSERT OR REPLACE INTO value_index_map (value, element_index, leaf_index, block_number)
+                VALUES (?, ?, ?, ?)
+                "#,
+            )
+            .bind(value)
+            .bind(element_index as i64)
+            .bind(leaf_index as i64)
+            .bind(block_number.map(|n| n as i64))
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+}
+
+impl Drop for BatchWrite<'_> {
+    fn drop(&mut self) {
+        if let Some(store) = self.store {
+            store.take_pending();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StoreManager;
+    use tempfile::TempDir;
+
+    async fn setup() -> (TempDir, SqlitePool, PooledStore) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("batch.db");
+        let pool = open_pool(path.to_str().unwrap()).await.unwrap();
+        StoreManager::with_pool(&pool).await.unwrap();
+        let store = PooledStore::new(pool.clone()).await.unwrap();
+        (dir, pool, store)
+    }
+
+    async fn count(pool: &SqlitePool, table: &str) -> i64 {
+        sqlx::query(&format!("SELECT COUNT(*) AS n FROM {}", table))
+            .fetch_one(pool)
+            .await
+            .unwrap()
+            .get("n")
+    }
+
+    #[tokio::test]
+    async fn test_pool_uses_wal() {
+        let (_dir, pool, _) = setup().await;
+        let mode: String = sqlx::query("PRAGMA journal_mode")
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+            .get(0);
+        assert_eq!(mode, "wal");
+    }
+
+    #[tokio::test]
+    async fn test_store_writes_through_without_batch() {
+        let (_dir, pool, store) = setup().await;
+        store.set("a", "1").await.unwrap();
+        store.set("b", "2").await.unwrap();
+        store.delete("b").await.unwrap();
+
+        assert_eq!(store.get("a").await.unwrap(), Some("1".to_string()));
+        assert_eq!(store.get("b").await.unwrap(), None);
+        assert_eq!(count(&pool, "store").await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_commits_atomically() {
+        let (_dir, pool, store) = setup().await;
+        store.set("kept", "0").await.unwrap();
+
+        let mut batch = BatchWrite::begin(&pool, Some(&store)).unwrap();
+        store.set("a", "1").await.unwrap();
+        store.delete("kept").await.unwrap();
+        batch.insert_leaf("0xaa", 1, Some(7)).unwrap();
+
+        // Visible to the store, not yet in the database
+        assert_eq!(store.get("a").await.unwrap(), Some("1".to_string()));
+        assert_eq!(store.get("kept").await.unwrap(), None);
+        assert_eq!(count(&pool, "value_index_map").await, 0);
+        assert!(BatchWrite::begin(&pool, Some(&store)).is_err());
+
+        batch.commit().await.unwrap();
+        assert_eq!(count(&pool, "store").await, 1);
+        assert_eq!(count(&pool, "value_index_map").await, 1);
+        assert_eq!(store.get("a").await.unwrap(), Some("1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_dropped_batch_discards_writes() {
+        let (_dir, pool, store) = setup().await;
+
+        {
+            let mut batch = BatchWrite::begin(&pool, Some(&store)).unwrap();
+            store.set("a", "1").await.unwrap();
+            batch.insert_leaf("0xaa", 1, None).unwrap();
+            assert!(batch.insert_leaf("0xbb", 3, None).is_err());
+        }
+
+        assert_eq!(store.get("a").await.unwrap(), None);
+        assert_eq!(count(&pool, "store").await, 0);
+        assert_eq!(count(&pool, "value_index_map").await, 0);
+
+        // The store is usable again
+        BatchWrite::begin(&pool, Some(&store)).unwrap();
+    }
+}
@@ -0,0 +1,292 @@
+//! Versioned layout of batch databases.
+//!
+//! Batch databases outlive the publisher that wrote them, so every database records
+//! the version of its layout in `schema_version` and is migrated step by step when it
+//! is opened. Databases written before the table existed are recognized by their
+//! columns. A database newer than this build is refused with
+//! [`UnsupportedSchemaVersion`] rather than read with the wrong layout.
+//!
+//! | version | change                                                              |
+//! |---------|---------------------------------------------------------------------|
+//! | 1       | `store`, `mmr_metadata` and `value_index_map(value, element_index)` |
+//! | 2       | leaf index and block number columns on `value_index_map`            |
+use crate::element_index_to_leaf_index;
+use eyre::Result;
+use sqlx::{Row, SqliteConnection, SqlitePool};
+use std::fmt;
+
+/// Version of the layout written by this build
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// A database written by a newer publisher than this one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedSchemaVersion {
+    pub found: u32,
+    pub supported: u32,
+}
+
+impl UnsupportedSchemaVersion {
+    /// Whether the database was written by a newer publisher than this one
+    pub const fn is_newer(&self) -> bool {
+        self.found > self.supported
+    }
+}
+
+impl fmt::Display for UnsupportedSchemaVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Batch database has schema version {}, but this publisher supports up to {}",
+            self.found, self.supported
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedSchemaVersion {}
+
+/// Returns the schema version of the database, 0 if it has no tables yet
+pub async fn schema_version(pool: &SqlitePool) -> Result<u32> {
+    let mut conn = pool.acquire().await?;
+    read_version(&mut conn).await
+}
+
+/// Fails with [`UnsupportedSchemaVersion`] if the database is newer than this build
+pub async fn check_compatibility(pool: &SqlitePool) -> Result<u32> {
+    let version = schema_version(pool).await?;
+    ensure_supported(version)?;
+    Ok(version)
+}
+
+/// Brings the database up to [`SCHEMA_VERSION`], one transaction per step, and
+/// returns the version it had before
+pub async fn migrate(pool: &SqlitePool) -> Result<u32> {
+    let initial = check_compatibility(pool).await?;
+
+    loop {
+        // Take the write lock before reading the version, so two connections
+        // migrating at once apply each step only once. Another connection may have
+        // migrated the database in the meantime.
+        let mut tx = pool.begin_with("BEGIN IMMEDIATE").await?;
+        let version = read_version(&mut tx).await?;
+        ensure_supported(version)?;
+        if version == SCHEMA_VERSION {
+            return Ok(initial);
+        }
+
+        apply(&mut tx, version + 1).await?;
+        write_version(&mut tx, version + 1).await?;
+        tx.commit().await?;
+    }
+}
+
+fn ensure_supported(version: u32) -> Result<()> {
+    if version > SCHEMA_VERSION {
+        return Err(UnsupportedSchemaVersion {
+            found: version,
+            supported: SCHEMA_VERSION,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+async fn table_exists(conn: &mut SqliteConnection, table: &str) -> Result<bool> {
+    Ok(
+        sqlx::query("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?")
+            .bind(table)
+            .fetch_optional(&mut *conn)
+            .await?
+            .is_some(),
+    )
+}
+
+async fn read_version(conn: &mut SqliteConnection) -> Result<u32> {
+    if table_exists(conn, "schema_version").await? {
+        let version: i64 = sqlx::query("SELECT MAX(version) AS version FROM schema_version")
+            .fetch_one(&mut *conn)
+            .await?
+            .try_get::<Option<i64>, _>("version")?
+            .unwrap_or(0);
+        return Ok(u32::try_from(version)?);
+    }
+
+    // Written before versioning; tell the layouts apart by their columns
+    if !table_exists(conn, "value_index_map").await? {
+        return Ok(0);
+    }
+    let has_leaf_index = sqlx::query("PRAGMA table_info(value_index_map)")
+        .fetch_all(&mut *conn)
+        .await?
+        .iter()
+        .any(|row| row.get::<String, _>("name") == "leaf_index");
+    Ok(if has_leaf_index { 2 } else { 1 })
+}
+
+async fn write_version(conn: &mut SqliteConnection, version: u32) -> Result<()> {
+    sqlx::query("CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL)")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("DELETE FROM schema_version")
+        .execute(&mut *conn)
+        .await?;
+    sqlx::query("INSERT INTO schema_version (version) VALUES (?)")
+        .bind(version as i64)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+/// Migrates the database from `version - 1` to `version`
+async fn apply(conn: &mut SqliteConnection, version: u32) -> Result<()> {
+    match version {
+        1 => {
+            for statement in [
+                "CREATE TABLE IF NOT EXISTS store (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+                "CREATE TABLE IF NOT EXISTS mmr_metadata (mmr_id TEXT PRIMARY KEY)",
+                "CREATE TABLE IF NOT EXISTS value_index_map (value TEXT PRIMARY KEY, element_index INTEGER NOT NULL)",
+            ] {
+                sqlx::query(statement).execute(&mut *conn).await?;
+            }
+        }
+        2 => {
+            for statement in [
+                "ALTER TABLE value_index_map ADD COLUMN leaf_index INTEGER",
+                "ALTER TABLE value_index_map ADD COLUMN block_number INTEGER",
+            ] {
+                sqlx::query(statement).execute(&mut *conn).await?;
+            }
+
+            // Some old publishers also indexed internal nodes; those rows keep a NULL
+            // leaf index
+            let element_indices: Vec<i64> =
+                sqlx::query("SELECT element_index FROM value_index_map")
+                    .fetch_all(&mut *conn)
+                    .await?
+                    .iter()
+                    .map(|row| row.get("element_index"))
+                    .collect();
+            for element_index in element_indices {
+                let Ok(leaf_index) = element_index_to_leaf_index(element_index as usize) else {
+                    continue;
+                };
+                sqlx::query("UPDATE value_index_map SET leaf_index = ? WHERE element_index = ?")
+                    .bind(leaf_index as i64)
+                    .bind(element_index)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+
+            for statement in [
+                "CREATE UNIQUE INDEX IF NOT EXISTS value_index_map_element_index ON value_index_map (element_index)",
+                "CREATE INDEX IF NOT EXISTS value_index_map_leaf_index ON value_index_map (leaf_index)",
+                "CREATE INDEX IF NOT EXISTS value_index_map_block_number ON value_index_map (block_number)",
+            ] {
+                sqlx::query(statement).execute(&mut *conn).await?;
+            }
+        }
+        _ => unreachable!("No migration to schema version {}", version),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn memory_pool() -> SqlitePool {
+        // A single connection, so the in-memory database is not shared between tests
+        sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_migrates_new_database() {
+        let pool = memory_pool().await;
+        assert_eq!(schema_version(&pool).await.unwrap(), 0);
+
+        assert_eq!(migrate(&pool).await.unwrap(), 0);
+        assert_eq!(schema_version(&pool).await.unwrap(), SCHEMA_VERSION);
+
+        // Running it again is a no-op
+        assert_eq!(migrate(&pool).await.unwrap(), SCHEMA_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_migrations() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("batch.db");
+        let path = path.to_str().unwrap();
+        let (a, b) = (
+            crate::pool::open_pool(path).await.unwrap(),
+            crate::pool::open_pool(path).await.unwrap(),
+        );
+
+        let (from_a, from_b) = tokio::join!(migrate(&a), migrate(&b));
+        from_a.unwrap();
+        from_b.unwrap();
+        assert_eq!(schema_version(&a).await.unwrap(), SCHEMA_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_legacy_value_index_is_upgraded() {
+        let pool = memory_pool().await;
+        sqlx::query(
+            "CREATE TABLE value_index_map (value TEXT PRIMARY KEY, element_index INTEGER NOT NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        // Element 3 is an internal node, which old publishers indexed too
+        sqlx::query("INSERT INTO value_index_map VALUES ('a', 1), ('b', 2), ('p', 3), ('c', 4)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(schema_version(&pool).await.unwrap(), 1);
+
+        assert_eq!(migrate(&pool).await.unwrap(), 1);
+
+        let leaves: Vec<(Option<i64>, Option<i64>)> = sqlx::query(
+            "SELECT leaf_index, block_number FROM value_index_map ORDER BY element_index",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap()
+        .iter()
+        .map(|row| (row.get("leaf_index"), row.get("block_number")))
+        .collect();
+        assert_eq!(
+            leaves,
+            vec![
+                (Some(0), None),
+                (Some(1), None),
+                (None, None),
+                (Some(2), None)
+            ]
+        );
+        assert_eq!(schema_version(&pool).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_future_version() {
+        let pool = memory_pool().await;
+        migrate(&pool).await.unwrap();
+        sqlx::query("UPDATE schema_version SET version = ?")
+            .bind(SCHEMA_VERSION as i64 + 1)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let err = migrate(&pool).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<UnsupportedSchemaVersion>(),
+            Some(&UnsupportedSchemaVersion {
+                found: SCHEMA_VERSION + 1,
+                supported: SCHEMA_VERSION,
+            })
+        );
+        assert!(check_compatibility(&pool).await.is_err());
+    }
+}
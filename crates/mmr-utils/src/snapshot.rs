@@ -0,0 +1,679 @@
+//! Canonical binary form of a batch MMR.
+//!
+//! A SQLite file depends on page layout, insertion order and the random `mmr_id`, so
+//! two publishers building the same batch upload different bytes and get different
+//! CIDs. A snapshot holds only what defines the MMR, in a fixed order:
+//!
+//! | field            | size                                  |
+//! |------------------|---------------------------------------|
+//! | magic `FSLBATCH` | 8                                     |
+//! | version          | 2                                     |
+//! | hasher_id        | 1                                     |
+//! | elements_count   | 8                                     |
+//! | leaves_count     | 8                                     |
+//! | hashes           | 32 * elements_count, by element index |
+//! | index_len        | 8                                     |
+//! | leaf index       | 48 * index_len                        |
+//!
+//! Each leaf index entry is the element index (8), the value (32) and the block number
+//! (8, all ones when unknown). Integers are big-endian. The leaf index is sorted by
+//! element index.
+use crate::{element_index_to_leaf_index, get_mmr_id, initialize_mmr};
+use eyre::{eyre, Result};
+use guest_mmr::core::GuestMMR;
+use guest_types::journal::SHA256_HASHER_ID;
+use mmr::MMR;
+use sqlx::{Row, SqlitePool};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const BATCH_SNAPSHOT_MAGIC: &[u8; 8] = b"FSLBATCH";
+pub const BATCH_SNAPSHOT_VERSION: u16 = 2;
+
+/// A snapshot in a format this build cannot read, written by an older or a newer
+/// publisher
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedSnapshotVersion {
+    pub found: u16,
+    pub supported: u16,
+}
+
+impl UnsupportedSnapshotVersion {
+    /// Whether the snapshot was written by a newer publisher than this one
+    pub const fn is_newer(&self) -> bool {
+        self.found > self.supported
+    }
+}
+
+impl fmt::Display for UnsupportedSnapshotVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_newer() {
+            write!(
+                f,
+                "Batch snapshot has version {}, newer than version {} this publisher supports",
+                self.found, self.supported
+            )
+        } else {
+            write!(
+                f,
+                "Batch snapshot has version {}, older than version {} this publisher supports",
+                self.found, self.supported
+            )
+        }
+    }
+}
+
+impl std::error::Error for UnsupportedSnapshotVersion {}
+
+/// Block number of leaves whose block is not recorded
+const UNKNOWN_BLOCK: u64 = u64::MAX;
+
+const SQLITE_MAGIC: &[u8] = b"SQLite format 3\0";
+const FIXED_LEN: usize = 8 + 2 + 1 + 8 + 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchSnapshot {
+    hasher_id: u8,
+    leaves_count: u64,
+    /// Node hashes for element indices `1..=elements_count`
+    hashes: Vec<[u8; 32]>,
+    /// `(element_index, value, block_number)` of every indexed leaf, sorted by element
+    /// index
+    leaf_index: Vec<(u64, [u8; 32], Option<u64>)>,
+}
+
+impl BatchSnapshot {
+    /// Builds a snapshot from hex hashes ordered by element index and an unordered
+    /// leaf index
+    pub fn new(
+        hasher_id: u8,
+        leaves_count: usize,
+        hashes: &[String],
+        leaf_index: &[(usize, String, Option<u64>)],
+    ) -> Result<Self> {
+        let hashes = hashes
+            .iter()
+            .map(|hash| hash_to_bytes(hash))
+            .collect::<Result<Vec<_>>>()?;
+        let mut leaf_index = leaf_index
+            .iter()
+            .map(|(index, value, block_number)| {
+                if *block_number == Some(UNKNOWN_BLOCK) {
+                    return Err(eyre!("Block number {} is reserved", UNKNOWN_BLOCK));
+                }
+                Ok((*index as u64, hash_to_bytes(value)?, *block_number))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        leaf_index.sort_unstable();
+
+        let snapshot = Self {
+            hasher_id,
+            leaves_count: leaves_count as u64,
+            hashes,
+            leaf_index,
+        };
+        snapshot.check_shape()?;
+        Ok(snapshot)
+    }
+
+    pub const fn hasher_id(&self) -> u8 {
+        self.hasher_id
+    }
+
+    pub fn elements_count(&self) -> usize {
+        self.hashes.len()
+    }
+
+    pub const fn leaves_count(&self) -> usize {
+        self.leaves_count as usize
+    }
+
+    /// Node hashes as `0x`-prefixed hex, by element index starting at 1
+    pub fn hashes(&self) -> Vec<String> {
+        self.hashes.iter().map(bytes_to_hash).collect()
+    }
+
+    /// `(element_index, value, block_number)` of every indexed leaf, sorted by element
+    /// index
+    pub fn leaf_index(&self) -> Vec<(usize, String, Option<u64>)> {
+        self.leaf_index
+            .iter()
+            .map(|(index, value, block_number)| {
+                (*index as usize, bytes_to_hash(value), *block_number)
+            })
+            .collect()
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out =
+            Vec::with_capacity(FIXED_LEN + 8 + 32 * self.hashes.len() + 48 * self.leaf_index.len());
+        out.extend_from_slice(BATCH_SNAPSHOT_MAGIC);
+        out.extend_from_slice(&BATCH_SNAPSHOT_VERSION.to_be_bytes());
+        out.push(self.hasher_id);
+        out.extend_from_slice(&(self.hashes.len() as u64).to_be_bytes());
+        out.extend_from_slice(&self.leaves_count.to_be_bytes());
+        for hash in &self.hashes {
+            out.extend_from_slice(hash);
+        }
+        out.extend_from_slice(&(self.leaf_index.len() as u64).to_be_bytes());
+        for (index, value, block_number) in &self.leaf_index {
+            out.extend_from_slice(&index.to_be_bytes());
+            out.extend_from_slice(value);
+            out.extend_from_slice(&block_number.unwrap_or(UNKNOWN_BLOCK).to_be_bytes());
+        }
+        out
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader { bytes, offset: 0 };
+
+        if reader.take(8)? != BATCH_SNAPSHOT_MAGIC {
+            return Err(eyre!("Not a batch snapshot"));
+        }
+        let version = u16::from_be_bytes(reader.array()?);
+        if version != BATCH_SNAPSHOT_VERSION {
+            return Err(UnsupportedSnapshotVersion {
+                found: version,
+                supported: BATCH_SNAPSHOT_VERSION,
+            }
+            .into());
+        }
+        let hasher_id = reader.take(1)?[0];
+        let elements_count = reader.u64()?;
+        let leaves_count = reader.u64()?;
+        // Counts are untrusted, so check them against the input before allocating
+        reader.ensure_entries(elements_count, 32)?;
+
+        let hashes = (0..elements_count)
+            .map(|_| reader.array())
+            .collect::<Result<Vec<[u8; 32]>>>()?;
+        let index_len = reader.u64()?;
+        reader.ensure_entries(index_len, 48)?;
+        let leaf_index = (0..index_len)
+            .map(|_| {
+                let index = reader.u64()?;
+                let value = reader.array()?;
+                let block_number = Some(reader.u64()?).filter(|n| *n != UNKNOWN_BLOCK);
+                Ok((index, value, block_number))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if reader.offset != bytes.len() {
+            return Err(eyre!(
+                "{} trailing bytes after batch snapshot",
+                bytes.len() - reader.offset
+            ));
+        }
+
+        let snapshot = Self {
+            hasher_id,
+            leaves_count,
+            hashes,
+            leaf_index,
+        };
+        snapshot.check_shape()?;
+        Ok(snapshot)
+    }
+
+    /// Structural checks that need no hashing
+    fn check_shape(&self) -> Result<()> {
+        if self.hasher_id != SHA256_HASHER_ID {
+            return Err(eyre!("Unknown hasher ID {}", self.hasher_id));
+        }
+
+        let expected_elements = mmr_size(self.leaves_count)
+            .ok_or_else(|| eyre!("Leaves count {} is too large", self.leaves_count))?;
+        if self.hashes.len() as u64 != expected_elements {
+            return Err(eyre!(
+                "{} leaves need {} elements, snapshot has {}",
+                self.leaves_count,
+                expected_elements,
+                self.hashes.len()
+            ));
+        }
+
+        let mut previous = 0;
+        for (index, value, _) in &self.leaf_index {
+            if *index <= previous || *index > self.hashes.len() as u64 {
+                return Err(eyre!("Leaf index entry {} is out of order or range", index));
+            }
+            if self.hashes[*index as usize - 1] != *value {
+                return Err(eyre!("Leaf index entry {} does not match the MMR", index));
+            }
+            previous = *index;
+        }
+        Ok(())
+    }
+
+    /// Rebuilds the MMR from its leaves, checks that every stored node matches and
+    /// returns the root hash. Depends on nothing but the snapshot itself.
+    pub fn verify(&self) -> Result<String> {
+        let mut mmr = GuestMMR::new_empty();
+        while mmr.get_elements_count() < self.hashes.len() {
+            let leaf = bytes_to_hash(&self.hashes[mmr.get_elements_count()]);
+            mmr.append(leaf)?;
+        }
+
+        if mmr.get_leaves_count() as u64 != self.leaves_count {
+            return Err(eyre!(
+                "Snapshot claims {} leaves, MMR has {}",
+                self.leaves_count,
+                mmr.get_leaves_count()
+            ));
+        }
+        for (index, hash) in mmr.get_all_hashes() {
+            if self.hashes[index - 1] != hash_to_bytes(&hash)? {
+                return Err(eyre!("Hash mismatch at element {}", index));
+            }
+        }
+
+        mmr.calculate_root_hash(self.hashes.len())
+    }
+
+    /// Reads the MMR held in a batch database
+    pub async fn export(mmr: &MMR, pool: &SqlitePool) -> Result<Self> {
+        let mmr_id = get_mmr_id(pool)
+            .await?
+            .ok_or_else(|| eyre!("Batch database has no MMR ID"))?;
+        let elements_count = mmr.elements_count.get().await?;
+        let leaves_count = mmr.leaves_count.get().await?;
+
+        let prefix = format!("{}:hashes:", mmr_id);
+        let rows = sqlx::query("SELECT key, value FROM store WHERE key LIKE ?")
+            .bind(format!("{}%", prefix))
+            .fetch_all(pool)
+            .await?;
+
+        let mut hashes = vec![None; elements_count];
+        for row in rows {
+            let key: String = row.get("key");
+            let value: String = row.get("value");
+            let index: usize = key
+                .strip_prefix(&prefix)
+                .and_then(|index| index.parse().ok())
+                .ok_or_else(|| eyre!("Malformed store key {}", key))?;
+            let slot = index
+                .checked_sub(1)
+                .and_then(|slot| hashes.get_mut(slot))
+                .ok_or_else(|| eyre!("Element {} is beyond the MMR size", index))?;
+            *slot = Some(value);
+        }
+        let hashes = hashes
+            .into_iter()
+            .enumerate()
+            .map(|(slot, hash)| hash.ok_or_else(|| eyre!("Missing hash for element {}", slot + 1)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let leaf_index = sqlx::query(
+            "SELECT value, element_index, block_number FROM value_index_map \
+                 WHERE leaf_index IS NOT NULL",
+        )
+        .fetch_all(pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let index: i64 = row.get("element_index");
+            let block_number: Option<i64> = row.get("block_number");
+            (
+                index as usize,
+                row.get::<String, _>("value"),
+                block_number.map(|n| n as u64),
+            )
+        })
+        .collect::<Vec<_>>();
+
+        Self::new(SHA256_HASHER_ID, leaves_count, &hashes, &leaf_index)
+    }
+
+    /// Writes this snapshot into the empty batch database at `store_path` under a
+    /// fresh MMR ID
+    pub async fn import(&self, store_path: &str) -> Result<()> {
+        let (_, mmr, pool) = initialize_mmr(store_path).await?;
+        if mmr.elements_count.get().await? != 0 {
+            return Err(eyre!("Refusing to import into a non-empty database"));
+        }
+        let mmr_id = get_mmr_id(&pool)
+            .await?
+            .ok_or_else(|| eyre!("Batch database has no MMR ID"))?;
+
+        let mut tx = pool.begin().await?;
+        for (slot, hash) in self.hashes().into_iter().enumerate() {
+            sqlx::query("INSERT OR REPLACE INTO store (key, value) VALUES (?, ?)")
+                .bind(format!("{}:hashes:{}", mmr_id, slot + 1))
+                .bind(hash)
+                .execute(&mut *tx)
+                .await?;
+        }
+        for (index, value, block_number) in self.leaf_index() {
+            sqlx::query(
+                "INSERT OR REPLACE INTO value_index_map \
+                 (value, element_index, leaf_index, block_number) VALUES (?, ?, ?, ?)",
+            )
+            .bind(value)
+            .bind(index as i64)
+            .bind(element_index_to_leaf_index(index).ok().map(|n| n as i64))
+            .bind(block_number.map(|n| n as i64))
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        mmr.elements_count.set(self.elements_count()).await?;
+        mmr.leaves_count.set(self.leaves_count()).await?;
+
+        pool.close().await;
+        Ok(())
+    }
+}
+
+/// Returns whether `bytes` starts like a batch snapshot
+pub fn is_batch_snapshot(bytes: &[u8]) -> bool {
+    bytes.starts_with(BATCH_SNAPSHOT_MAGIC)
+}
+
+/// Turns a downloaded batch at `download_path` into a SQLite database at `db_path`.
+/// Snapshots are verified and imported; plain SQLite files, as uploaded before
+/// snapshots existed, are moved into place. `db_path` is only replaced on success.
+pub async fn restore_batch_db(download_path: &Path, db_path: &Path) -> Result<()> {
+    let bytes = fs::read(download_path)?;
+
+    if is_batch_snapshot(&bytes) {
+        let snapshot = BatchSnapshot::decode(&bytes)?;
+        snapshot.verify()?;
+
+        let importing = with_suffix(db_path, ".importing");
+        fs::File::create(&importing)?;
+        let importing_str = importing
+            .to_str()
+            .ok_or_else(|| eyre!("Invalid path: {:?}", importing))?;
+        if let Err(e) = snapshot.import(importing_str).await {
+            let _ = fs::remove_file(&importing);
+            return Err(e);
+        }
+        fs::rename(&importing, db_path)?;
+    } else if bytes.starts_with(SQLITE_MAGIC) {
+        fs::rename(download_path, db_path)?;
+    } else {
+        return Err(eyre!(
+            "Downloaded batch is neither a snapshot nor a SQLite file"
+        ));
+    }
+    Ok(())
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Number of elements in an MMR with `leaves_count` leaves, `None` if it overflows
+fn mmr_size(leaves_count: u64) -> Option<u64> {
+    leaves_count
+        .checked_mul(2)?
+        .checked_sub(leaves_count.count_ones() as u64)
+}
+
+fn hash_to_bytes(hash: &str) -> Result<[u8; 32]> {
+    let digits = hash
+        .strip_prefix("0x")
+        .ok_or_else(|| eyre!("Hash is not 0x-prefixed: {}", hash))?;
+    if digits.len() != 64 || !digits.is_ascii() {
+        return Err(eyre!("Hash is not 32 bytes: {}", hash));
+    }
+
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[2 * i..2 * i + 2], 16)
+            .map_err(|_| eyre!("Hash is not hex: {}", hash))?;
+    }
+    Ok(out)
+}
+
+fn bytes_to_hash(bytes: &[u8; 32]) -> String {
+    let mut out = String::with_capacity(66);
+    out.push_str("0x");
+    for byte in bytes {
+        out.push_str(&format!("{:02x}", byte));
+    }
+    out
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| eyre!("Truncated batch snapshot"))?;
+        let slice = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_be_bytes(self.array()?))
+    }
+
+    /// Checks that `count` entries of `len` bytes fit in the remaining input
+    fn ensure_entries(&self, count: u64, len: usize) -> Result<()> {
+        let remaining = (self.bytes.len() - self.offset) / len;
+        if count > remaining as u64 {
+            return Err(eyre!("Truncated batch snapshot"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn leaf(i: u8) -> String {
+        format!("0x{}", format!("{:02x}", i).repeat(32))
+    }
+
+    fn block(i: u8) -> u64 {
+        1000 + i as u64
+    }
+
+    /// Snapshot of an MMR built in memory from `count` leaves
+    fn guest_snapshot(count: u8) -> (BatchSnapshot, String) {
+        let mut mmr = GuestMMR::new_empty();
+        let mut leaf_index = Vec::new();
+        for i in 0..count {
+            let result = mmr.append(leaf(i)).unwrap();
+            leaf_index.push((result.element_index(), leaf(i), Some(block(i))));
+        }
+        let hashes: Vec<String> = mmr.get_all_hashes().into_iter().map(|(_, h)| h).collect();
+        let root = mmr.calculate_root_hash(hashes.len()).unwrap();
+
+        let snapshot = BatchSnapshot::new(
+            SHA256_HASHER_ID,
+            mmr.get_leaves_count(),
+            &hashes,
+            &leaf_index,
+        )
+        .unwrap();
+        (snapshot, root)
+    }
+
+    async fn sqlite_batch(dir: &TempDir, name: &str, count: u8) -> (MMR, SqlitePool) {
+        let path = dir.path().join(name);
+        fs::File::create(&path).unwrap();
+        let (store_manager, mut mmr, pool) = initialize_mmr(path.to_str().unwrap()).await.unwrap();
+
+        for i in 0..count {
+            let result = mmr.append(leaf(i)).await.unwrap();
+            store_manager
+                .insert_leaf(&pool, &leaf(i), result.element_index, Some(block(i)))
+                .await
+                .unwrap();
+        }
+        (mmr, pool)
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let (snapshot, _) = guest_snapshot(7);
+        let encoded = snapshot.encode();
+
+        assert!(is_batch_snapshot(&encoded));
+        assert_eq!(encoded.len(), FIXED_LEN + 32 * 11 + 8 + 48 * 7);
+        assert_eq!(BatchSnapshot::decode(&encoded).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn test_decode_rejects_malformed_input() {
+        let (snapshot, _) = guest_snapshot(3);
+        let encoded = snapshot.encode();
+
+        assert!(BatchSnapshot::decode(&encoded[..encoded.len() - 1]).is_err());
+        assert!(BatchSnapshot::decode(&[encoded.as_slice(), &[0]].concat()).is_err());
+        assert!(BatchSnapshot::decode(b"SQLite format 3\0").is_err());
+
+        let mut newer = encoded.clone();
+        newer[9] = 3;
+        assert_eq!(
+            BatchSnapshot::decode(&newer)
+                .unwrap_err()
+                .downcast_ref::<UnsupportedSnapshotVersion>(),
+            Some(&UnsupportedSnapshotVersion {
+                found: 3,
+                supported: BATCH_SNAPSHOT_VERSION,
+            })
+        );
+
+        let mut older = encoded.clone();
+        older[9] = 1;
+        let err = BatchSnapshot::decode(&older).unwrap_err();
+        let version = err.downcast_ref::<UnsupportedSnapshotVersion>().unwrap();
+        assert!(!version.is_newer());
+        assert!(err.to_string().contains("older than version"), "{}", err);
+
+        // Element count that no number of leaves produces
+        let mut wrong_leaves = encoded.clone();
+        wrong_leaves[FIXED_LEN - 1] = 4;
+        assert!(BatchSnapshot::decode(&wrong_leaves).is_err());
+
+        // Leaves count whose MMR size overflows
+        let mut huge_leaves = encoded.clone();
+        huge_leaves[FIXED_LEN - 8..FIXED_LEN].copy_from_slice(&u64::MAX.to_be_bytes());
+        assert_eq!(
+            BatchSnapshot::decode(&huge_leaves).unwrap_err().to_string(),
+            format!("Leaves count {} is too large", u64::MAX)
+        );
+
+        // Counts far beyond the input are rejected before allocating
+        let mut huge_elements = encoded.clone();
+        huge_elements[FIXED_LEN - 16..FIXED_LEN - 8].copy_from_slice(&u64::MAX.to_be_bytes());
+        assert!(BatchSnapshot::decode(&huge_elements).is_err());
+        let index_len = FIXED_LEN + 32 * 4;
+        let mut huge_index = encoded;
+        huge_index[index_len..index_len + 8].copy_from_slice(&u64::MAX.to_be_bytes());
+        assert!(BatchSnapshot::decode(&huge_index).is_err());
+    }
+
+    #[test]
+    fn test_mmr_size() {
+        assert_eq!(mmr_size(0), Some(0));
+        assert_eq!(mmr_size(1), Some(1));
+        assert_eq!(mmr_size(3), Some(4));
+        assert_eq!(mmr_size(7), Some(11));
+        assert_eq!(mmr_size(u64::MAX / 2), Some(u64::MAX - 64));
+        assert_eq!(mmr_size(u64::MAX / 2 + 1), None);
+    }
+
+    #[test]
+    fn test_verify_returns_root() {
+        for count in [0, 1, 2, 5, 16, 33] {
+            let (snapshot, root) = guest_snapshot(count);
+            assert_eq!(snapshot.verify().unwrap(), root);
+        }
+    }
+
+    #[test]
+    fn test_verify_detects_tampering() {
+        let (snapshot, _) = guest_snapshot(5);
+        let mut hashes = snapshot.hashes();
+        // Element 3 is the parent of the first two leaves
+        hashes[2] = leaf(0xee);
+
+        let tampered =
+            BatchSnapshot::new(SHA256_HASHER_ID, 5, &hashes, &snapshot.leaf_index()).unwrap();
+        assert_eq!(
+            tampered.verify().unwrap_err().to_string(),
+            "Hash mismatch at element 3"
+        );
+    }
+
+    #[test]
+    fn test_leaf_index_must_match_hashes() {
+        let (snapshot, _) = guest_snapshot(2);
+        let result = BatchSnapshot::new(
+            SHA256_HASHER_ID,
+            2,
+            &snapshot.hashes(),
+            &[(1, leaf(9), None)],
+        );
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_export_is_deterministic() {
+        let dir = TempDir::new().unwrap();
+        // Separate databases get different random MMR IDs
+        let (mmr_a, pool_a) = sqlite_batch(&dir, "a.db", 10).await;
+        let (mmr_b, pool_b) = sqlite_batch(&dir, "b.db", 10).await;
+
+        let a = BatchSnapshot::export(&mmr_a, &pool_a).await.unwrap();
+        let b = BatchSnapshot::export(&mmr_b, &pool_b).await.unwrap();
+        assert_eq!(a.encode(), b.encode());
+        assert_eq!(a, guest_snapshot(10).0);
+    }
+
+    #[tokio::test]
+    async fn test_restore_imports_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let (mmr, pool) = sqlite_batch(&dir, "source.db", 6).await;
+        let snapshot = BatchSnapshot::export(&mmr, &pool).await.unwrap();
+
+        let download = dir.path().join("download");
+        let restored = dir.path().join("restored.db");
+        fs::write(&download, snapshot.encode()).unwrap();
+        restore_batch_db(&download, &restored).await.unwrap();
+
+        let (_, mmr, pool) = initialize_mmr(restored.to_str().unwrap()).await.unwrap();
+        assert_eq!(mmr.leaves_count.get().await.unwrap(), 6);
+        let elements_count = mmr.elements_count.get().await.unwrap();
+        let bag = mmr.bag_the_peaks(Some(elements_count)).await.unwrap();
+        let root = mmr.calculate_root_hash(&bag, elements_count).unwrap();
+        assert_eq!(root, snapshot.verify().unwrap());
+
+        assert_eq!(BatchSnapshot::export(&mmr, &pool).await.unwrap(), snapshot);
+    }
+
+    #[tokio::test]
+    async fn test_restore_rejects_unknown_format() {
+        let dir = TempDir::new().unwrap();
+        let download = dir.path().join("download");
+        let restored = dir.path().join("restored.db");
+        fs::write(&download, b"not a batch").unwrap();
+
+        assert!(restore_batch_db(&download, &restored).await.is_err());
+        assert!(!restored.exists());
+    }
+}
@@ -0,0 +1,8 @@
+use clap::Parser;
+use publisher::cli::groth16_fixture::{run, Args};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    run(args).await
+}
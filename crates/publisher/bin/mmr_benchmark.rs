@@ -1,4 +1,6 @@
 use clap::Parser;
+use eth_rlp_types::BlockHeader;
+use guest_types::header::Header;
 use methods::MMR_BENCHMARK_ELF;
 use publisher::db::DbConnection;
 use risc0_zkvm::{default_executor, ExecutorEnv};
@@ -27,9 +29,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         e
     })?;
 
-    let block_headers = db_connection
+    let block_headers: Vec<BlockHeader> = db_connection
         .get_block_headers_by_block_range(args.start_block, args.end_block)
-        .await?;
+        .await?
+        .into_iter()
+        .map(Header::into_block)
+        .collect();
 
     // Execute the guest code.
     let env = ExecutorEnv::builder().write(&block_headers)?.build()?;
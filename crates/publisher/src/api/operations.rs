@@ -1,34 +1,38 @@
+use common::signer::KeySource;
 use starknet_handler::{account::StarknetAccount, provider::StarknetProvider};
 
 use crate::core::{AccumulatorBuilder, BatchProcessor, MMRStateManager, ProofGenerator};
-use eyre::Result;
+use eyre::{eyre, Result};
+use ipfs_utils::{BatchPin, BatchStore, PinScope};
 use methods::{MMR_BUILD_ELF, MMR_BUILD_ID};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub async fn prove_mmr_update(
-    rpc_url: &String,
+    starknet_provider: &StarknetProvider,
     chain_id: u64,
     verifier_address: &String,
     store_address: &String,
-    account_private_key: &String,
+    key_source: &KeySource,
     account_address: &String,
     batch_size: u64,
     start_block: u64,
     end_block: u64,
 ) -> Result<()> {
-    let starknet_provider = StarknetProvider::new(rpc_url)?;
-    let starknet_account = StarknetAccount::new(
+    let starknet_account = StarknetAccount::from_key_source(
         starknet_provider.provider(),
-        account_private_key,
+        key_source,
         account_address,
     )?;
 
     // Create components for AccumulatorBuilder
     let proof_generator = ProofGenerator::new(MMR_BUILD_ELF, MMR_BUILD_ID)?;
-    let mmr_state_manager = MMRStateManager::new(starknet_account, store_address, rpc_url);
+    let mmr_state_manager =
+        MMRStateManager::new(starknet_account, store_address, starknet_provider.clone());
     let batch_processor = BatchProcessor::new(batch_size, proof_generator, mmr_state_manager)?;
 
     let mut builder = AccumulatorBuilder::new(
-        rpc_url,
         chain_id,
         verifier_address,
         batch_processor,
@@ -56,32 +60,27 @@ pub async fn prove_mmr_update(
     Ok(())
 }
 
+/// Like [`prove_mmr_update`], sending through `starknet_account`. Callers updating
+/// repeatedly pass the same account, so its nonce count carries over between
+/// updates.
 pub async fn update_mmr(
-    rpc_url: &String,
+    starknet_provider: &StarknetProvider,
     chain_id: u64,
     verifier_address: &String,
     store_address: &String,
-    account_private_key: &String,
-    account_address: &String,
+    starknet_account: Arc<StarknetAccount>,
     batch_size: u64,
     start_block: u64,
     end_block: u64,
 ) -> Result<Option<String>> {
-    let starknet_provider = StarknetProvider::new(rpc_url)?;
-    let starknet_account = StarknetAccount::new(
-        starknet_provider.provider(),
-        account_private_key,
-        account_address,
-    )?;
-
     // Create components for AccumulatorBuilder
     let proof_generator = ProofGenerator::new(MMR_BUILD_ELF, MMR_BUILD_ID)?;
-    let mmr_state_manager = MMRStateManager::new(starknet_account, store_address, rpc_url);
+    let mmr_state_manager =
+        MMRStateManager::from_shared(starknet_account, store_address, starknet_provider.clone());
     let batch_processor = BatchProcessor::new(batch_size, proof_generator, mmr_state_manager)?;
 
     // Use the constructor directly with the correct signature
     let mut builder = AccumulatorBuilder::new(
-        rpc_url,
         chain_id,
         verifier_address,
         batch_processor,
@@ -98,3 +97,120 @@ pub async fn update_mmr(
     // For now, return None as we don't have a way to capture the tx hash
     Ok(None)
 }
+
+/// Unpins batch databases that no batch on-chain points at any more. Every incomplete
+/// batch update uploads a new database and replaces the batch's CID, leaving the old
+/// one pinned. Returns the superseded CIDs, which are only unpinned if `dry_run` is
+/// false.
+///
+/// Only pins `batch_store` made for batch uploads of this chain and store are
+/// considered, and only once they are older than `grace_period`: a fresh upload is
+/// not referenced on-chain until its proof has been verified.
+pub async fn gc_pins(
+    starknet_provider: &StarknetProvider,
+    chain_id: u64,
+    store_address: &str,
+    batch_store: &dyn BatchStore,
+    batch_size: u64,
+    grace_period: Duration,
+    dry_run: bool,
+) -> Result<Vec<String>> {
+    if batch_size == 0 {
+        return Err(eyre!("Batch size must be greater than 0"));
+    }
+
+    let snapshot = starknet_provider.snapshot().await?;
+
+    let min_block = snapshot.get_min_mmr_block(store_address).await?;
+    let latest_block = snapshot.get_latest_mmr_block(store_address).await?;
+
+    let mut referenced = HashSet::new();
+    for batch_index in min_block / batch_size..=latest_block / batch_size {
+        let state = snapshot.get_mmr_state(store_address, batch_index).await?;
+        let cid = state
+            .ipfs_hash()
+            .map_err(|e| eyre!("Invalid IPFS hash for batch {}: {}", batch_index, e))?;
+        if let Some(cid) = cid {
+            referenced.insert(cid.to_string());
+        }
+    }
+
+    // An empty set most likely means the wrong store address, not that every pin is
+    // garbage
+    if referenced.is_empty() {
+        return Err(eyre!(
+            "No batch in store {} references a CID, refusing to unpin",
+            store_address
+        ));
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let superseded = superseded_pins(
+        batch_store
+            .list_batch_pins(&PinScope::new(chain_id, store_address))
+            .await?,
+        &referenced,
+        now.saturating_sub(grace_period.as_secs()),
+    );
+    tracing::info!(
+        referenced = referenced.len(),
+        superseded = superseded.len(),
+        "Collected batch pins"
+    );
+
+    if !dry_run {
+        for cid in &superseded {
+            batch_store.unpin(cid).await?;
+        }
+    }
+
+    Ok(superseded)
+}
+
+/// Pins made no later than `pinned_before` that are not referenced by any batch
+fn superseded_pins(
+    pins: Vec<BatchPin>,
+    referenced: &HashSet<String>,
+    pinned_before: u64,
+) -> Vec<String> {
+    pins.into_iter()
+        .filter(|pin| pin.pinned_at <= pinned_before && !referenced.contains(&pin.cid))
+        .map(|pin| pin.cid)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_superseded_pins() {
+        let referenced: HashSet<String> = ["QmCurrent0", "QmCurrent1"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let pins = [
+            ("QmCurrent0", 10),
+            ("QmOld0", 10),
+            ("QmCurrent1", 20),
+            ("QmOld1", 20),
+            ("QmFresh", 30),
+        ]
+        .into_iter()
+        .map(|(cid, pinned_at)| BatchPin {
+            cid: cid.to_string(),
+            pinned_at,
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            superseded_pins(pins.clone(), &referenced, 29),
+            vec!["QmOld0", "QmOld1"]
+        );
+        assert_eq!(
+            superseded_pins(pins.clone(), &referenced, 30),
+            vec!["QmOld0", "QmOld1", "QmFresh"]
+        );
+        assert_eq!(superseded_pins(pins, &referenced, 15), vec!["QmOld0"]);
+    }
+}
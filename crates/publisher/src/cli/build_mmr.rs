@@ -1,7 +1,8 @@
 use crate::core::AccumulatorBuilder;
 use crate::core::{BatchProcessor, MMRStateManager, ProofGenerator};
 use clap::Parser;
-use common::{get_env_var, initialize_logger_and_env};
+use common::deployment::{contract_address, Contract};
+use common::{get_env_var, initialize_logger_and_env, signer::KeySource};
 use methods::{MMR_BUILD_ELF, MMR_BUILD_ID};
 use starknet_handler::{account::StarknetAccount, provider::StarknetProvider};
 #[derive(Parser, Debug)]
@@ -39,23 +40,30 @@ pub async fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
 
     let chain_id = get_env_var("CHAIN_ID")?.parse::<u64>()?;
     let rpc_url = get_env_var("STARKNET_RPC_URL")?;
-    let verifier_address = get_env_var("FOSSIL_VERIFIER")?;
-    let store_address = get_env_var("FOSSIL_STORE")?;
-    let private_key = get_env_var("STARKNET_PRIVATE_KEY")?;
+    let verifier_address = contract_address(Contract::FossilVerifier)?;
+    let store_address = contract_address(Contract::Store)?;
+    let key_source = KeySource::from_env("STARKNET")?;
     let account_address = get_env_var("STARKNET_ACCOUNT_ADDRESS")?;
 
     let starknet_provider = StarknetProvider::new(&rpc_url)?;
-    let starknet_account =
-        StarknetAccount::new(starknet_provider.provider(), &private_key, &account_address)?;
+    let starknet_account = StarknetAccount::from_key_source(
+        starknet_provider.provider(),
+        &key_source,
+        &account_address,
+    )?;
 
     // Create the batch processor first
     let proof_generator = ProofGenerator::new(MMR_BUILD_ELF, MMR_BUILD_ID)?;
-    let mmr_state_manager = MMRStateManager::new(starknet_account, &store_address, &rpc_url);
+    tracing::info!(
+        method_id = %proof_generator.method_id_hex(),
+        "Proving with the mmr-build guest"
+    );
+    let mmr_state_manager =
+        MMRStateManager::new(starknet_account, &store_address, starknet_provider);
     let batch_processor = BatchProcessor::new(args.batch_size, proof_generator, mmr_state_manager)?;
 
     // Then create the accumulator builder
     let mut builder = AccumulatorBuilder::new(
-        &rpc_url,
         chain_id,
         &verifier_address,
         batch_processor,
@@ -0,0 +1,92 @@
+use crate::api::operations::gc_pins;
+use clap::Parser;
+use common::deployment::{contract_address, Contract};
+use common::get_env_var;
+use ipfs_utils::batch_store_from_env;
+use starknet_handler::provider::StarknetProvider;
+use std::time::Duration;
+use tracing::info;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Unpin batch databases no longer referenced on-chain", long_about = None)]
+pub struct Args {
+    /// Number of blocks in each batch
+    #[arg(short = 'b', long, default_value_t = 1024)]
+    pub batch_size: u64,
+
+    /// Hours a pin is kept after upload even if no batch references it yet, covering
+    /// proofs still queued or in flight
+    #[arg(short = 'g', long, default_value_t = 24)]
+    pub grace_period_hours: u64,
+
+    /// List the superseded pins without removing them
+    #[arg(short = 'n', long, default_value_t = false)]
+    pub dry_run: bool,
+}
+
+pub struct Config {
+    pub rpc_url: String,
+    pub chain_id: u64,
+    pub store_address: String,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self {
+            rpc_url: get_env_var("STARKNET_RPC_URL")?,
+            chain_id: get_env_var("CHAIN_ID")?.parse()?,
+            store_address: contract_address(Contract::Store)?,
+        })
+    }
+}
+
+pub async fn run(config: Config, args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    let starknet_provider = StarknetProvider::new(&config.rpc_url)?;
+    let batch_store = batch_store_from_env()?;
+    let superseded = gc_pins(
+        &starknet_provider,
+        config.chain_id,
+        &config.store_address,
+        batch_store.as_ref(),
+        args.batch_size,
+        Duration::from_secs(args.grace_period_hours * 3600),
+        args.dry_run,
+    )
+    .await?;
+
+    for cid in &superseded {
+        info!(cid, dry_run = args.dry_run, "Superseded batch pin");
+    }
+    if args.dry_run {
+        info!("Found {} superseded pins", superseded.len());
+    } else {
+        info!("Unpinned {} superseded pins", superseded.len());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args() {
+        let args = Args::parse_from(["gc_pins"]);
+        assert_eq!(args.batch_size, 1024);
+        assert_eq!(args.grace_period_hours, 24);
+        assert!(!args.dry_run);
+
+        let args = Args::parse_from([
+            "gc_pins",
+            "--batch-size",
+            "512",
+            "--grace-period-hours",
+            "2",
+            "--dry-run",
+        ]);
+        assert_eq!(args.batch_size, 512);
+        assert_eq!(args.grace_period_hours, 2);
+        assert!(args.dry_run);
+    }
+}
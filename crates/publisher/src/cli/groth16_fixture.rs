@@ -0,0 +1,203 @@
+use crate::core::ProofGenerator;
+use crate::db::DbConnection;
+use clap::Parser;
+use common::{get_env_var, initialize_logger_and_env};
+use eyre::eyre;
+use guest_types::{
+    fees::group_headers_by_hour, header::verify_headers, CombinedInput, GuestOutput, MMRInput,
+};
+use methods::{MMR_BUILD_ELF, MMR_BUILD_ID};
+use starknet::core::types::Felt;
+use std::fmt::Write;
+use tracing::info;
+
+#[derive(Parser, Debug)]
+#[command(
+    version,
+    about = "Prove one batch with the current guest and write the Cairo Groth16 test fixture",
+    long_about = None
+)]
+pub struct Args {
+    /// First block of the batch to prove, a multiple of the batch size
+    #[arg(short = 's', long)]
+    pub start_block: u64,
+
+    /// Number of blocks in each batch
+    #[arg(short = 'b', long, default_value_t = 1024)]
+    pub batch_size: u64,
+
+    /// Path to environment file (optional)
+    #[arg(short = 'e', long, default_value = ".env")]
+    pub env_file: String,
+
+    /// Where to write the fixture
+    #[arg(
+        short = 'o',
+        long,
+        default_value = "contracts/starknet/verifier/tests/groth16_fixture.cairo"
+    )]
+    pub output: String,
+}
+
+pub async fn run(args: Args) -> Result<(), Box<dyn std::error::Error>> {
+    dotenv::from_path(&args.env_file)?;
+    initialize_logger_and_env()?;
+
+    if args.batch_size == 0 || args.start_block % args.batch_size != 0 {
+        return Err(eyre!(
+            "Start block {} is not the first block of a batch of {}",
+            args.start_block,
+            args.batch_size
+        )
+        .into());
+    }
+    let chain_id = get_env_var("CHAIN_ID")?.parse::<u64>()?;
+    let end_block = args.start_block + args.batch_size - 1;
+
+    let db_connection = DbConnection::new().await?;
+    let headers = db_connection
+        .get_block_headers_by_block_range(args.start_block, end_block)
+        .await?;
+    verify_headers(chain_id, &headers)?;
+
+    let new_headers: Vec<String> = headers.iter().map(|h| h.block_hash.clone()).collect();
+    let grouped_headers = group_headers_by_hour(headers)?;
+
+    // Each batch starts its own MMR, so the fixture needs no earlier state
+    let proof_generator = ProofGenerator::new(MMR_BUILD_ELF, MMR_BUILD_ID)?;
+    let combined_input = CombinedInput::new(
+        chain_id,
+        args.batch_size,
+        proof_generator.method_id(),
+        grouped_headers,
+        MMRInput::new(vec![], 0, 0, new_headers),
+    );
+    info!(
+        method_id = %proof_generator.method_id_hex(),
+        start_block = args.start_block,
+        end_block,
+        "Proving fixture batch"
+    );
+
+    let proof = proof_generator
+        .generate_groth16_proof(combined_input)
+        .await?;
+    let output = proof_generator.decode_journal(&proof, chain_id)?;
+    std::fs::write(&args.output, render_fixture(&output, &proof.calldata()))?;
+    info!(output = %args.output, "Wrote Groth16 fixture");
+
+    Ok(())
+}
+
+/// Renders the journal and calldata of a proof as the Cairo fixture the verifier tests
+/// read: `test_journal`, `test_avg_fees` and `calldata_default`.
+pub fn render_fixture(output: &GuestOutput, calldata: &[Felt]) -> String {
+    let header = output.header();
+    let method_id: String = header
+        .method_id()
+        .iter()
+        .map(|word| format!("{:08x}", word))
+        .collect();
+
+    let mut fixture = String::new();
+    fixture.push_str("// Generated by `cargo run --bin groth16-fixture`, do not edit by hand\n");
+    fixture.push_str("use verifier::{AvgFees, Journal};\n\n");
+
+    fixture.push_str("pub fn test_journal() -> Journal {\n    Journal {\n");
+    let fields = [
+        ("version", header.version().to_string()),
+        ("method_id", format!("0x{}", method_id)),
+        ("hasher_id", header.hasher_id().to_string()),
+        ("chain_id", header.chain_id().to_string()),
+        ("batch_index", output.batch_index().to_string()),
+        ("latest_mmr_block", output.latest_mmr_block().to_string()),
+        (
+            "latest_mmr_block_hash",
+            output.latest_mmr_block_hash().to_string(),
+        ),
+        ("root_hash", output.root_hash().to_string()),
+        ("leaves_count", output.leaves_count().to_string()),
+        (
+            "first_block_parent_hash",
+            output.first_block_parent_hash().to_string(),
+        ),
+        ("fork_id", output.fork_id().to_string()),
+    ];
+    for (name, value) in fields {
+        let _ = writeln!(fixture, "        {}: {},", name, value);
+    }
+    fixture.push_str("    }\n}\n\n");
+
+    fixture.push_str("pub fn test_avg_fees() -> Array<AvgFees> {\n    array![\n");
+    for fees in output.avg_fees() {
+        let fields = [
+            ("timestamp", fees.timestamp()),
+            ("data_points", fees.data_points()),
+            ("avg_fee", fees.avg_fee()),
+            ("min_fee", fees.min_fee()),
+            ("max_fee", fees.max_fee()),
+            ("median_fee", fees.median_fee()),
+            ("weighted_avg_fee", fees.weighted_avg_fee()),
+            ("twap_fee", fees.twap_fee()),
+            ("blob_base_fee", fees.blob_base_fee()),
+        ];
+        fixture.push_str("        AvgFees {\n");
+        for (name, value) in fields {
+            let _ = writeln!(fixture, "            {}: {},", name, value);
+        }
+        fixture.push_str("        },\n");
+    }
+    fixture.push_str("    ]\n}\n\n");
+
+    fixture.push_str("pub fn calldata_default() -> Span<felt252> {\n    array![\n");
+    for felt in calldata {
+        let _ = writeln!(fixture, "        {},", felt.to_hex_string());
+    }
+    fixture.push_str("    ]\n        .span()\n}\n");
+
+    fixture
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use guest_types::{journal::JournalHeader, AvgFees};
+
+    #[test]
+    fn test_args() {
+        let args = Args::parse_from(["groth16_fixture", "--start-block", "2048"]);
+        assert_eq!(args.start_block, 2048);
+        assert_eq!(args.batch_size, 1024);
+        assert_eq!(
+            args.output,
+            "contracts/starknet/verifier/tests/groth16_fixture.cairo"
+        );
+    }
+
+    #[test]
+    fn test_render_fixture() {
+        let output = GuestOutput::new(
+            JournalHeader::new([0x11111111, 2, 3, 4, 5, 6, 7, 0x88888888], 11155111),
+            2,
+            3071,
+            "0x930046a42d2e9ae094e48890903f998d6edf12265aad7f5f620be4507e961d48".to_string(),
+            "0x930aa189e5be10188debe34e6df8930d0bce5ee7c61ff26222e5a33e2ce421fb".to_string(),
+            1024,
+            "0x85350e4a9d2bd94d4b2bb6b9e2a09a3e8f6b85c7e39b04de6d8f5eb4fa32d0c3".to_string(),
+            vec![AvgFees::new(1739984400, 300, 7, 1, 9, 6, 8, 7, 1)],
+            3,
+        );
+        let fixture = render_fixture(&output, &[Felt::from(2u64), Felt::from(0x9f1u64)]);
+
+        assert!(fixture.contains(
+            "        method_id: 0x1111111100000002000000030000000400000005000000060000000788888888,\n"
+        ));
+        assert!(fixture.contains("        chain_id: 11155111,\n"));
+        assert!(fixture.contains(
+            "        root_hash: 0x930aa189e5be10188debe34e6df8930d0bce5ee7c61ff26222e5a33e2ce421fb,\n"
+        ));
+        assert!(fixture.contains("            timestamp: 1739984400,\n"));
+        assert!(fixture.contains("            blob_base_fee: 1,\n"));
+        assert!(fixture.ends_with("        0x2,\n        0x9f1,\n    ]\n        .span()\n}\n"));
+    }
+}
@@ -1,3 +1,5 @@
 pub mod build_mmr;
 // pub mod extract_fees;
+pub mod gc_pins;
+pub mod groth16_fixture;
 pub mod update_mmr;
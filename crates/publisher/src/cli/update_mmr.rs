@@ -1,6 +1,9 @@
 use crate::api::operations::prove_mmr_update;
 use clap::Parser;
+use common::deployment::{contract_address, Contract};
 use common::get_env_var;
+use common::signer::KeySource;
+use starknet_handler::provider::StarknetProvider;
 use tracing::info;
 
 #[derive(Parser, Debug)]
@@ -28,7 +31,7 @@ pub struct Config {
     pub rpc_url: String,
     pub verifier_address: String,
     pub store_address: String,
-    pub private_key: String,
+    pub key_source: KeySource,
     pub account_address: String,
 }
 
@@ -37,9 +40,9 @@ impl Config {
         Ok(Self {
             chain_id: get_env_var("CHAIN_ID")?.parse()?,
             rpc_url: get_env_var("STARKNET_RPC_URL")?,
-            verifier_address: get_env_var("FOSSIL_VERIFIER")?,
-            store_address: get_env_var("FOSSIL_STORE")?,
-            private_key: get_env_var("STARKNET_PRIVATE_KEY")?,
+            verifier_address: contract_address(Contract::FossilVerifier)?,
+            store_address: contract_address(Contract::Store)?,
+            key_source: KeySource::from_env("STARKNET")?,
             account_address: get_env_var("STARKNET_ACCOUNT_ADDRESS")?,
         })
     }
@@ -48,12 +51,13 @@ impl Config {
 pub async fn run(config: Config, args: Args) -> Result<(), Box<dyn std::error::Error>> {
     info!("Starting Publisher...");
 
+    let starknet_provider = StarknetProvider::new(&config.rpc_url)?;
     prove_mmr_update(
-        &config.rpc_url,
+        &starknet_provider,
         config.chain_id,
         &config.verifier_address,
         &config.store_address,
-        &config.private_key,
+        &config.key_source,
         &config.account_address,
         args.batch_size,
         args.start,
@@ -90,7 +94,7 @@ mod tests {
                 rpc_url: env::var("STARKNET_RPC_URL")?,
                 verifier_address: env::var("FOSSIL_VERIFIER")?,
                 store_address: env::var("FOSSIL_STORE")?,
-                private_key: env::var("STARKNET_PRIVATE_KEY")?,
+                key_source: KeySource::from_vars("STARKNET", |name| env::var(name).ok())?,
                 account_address: env::var("STARKNET_ACCOUNT_ADDRESS")?,
             })
         }
@@ -145,7 +149,7 @@ mod tests {
         assert_eq!(config.rpc_url, "http://test.url");
         assert_eq!(config.verifier_address, "verifier_addr");
         assert_eq!(config.store_address, "store_addr");
-        assert_eq!(config.private_key, "private_key");
+        assert!(matches!(config.key_source, KeySource::Local(key) if key == "private_key"));
         assert_eq!(config.account_address, "account_addr");
     }
 
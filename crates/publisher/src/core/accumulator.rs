@@ -2,23 +2,25 @@ use crate::utils::BatchResult;
 use ethereum::get_finalized_block_hash;
 use eyre::{eyre, Result};
 use starknet_crypto::Felt;
-use starknet_handler::provider::StarknetProvider;
+use starknet_handler::account::ProofSubmission;
+use starknet_handler::cid::Cid;
+use starknet_handler::tx::TxOutcome;
 use tracing::{debug, error, info, warn};
 
 use super::BatchProcessor;
 
 pub struct AccumulatorBuilder<'a> {
-    starknet_rpc_url: &'a String,
     chain_id: u64,
     verifier_address: &'a String,
     batch_processor: BatchProcessor<'a>,
     current_batch: u64,
     total_batches: u64,
+    /// Build proofs waiting to be verified together
+    pending_proofs: Vec<ProofSubmission>,
 }
 
 impl<'a> AccumulatorBuilder<'a> {
     pub async fn new(
-        starknet_rpc_url: &'a String,
         chain_id: u64,
         verifier_address: &'a String,
         batch_processor: BatchProcessor<'a>,
@@ -33,12 +35,12 @@ impl<'a> AccumulatorBuilder<'a> {
         }
 
         Ok(Self {
-            starknet_rpc_url,
             chain_id,
             verifier_address,
             batch_processor,
             current_batch,
             total_batches,
+            pending_proofs: Vec::new(),
         })
     }
 
@@ -79,8 +81,10 @@ impl<'a> AccumulatorBuilder<'a> {
             let result = self
                 .batch_processor
                 .process_batch(self.chain_id, start_block, current_end)
-                .await
-                .map_err(|e| {
+                .await;
+            let result = match result {
+                Ok(result) => result,
+                Err(e) => {
                     error!(
                         error = %e,
                         batch_num,
@@ -88,8 +92,9 @@ impl<'a> AccumulatorBuilder<'a> {
                         current_end,
                         "Failed to process batch"
                     );
-                    e
-                })?;
+                    return Err(self.abort(e).await);
+                }
+            };
 
             if let Some(batch_result) = result {
                 self.handle_batch_result(&batch_result, true).await?;
@@ -103,6 +108,7 @@ impl<'a> AccumulatorBuilder<'a> {
             current_end = start_block.saturating_sub(1);
         }
 
+        self.flush_proofs().await?;
         info!("MMR build completed successfully");
         Ok(())
     }
@@ -119,10 +125,14 @@ impl<'a> AccumulatorBuilder<'a> {
 
         while current_end > 0 {
             let start_block = self.batch_processor.calculate_start_block(current_end)?;
-            let batch_result = self
+            let batch_result = match self
                 .batch_processor
                 .process_batch(self.chain_id, start_block, current_end)
-                .await?;
+                .await
+            {
+                Ok(batch_result) => batch_result,
+                Err(e) => return Err(self.abort(e).await),
+            };
 
             if let Some(result) = batch_result {
                 self.handle_batch_result(&result, true).await?;
@@ -131,6 +141,7 @@ impl<'a> AccumulatorBuilder<'a> {
             current_end = start_block.saturating_sub(1);
         }
 
+        self.flush_proofs().await?;
         Ok(())
     }
 
@@ -170,6 +181,7 @@ impl<'a> AccumulatorBuilder<'a> {
 
         // Always handle the batch result with the is_build flag
         self.handle_batch_result(&batch_result, is_build).await?;
+        self.flush_proofs().await?;
 
         self.current_batch += 1;
         info!(
@@ -180,30 +192,52 @@ impl<'a> AccumulatorBuilder<'a> {
         Ok(())
     }
 
-    async fn handle_batch_result(&self, batch_result: &BatchResult, is_build: bool) -> Result<()> {
-        // Always attempt verification if proof is available
-        if let Some(proof) = batch_result.proof() {
-            self.verify_proof(proof.calldata(), batch_result.ipfs_hash(), is_build)
-                .await?;
-        } else {
+    async fn handle_batch_result(
+        &mut self,
+        batch_result: &BatchResult,
+        is_build: bool,
+    ) -> Result<()> {
+        let Some(proof) = batch_result.proof() else {
             return Err(eyre!(
                 "No proof available for verification for batch: {:?}",
                 batch_result
             ));
+        };
+
+        if !is_build {
+            return self
+                .verify_proof(proof.calldata(), batch_result.ipfs_hash(), is_build)
+                .await;
         }
-        Ok(())
+
+        // Builds go from newer to older batches, and the verifier checks each build
+        // batch against the link its newer neighbour stored on-chain
+        // (`get_batch_last_block_link`). Queued proofs therefore have to reach the
+        // verifier in the order they were proven; multicalls run their calls in
+        // order, so batching them into fewer transactions keeps that order.
+        let submission = ProofSubmission {
+            proof: proof.calldata(),
+            ipfs_hash: batch_result.ipfs_hash(),
+        };
+        let max_calldata = self
+            .batch_processor
+            .mmr_state_manager()
+            .account()
+            .max_calldata();
+        let ready = queue_proof(&mut self.pending_proofs, submission, max_calldata);
+        self.verify_queued(ready).await
     }
 
     async fn verify_proof(
         &self,
         calldata: Vec<Felt>,
-        ipfs_hash: String,
+        ipfs_hash: Cid,
         is_build: bool,
     ) -> Result<()> {
         let starknet_account = self.batch_processor.mmr_state_manager().account();
 
         info!("Verifying MMR proof (is_build: {})", is_build);
-        starknet_account
+        let outcome = starknet_account
             .verify_mmr_proof(&self.verifier_address, calldata, ipfs_hash, is_build)
             .await
             .map_err(|e| {
@@ -211,6 +245,58 @@ impl<'a> AccumulatorBuilder<'a> {
                 e
             })?;
 
+        self.check_outcome(outcome)
+    }
+
+    /// Verifies the queued build proofs, in as few transactions as they fit in
+    async fn flush_proofs(&mut self) -> Result<()> {
+        let proofs = std::mem::take(&mut self.pending_proofs);
+        self.verify_queued(proofs).await
+    }
+
+    /// Verifies build proofs taken off the queue, in order
+    async fn verify_queued(&self, proofs: Vec<ProofSubmission>) -> Result<()> {
+        if proofs.is_empty() {
+            return Ok(());
+        }
+
+        info!(proofs = proofs.len(), "Verifying queued MMR proofs");
+        let outcomes = self
+            .batch_processor
+            .mmr_state_manager()
+            .account()
+            .verify_mmr_proofs(&self.verifier_address, &proofs, true)
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to verify MMR proofs");
+                e
+            })?;
+
+        for outcome in outcomes {
+            self.check_outcome(outcome)?;
+        }
+        Ok(())
+    }
+
+    /// Verifies queued proofs before `error` is returned, so that proving work
+    /// already done isn't lost
+    async fn abort(&mut self, error: eyre::Report) -> eyre::Report {
+        if let Err(e) = self.flush_proofs().await {
+            error!(error = %e, "Failed to verify queued MMR proofs");
+        }
+        error
+    }
+
+    // The fee is logged by the account when the receipt arrives, once per
+    // transaction however many proofs it verified
+    fn check_outcome(&self, outcome: TxOutcome) -> Result<()> {
+        // A reverted verification leaves the on-chain state untouched, so the next
+        // batch must not be built on top of it
+        outcome.into_result().map_err(|e| {
+            error!(error = %e, "MMR proof verification reverted");
+            e
+        })?;
+
         Ok(())
     }
 
@@ -253,10 +339,14 @@ impl<'a> AccumulatorBuilder<'a> {
 
         while current_end > 0 {
             let start = self.batch_processor.calculate_start_block(current_end)?;
-            let batch_result = self
+            let batch_result = match self
                 .batch_processor
                 .process_batch(self.chain_id, start, current_end)
-                .await?;
+                .await
+            {
+                Ok(batch_result) => batch_result,
+                Err(e) => return Err(self.abort(e).await),
+            };
 
             if let Some(result) = batch_result {
                 self.handle_batch_result(&result, is_build).await?;
@@ -265,6 +355,7 @@ impl<'a> AccumulatorBuilder<'a> {
             current_end = start.saturating_sub(1);
         }
 
+        self.flush_proofs().await?;
         Ok(())
     }
 
@@ -309,8 +400,10 @@ impl<'a> AccumulatorBuilder<'a> {
             let result = self
                 .batch_processor
                 .process_batch(self.chain_id, start, current_end)
-                .await
-                .map_err(|e| {
+                .await;
+            let result = match result {
+                Ok(result) => result,
+                Err(e) => {
                     error!(
                         error = %e,
                         batch_num,
@@ -318,8 +411,9 @@ impl<'a> AccumulatorBuilder<'a> {
                         current_end,
                         "Failed to process batch"
                     );
-                    e
-                })?;
+                    return Err(self.abort(e).await);
+                }
+            };
 
             if let Some(batch_result) = result {
                 self.handle_batch_result(&batch_result, is_build).await?;
@@ -333,16 +427,16 @@ impl<'a> AccumulatorBuilder<'a> {
             current_end = start.saturating_sub(1);
         }
 
+        self.flush_proofs().await?;
         info!("MMR accumulation completed successfully");
         Ok(())
     }
 
     pub async fn build_from_latest(&mut self, is_build: bool) -> Result<()> {
-        let provider = StarknetProvider::new(&self.starknet_rpc_url)
-            .map_err(|e| eyre!("Failed to create Starknet provider: {}", e))?;
-
-        let latest_mmr_block = provider
-            .get_min_mmr_block(&self.batch_processor.mmr_state_manager().store_address())
+        let mmr_state_manager = self.batch_processor.mmr_state_manager();
+        let latest_mmr_block = mmr_state_manager
+            .provider()
+            .get_min_mmr_block(mmr_state_manager.store_address(), None)
             .await
             .map_err(|e| eyre!("Failed to get latest MMR block: {}", e))?;
 
@@ -359,11 +453,10 @@ impl<'a> AccumulatorBuilder<'a> {
         num_batches: u64,
         is_build: bool,
     ) -> Result<()> {
-        let provider = StarknetProvider::new(&self.starknet_rpc_url)
-            .map_err(|e| eyre!("Failed to create Starknet provider: {}", e))?;
-
-        let min_mmr_block = provider
-            .get_min_mmr_block(&self.batch_processor.mmr_state_manager().store_address())
+        let mmr_state_manager = self.batch_processor.mmr_state_manager();
+        let min_mmr_block = mmr_state_manager
+            .provider()
+            .get_min_mmr_block(mmr_state_manager.store_address(), None)
             .await
             .map_err(|e| eyre!("Failed to get minimum MMR block: {}", e))?;
 
@@ -382,6 +475,26 @@ impl<'a> AccumulatorBuilder<'a> {
     }
 }
 
+/// Queues `submission` behind the `pending` build proofs. If it would not fit in a
+/// multicall with them, the proofs queued so far are returned to be verified first.
+fn queue_proof(
+    pending: &mut Vec<ProofSubmission>,
+    submission: ProofSubmission,
+    max_calldata: usize,
+) -> Vec<ProofSubmission> {
+    let queued_len = 1 + pending
+        .iter()
+        .map(ProofSubmission::multicall_len)
+        .sum::<usize>();
+    let ready = if !pending.is_empty() && queued_len + submission.multicall_len() > max_calldata {
+        std::mem::take(pending)
+    } else {
+        Vec::new()
+    };
+    pending.push(submission);
+    ready
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core::{MMRStateManager, ProofGenerator};
@@ -389,12 +502,9 @@ mod tests {
     use super::*;
     use mockall::mock;
     use mockall::predicate::*;
-    use starknet::providers::jsonrpc::HttpTransport;
-    use starknet::providers::JsonRpcClient;
-    use starknet::providers::Url;
     use starknet_handler::account::StarknetAccount;
+    use starknet_handler::provider::StarknetProvider;
     use std::env;
-    use std::sync::Arc;
 
     // Setup test environment variables
     fn setup_test_env() {
@@ -406,7 +516,8 @@ mod tests {
     mock! {
         #[derive(Clone)]
         pub StarknetAccount {
-            fn verify_mmr_proof(&self, verifier_address: &str, calldata: Vec<Felt>, ipfs_hash: String, is_build: bool) -> Result<()>;
+            fn verify_mmr_proof(&self, verifier_address: &str, calldata: Vec<Felt>, ipfs_hash: Cid, is_build: bool) -> Result<TxOutcome>;
+            fn verify_mmr_proofs(&self, verifier_address: &str, proofs: &[ProofSubmission], is_build: bool) -> Result<Vec<TxOutcome>>;
         }
     }
 
@@ -415,8 +526,9 @@ mod tests {
         #[allow(clippy::unused_self)]
         fn from(_mock: MockStarknetAccount) -> Self {
             // Create a new StarknetAccount for testing
-            let transport = HttpTransport::new(Url::parse("http://localhost:8545").unwrap());
-            let provider = Arc::new(JsonRpcClient::new(transport));
+            let provider = StarknetProvider::new("http://localhost:8545")
+                .unwrap()
+                .provider();
 
             StarknetAccount::new(provider, "0x123", "0x456").unwrap()
         }
@@ -428,19 +540,18 @@ mod tests {
 
         let account = MockStarknetAccount::new();
         // Create longer-lived String values
-        let rpc_url = "http://localhost:8545".to_string();
+        let provider = StarknetProvider::new("http://localhost:8545").unwrap();
         let verifier_addr = "0x123".to_string();
         let store_addr = "0x456".to_string();
 
         let batch_processor = BatchProcessor::new(
             100,
             ProofGenerator::mock_for_tests(),
-            MMRStateManager::new(account.into(), &store_addr, &rpc_url),
+            MMRStateManager::new(account.into(), &store_addr, provider),
         )
         .unwrap();
 
-        let result =
-            AccumulatorBuilder::new(&rpc_url, 1, &verifier_addr, batch_processor, 0, 0).await;
+        let result = AccumulatorBuilder::new(1, &verifier_addr, batch_processor, 0, 0).await;
 
         assert!(result.is_ok());
         let builder = result.unwrap();
@@ -454,19 +565,19 @@ mod tests {
         setup_test_env();
 
         let account = MockStarknetAccount::new();
-        let rpc_url = "http://localhost:8545".to_string();
+        let provider = StarknetProvider::new("http://localhost:8545").unwrap();
         let store_addr = "0x456".to_string();
 
         let batch_processor = BatchProcessor::new(
             100,
             ProofGenerator::mock_for_tests(),
-            MMRStateManager::new(account.into(), &store_addr, &rpc_url),
+            MMRStateManager::new(account.into(), &store_addr, provider),
         )
         .unwrap();
 
         // Test empty verifier address
         let binding = "".to_string();
-        let result = AccumulatorBuilder::new(&rpc_url, 1, &binding, batch_processor, 0, 0).await;
+        let result = AccumulatorBuilder::new(1, &binding, batch_processor, 0, 0).await;
         assert!(
             matches!(result, Err(e) if e.to_string().contains("Verifier address cannot be empty"))
         );
@@ -477,21 +588,20 @@ mod tests {
         setup_test_env();
 
         let account = MockStarknetAccount::new();
-        let rpc_url = "http://localhost:8545".to_string();
+        let provider = StarknetProvider::new("http://localhost:8545").unwrap();
         let verifier_addr = "0x123".to_string();
         let store_addr = "0x456".to_string();
 
         let batch_processor = BatchProcessor::new(
             100,
             ProofGenerator::mock_for_tests(),
-            MMRStateManager::new(account.into(), &store_addr, &rpc_url),
+            MMRStateManager::new(account.into(), &store_addr, provider),
         )
         .unwrap();
 
-        let mut builder =
-            AccumulatorBuilder::new(&rpc_url, 1, &verifier_addr, batch_processor, 0, 0)
-                .await
-                .unwrap();
+        let mut builder = AccumulatorBuilder::new(1, &verifier_addr, batch_processor, 0, 0)
+            .await
+            .unwrap();
 
         let result = builder.build_with_num_batches(0).await;
         assert!(
@@ -504,25 +614,57 @@ mod tests {
         setup_test_env();
 
         let account = MockStarknetAccount::new();
-        let rpc_url = "http://localhost:8545".to_string();
+        let provider = StarknetProvider::new("http://localhost:8545").unwrap();
         let verifier_addr = "0x123".to_string();
         let store_addr = "0x456".to_string();
 
         let batch_processor = BatchProcessor::new(
             100,
             ProofGenerator::mock_for_tests(),
-            MMRStateManager::new(account.into(), &store_addr, &rpc_url),
+            MMRStateManager::new(account.into(), &store_addr, provider),
         )
         .unwrap();
 
-        let mut builder =
-            AccumulatorBuilder::new(&rpc_url, 1, &verifier_addr, batch_processor, 0, 0)
-                .await
-                .unwrap();
+        let mut builder = AccumulatorBuilder::new(1, &verifier_addr, batch_processor, 0, 0)
+            .await
+            .unwrap();
 
         let result = builder.update_mmr_with_new_headers(100, 50, false).await;
         assert!(
             matches!(result, Err(e) if e.to_string().contains("End block cannot be less than start block"))
         );
     }
+
+    #[test]
+    fn test_queue_proof_keeps_order() {
+        let ipfs_hash: Cid = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+            .parse()
+            .unwrap();
+        // 19 felts each in a multicall, so two fit within 50
+        let submission = |n: u64| ProofSubmission {
+            proof: vec![Felt::from(n); 10],
+            ipfs_hash: ipfs_hash.clone(),
+        };
+
+        let mut pending = Vec::new();
+        let mut verified = Vec::new();
+        for n in 0..7 {
+            let ready = queue_proof(&mut pending, submission(n), 50);
+            if !ready.is_empty() {
+                verified.push(ready);
+            }
+        }
+        // What the final flush verifies
+        verified.push(std::mem::take(&mut pending));
+
+        let order: Vec<Vec<Felt>> = verified
+            .iter()
+            .map(|group| group.iter().map(|p| p.proof[0]).collect())
+            .collect();
+        let felts = |ns: &[u64]| ns.iter().map(|n| Felt::from(*n)).collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            vec![felts(&[0, 1]), felts(&[2, 3]), felts(&[4, 5]), felts(&[6])]
+        );
+    }
 }
@@ -1,24 +1,34 @@
 use crate::core::{MMRStateManager, ProofGenerator};
 use crate::db::DbConnection;
 use crate::utils::BatchResult;
-use common::get_or_create_db_path;
-use eth_rlp_types::BlockHeader;
 use eyre::{eyre, Result};
-use guest_types::{CombinedInput, GuestOutput, MMRInput};
-use ipfs_utils::IpfsManager;
-use mmr::PeaksOptions;
-use mmr_utils::initialize_mmr;
-use starknet_handler::provider::StarknetProvider;
-use starknet_handler::u256_from_hex;
-use std::path::PathBuf;
+use guest_types::{
+    fees::{group_headers_by_hour, predict_avg_fees},
+    header::{verify_headers, Header},
+    CombinedInput, MMRInput,
+};
+use ipfs_utils::{batch_store_from_env, BatchStore, PinScope};
+use mmr::{PeaksOptions, MMR};
+use mmr_utils::{
+    cache::MmrCache,
+    initialize_mmr,
+    schema::UnsupportedSchemaVersion,
+    snapshot::{restore_batch_db, BatchSnapshot, UnsupportedSnapshotVersion},
+    StoreManager,
+};
+use sqlx::SqlitePool;
+use starknet::core::types::U256;
+use starknet_handler::{cid::Cid, u256_from_hex, u256_to_hex, MmrSnapshot};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::{debug, error, info, warn};
-use uuid;
 
 pub struct BatchProcessor<'a> {
     batch_size: u64,
     proof_generator: ProofGenerator<CombinedInput>,
     mmr_state_manager: MMRStateManager<'a>,
-    ipfs_manager: IpfsManager,
+    batch_store: Arc<dyn BatchStore>,
+    mmr_cache: MmrCache,
 }
 
 impl<'a> BatchProcessor<'a> {
@@ -26,24 +36,43 @@ impl<'a> BatchProcessor<'a> {
         batch_size: u64,
         proof_generator: ProofGenerator<CombinedInput>,
         mmr_state_manager: MMRStateManager<'a>,
+    ) -> Result<Self> {
+        let batch_store = batch_store_from_env().map_err(|e| {
+            error!(error = %e, "Failed to create batch store");
+            eyre!("Failed to create batch store: {}", e)
+        })?;
+
+        Self::with_batch_store(batch_size, proof_generator, mmr_state_manager, batch_store)
+    }
+
+    /// Like [`Self::new`], but stores batch databases in `batch_store` instead of the
+    /// backend selected by `BATCH_STORE_BACKEND`.
+    pub fn with_batch_store(
+        batch_size: u64,
+        proof_generator: ProofGenerator<CombinedInput>,
+        mmr_state_manager: MMRStateManager<'a>,
+        batch_store: Arc<dyn BatchStore>,
     ) -> Result<Self> {
         if batch_size == 0 {
             return Err(eyre!("Batch size must be greater than 0: {}", batch_size));
         }
 
-        let ipfs_manager = IpfsManager::with_endpoint().map_err(|e| {
-            error!(error = %e, "Failed to create IPFS manager");
-            eyre!("Failed to create IPFS manager: {}", e)
-        })?;
-
         Ok(Self {
             batch_size,
             proof_generator,
             mmr_state_manager,
-            ipfs_manager,
+            batch_store,
+            mmr_cache: MmrCache::from_env()?,
         })
     }
 
+    /// Keeps batch databases in `mmr_cache` instead of the cache configured by
+    /// `MMR_CACHE_DIR`
+    pub fn with_mmr_cache(mut self, mmr_cache: MmrCache) -> Self {
+        self.mmr_cache = mmr_cache;
+        self
+    }
+
     pub fn mmr_state_manager(&self) -> &MMRStateManager<'a> {
         &self.mmr_state_manager
     }
@@ -83,98 +112,43 @@ impl<'a> BatchProcessor<'a> {
 
         let adjusted_end_block = std::cmp::min(end_block, batch_end);
 
-        // Check if batch state exists on-chain
-        let provider = StarknetProvider::new(&self.mmr_state_manager.rpc_url())?;
-        let mmr_state = provider
+        // Read the batch state and the relayed block at the same L2 block, so a proof
+        // landing in between can't pair a stale state with a newer relayed block
+        let snapshot = self.mmr_state_manager.provider().snapshot().await?;
+        let mmr_state = snapshot
             .get_mmr_state(self.mmr_state_manager.store_address(), batch_index)
             .await?;
 
         // Extract IPFS hash from MMR state
-        let ipfs_hash = mmr_state.ipfs_hash();
-        let ipfs_hash_str = String::try_from(ipfs_hash.clone())
-            .map_err(|_| eyre!("Failed to convert IPFS hash: {:?}", ipfs_hash))?;
-        // Create path for the batch database with a unique identifier
-        let batch_file_name = format!("batch_{}_{}.db", batch_index, uuid::Uuid::new_v4());
-        let db_file_path = PathBuf::from(get_or_create_db_path(&batch_file_name).map_err(|e| {
-            error!(error = %e, "Failed to get or create DB path");
-            e
-        })?);
+        let ipfs_hash = mmr_state.ipfs_hash()?;
+        let (store_manager, mut mmr, pool) = self
+            .load_batch_db(batch_index, &mmr_state, ipfs_hash.as_ref())
+            .await?;
 
-        // Use defer_cleanup to ensure file is removed at the end of function
-        let _cleanup_guard = defer_cleanup(db_file_path.clone());
+        // A batch that matches the on-chain root and is full needs no update
+        let leaves_count = mmr.leaves_count.get().await?;
+        if let Some(ipfs_hash) = ipfs_hash.filter(|_| leaves_count as u64 >= self.batch_size) {
+            debug!("Batch {} is already complete", batch_index);
+
+            // Create BatchResult and return early
+            let mmr_state_for_result = starknet_handler::MmrState::new(
+                mmr_state.latest_mmr_block(),
+                mmr_state.latest_mmr_block_hash(),
+                mmr_state.root_hash(),
+                mmr_state.leaves_count(),
+                Some(ipfs_hash.clone()),
+            );
 
-        // Initialize variables for MMR state
-        let (store_manager, mut mmr, pool) = if !ipfs_hash_str.is_empty() {
-            // Try to fetch from IPFS and initialize
-            match self
-                .ipfs_manager
-                .fetch_db(&ipfs_hash_str, &db_file_path)
-                .await
-            {
-                Ok(_) => {
-                    match initialize_mmr(db_file_path.to_str().unwrap()).await {
-                        Ok((sm, m, p)) => {
-                            // Validate MMR root matches on-chain state
-                            let mmr_elements_count = m.elements_count.get().await?;
-                            let bag = m.bag_the_peaks(Some(mmr_elements_count)).await?;
-                            let mmr_root_hex = m.calculate_root_hash(&bag, mmr_elements_count)?;
-                            let mmr_root = u256_from_hex(&mmr_root_hex)?;
-
-                            if mmr_root == mmr_state.root_hash() {
-                                // Check if batch is already complete
-                                let leaves_count = m.leaves_count.get().await?;
-
-                                if leaves_count as u64 >= self.batch_size {
-                                    debug!("Batch {} is already complete", batch_index);
-
-                                    // Create BatchResult and return early
-                                    let mmr_state_for_result = starknet_handler::MmrState::new(
-                                        mmr_state.latest_mmr_block(),
-                                        mmr_state.latest_mmr_block_hash(),
-                                        mmr_state.root_hash(),
-                                        mmr_state.leaves_count(),
-                                        Some(mmr_state.ipfs_hash()),
-                                    );
-
-                                    let batch_result = BatchResult::new(
-                                        start_block,
-                                        adjusted_end_block,
-                                        mmr_state_for_result,
-                                        None,
-                                        ipfs_hash_str.clone(),
-                                    );
-
-                                    return Ok(Some(batch_result));
-                                }
-
-                                info!(
-                                    "Loaded existing batch {} database with {} leaves (incomplete)",
-                                    batch_index, leaves_count
-                                );
-                                (sm, m, p)
-                            } else {
-                                warn!(
-                                    "MMR root mismatch for batch {}, creating new database",
-                                    batch_index
-                                );
-                                initialize_mmr(db_file_path.to_str().unwrap()).await?
-                            }
-                        }
-                        Err(e) => {
-                            warn!(error = %e, "Failed to initialize MMR from downloaded DB, creating new database");
-                            initialize_mmr(db_file_path.to_str().unwrap()).await?
-                        }
-                    }
-                }
-                Err(e) => {
-                    warn!(error = %e, "Failed to download DB from IPFS, creating new database");
-                    initialize_mmr(db_file_path.to_str().unwrap()).await?
-                }
-            }
-        } else {
-            debug!("Creating new database file: {}", db_file_path.display());
-            initialize_mmr(db_file_path.to_str().unwrap()).await?
-        };
+            let batch_result = BatchResult::new(
+                start_block,
+                adjusted_end_block,
+                mmr_state_for_result,
+                None,
+                ipfs_hash,
+            );
+
+            return Ok(Some(batch_result));
+        }
 
         // Fetch block headers for the requested range
         let db_connection = DbConnection::new().await.map_err(|e| {
@@ -201,8 +175,30 @@ impl<'a> BatchProcessor<'a> {
             ));
         }
 
+        // Reject headers the guest would refuse, e.g. with fields that don't match their
+        // fork, before spending proving time
+        let fork = verify_headers(chain_id, &headers).map_err(|e| {
+            error!(error = %e, "Header validation failed");
+            eyre!("Header validation failed: {}", e)
+        })?;
+        debug!("Headers validated against fork {}", fork);
+
+        // The store already trusts the block relayed from L1, so headers that disagree
+        // with it must not be proven
+        let (relayed_block, relayed_hash) = snapshot
+            .get_latest_relayed_block(self.mmr_state_manager.store_address())
+            .await?;
+        check_relayed_block(&headers, relayed_block, relayed_hash)?;
+
         let new_headers: Vec<String> = headers.iter().map(|h| h.block_hash.clone()).collect();
-        let grouped_headers = group_headers_by_hour(headers);
+        let numbered_headers = headers
+            .iter()
+            .map(|h| Ok((u64::try_from(h.number)?, h.block_hash.clone())))
+            .collect::<Result<Vec<_>>>()?;
+        let grouped_headers = group_headers_by_hour(headers).map_err(|e| {
+            error!(error = %e, "Failed to group headers by hour");
+            eyre!("Failed to group headers by hour: {}", e)
+        })?;
 
         info!(
             "Grouped {} headers into {} hourly groups",
@@ -210,6 +206,12 @@ impl<'a> BatchProcessor<'a> {
             grouped_headers.len()
         );
 
+        // Run the guest's fee aggregation up front so bad fee data fails before proving
+        let expected_avg_fees = predict_avg_fees(chain_id, &grouped_headers).map_err(|e| {
+            error!(error = %e, "Fee aggregation failed");
+            eyre!("Fee aggregation failed: {}", e)
+        })?;
+
         // Get current MMR state
         let current_peaks = mmr.get_peaks(PeaksOptions::default()).await.map_err(|e| {
             error!(error = %e, "Failed to get current peaks");
@@ -230,8 +232,13 @@ impl<'a> BatchProcessor<'a> {
             current_leaves_count,
             new_headers.clone(),
         );
-        let combined_input =
-            CombinedInput::new(chain_id, self.batch_size, grouped_headers, mmr_input);
+        let combined_input = CombinedInput::new(
+            chain_id,
+            self.batch_size,
+            self.proof_generator.method_id(),
+            grouped_headers,
+            mmr_input,
+        );
 
         // Debug the input
         debug!(
@@ -258,7 +265,7 @@ impl<'a> BatchProcessor<'a> {
                     // Decode the journal
                     match self
                         .proof_generator
-                        .decode_journal::<GuestOutput>(&generated_proof)
+                        .decode_journal(&generated_proof, chain_id)
                     {
                         Ok(output) => {
                             debug!(
@@ -266,6 +273,25 @@ impl<'a> BatchProcessor<'a> {
                                 output.root_hash(),
                                 output.leaves_count()
                             );
+                            if output.avg_fees() != expected_avg_fees.as_slice() {
+                                error!(
+                                    expected = ?expected_avg_fees,
+                                    actual = ?output.avg_fees(),
+                                    "Guest avg fees differ from host prediction"
+                                );
+                                return Err(eyre!("Guest avg fees differ from host prediction"));
+                            }
+                            for fees in output.avg_fees() {
+                                debug!(
+                                    timestamp = fees.timestamp(),
+                                    data_points = fees.data_points(),
+                                    avg_fee = fees.avg_fee(),
+                                    median_fee = fees.median_fee(),
+                                    twap_fee = fees.twap_fee(),
+                                    blob_base_fee = fees.blob_base_fee(),
+                                    "Decoded hourly fees"
+                                );
+                            }
                             (Some(output), Some(generated_proof))
                         }
                         Err(e) => {
@@ -292,7 +318,7 @@ impl<'a> BatchProcessor<'a> {
                 &pool,
                 adjusted_end_block,
                 guest_output.as_ref(),
-                &new_headers,
+                &numbered_headers,
             )
             .await
             .map_err(|e| {
@@ -300,28 +326,131 @@ impl<'a> BatchProcessor<'a> {
                 e
             })?;
 
-        // Close the database connection to ensure all writes are flushed
-        drop(pool);
+        // Upload a snapshot rather than the SQLite file, so that the same batch always
+        // produces the same bytes and CID
+        let snapshot = BatchSnapshot::export(&mmr, &pool).await?;
+        pool.close().await;
+
+        let snapshot_path = self.mmr_cache.batch_path(batch_index).with_extension("mmr");
+        let _snapshot_guard = defer_cleanup(snapshot_path.clone());
+        std::fs::write(&snapshot_path, snapshot.encode())?;
 
-        // Upload the SQLite database file to IPFS
-        let ipfs_hash = self
-            .ipfs_manager
-            .upload_db(&db_file_path)
+        let ipfs_hash: Cid = self
+            .batch_store
+            .upload_db(
+                &snapshot_path,
+                &PinScope::new(chain_id, self.mmr_state_manager.store_address()),
+            )
             .await
-            .map_err(|e| eyre!("Failed to upload to IPFS: {}", e))?;
+            .map_err(|e| eyre!("Failed to upload to {}: {}", self.batch_store.name(), e))?
+            .parse()?;
+
+        let evicted = self.mmr_cache.evict(Some(batch_index))?;
+        if !evicted.is_empty() {
+            debug!(?evicted, "Evicted batches from the local cache");
+        }
 
         let batch_result = Some(BatchResult::new(
             start_block,
             adjusted_end_block,
             new_mmr_state,
             proof,
-            ipfs_hash.to_string(),
+            ipfs_hash,
         ));
 
-        // The file will be automatically cleaned up when _cleanup_guard goes out of scope
         Ok(batch_result)
     }
 
+    /// Opens the database of `batch_index` from the local cache, falling back to the
+    /// batch store on a miss or when the cached copy does not match the on-chain root.
+    /// If neither matches, or nothing is on-chain yet, starts a new database. Older
+    /// databases are migrated on open; a batch published in a newer format is an
+    /// error.
+    async fn load_batch_db(
+        &self,
+        batch_index: u64,
+        mmr_state: &MmrSnapshot,
+        cid: Option<&Cid>,
+    ) -> Result<(StoreManager, MMR, SqlitePool)> {
+        let Some(cid) = cid else {
+            // Whatever is cached was never published
+            debug!("Creating new database for batch {}", batch_index);
+            return self.create_batch_db(batch_index).await;
+        };
+
+        if let Some(path) = self.mmr_cache.get(batch_index)? {
+            match open_if_root_matches(&path, mmr_state).await {
+                Ok(Some(db)) => {
+                    info!("Loaded batch {} from the local cache", batch_index);
+                    return Ok(db);
+                }
+                Ok(None) => warn!(
+                    "Cached batch {} does not match the on-chain root, fetching it",
+                    batch_index
+                ),
+                Err(e) => {
+                    warn!(error = %e, "Failed to open cached batch {}, fetching it", batch_index)
+                }
+            }
+            self.mmr_cache.remove(batch_index)?;
+        }
+
+        // Fetch the batch, which is either a snapshot or a legacy SQLite file, and
+        // turn it into a database
+        let db_file_path = self.mmr_cache.batch_path(batch_index);
+        let download_path = db_file_path.with_extension("download");
+        let _download_guard = defer_cleanup(download_path.clone());
+        let restored = match self
+            .batch_store
+            .fetch_db(cid.as_str(), &download_path)
+            .await
+        {
+            Ok(()) => restore_batch_db(&download_path, &db_file_path).await,
+            Err(e) => Err(e),
+        };
+
+        let opened = match restored {
+            Ok(()) => open_if_root_matches(&db_file_path, mmr_state).await,
+            Err(e) => Err(e),
+        };
+        match opened {
+            Ok(Some(db)) => {
+                info!(
+                    "Fetched batch {} from {}",
+                    batch_index,
+                    self.batch_store.name()
+                );
+                return Ok(db);
+            }
+            Ok(None) => warn!(
+                "MMR root mismatch for batch {}, creating new database",
+                batch_index
+            ),
+            Err(e) if is_newer_format(&e) => {
+                // Rebuilding would fork the published history, so leave the batch to a
+                // publisher that can read it
+                return Err(e.wrap_err(format!(
+                    "Cannot update batch {}, published by a newer publisher",
+                    batch_index
+                )));
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to restore batch {}, creating new database", batch_index)
+            }
+        }
+
+        self.create_batch_db(batch_index).await
+    }
+
+    async fn create_batch_db(&self, batch_index: u64) -> Result<(StoreManager, MMR, SqlitePool)> {
+        let path = self.mmr_cache.create(batch_index)?;
+        initialize_mmr(
+            path.to_str()
+                .ok_or_else(|| eyre!("Invalid path: {:?}", path))?,
+        )
+        .await
+    }
+
     pub fn calculate_batch_bounds(&self, batch_index: u64) -> Result<(u64, u64)> {
         let batch_start = batch_index
             .checked_mul(self.batch_size)
@@ -412,61 +541,6 @@ impl BatchRange {
     }
 }
 
-/// Groups block headers into vectors based on their timestamp hour and finds representative timestamps
-fn group_headers_by_hour(headers: Vec<BlockHeader>) -> Vec<(i64, Vec<BlockHeader>)> {
-    let mut grouped_headers: Vec<(i64, Vec<BlockHeader>)> = Vec::new();
-    let mut current_group: Vec<BlockHeader> = Vec::new();
-    let mut current_hour: Option<i64> = None;
-
-    for header in headers {
-        let timestamp = header
-            .timestamp
-            .as_ref()
-            .and_then(|ts| i64::from_str_radix(ts.trim_start_matches("0x"), 16).ok())
-            .unwrap_or_default();
-
-        let hour = timestamp / 3600;
-
-        match current_hour {
-            None => {
-                current_hour = Some(hour);
-                current_group.push(header);
-            }
-            Some(h) if h == hour => {
-                current_group.push(header);
-            }
-            Some(h) => {
-                if !current_group.is_empty() {
-                    // Find timestamp closest to the hour
-                    let representative_timestamp = h * 3600;
-                    info!(
-                        "Representative timestamp for hour {} is: {}",
-                        h, representative_timestamp
-                    );
-                    grouped_headers
-                        .push((representative_timestamp, std::mem::take(&mut current_group)));
-                }
-                current_hour = Some(hour);
-                current_group.push(header);
-            }
-        }
-    }
-
-    // Process the last group if it exists
-    if !current_group.is_empty() {
-        if let Some(h) = current_hour {
-            let representative_timestamp = h * 3600;
-            info!(
-                "Representative timestamp for hour {} is: {}",
-                h, representative_timestamp
-            );
-            grouped_headers.push((representative_timestamp, current_group));
-        }
-    }
-
-    grouped_headers
-}
-
 // Helper struct for cleanup
 struct CleanupGuard {
     path: PathBuf,
@@ -489,9 +563,70 @@ fn defer_cleanup(path: PathBuf) -> CleanupGuard {
     CleanupGuard { path }
 }
 
+/// Checks that the header of `relayed_block`, if `headers` include it, has the hash
+/// relayed from L1. A mismatch means the indexer followed a different chain, for
+/// example after a reorg or when pointed at the wrong database.
+fn check_relayed_block(headers: &[Header], relayed_block: u64, relayed_hash: U256) -> Result<()> {
+    let Some(header) = headers.iter().find(|h| h.number as u64 == relayed_block) else {
+        return Ok(());
+    };
+
+    let header_hash = u256_from_hex(&header.block_hash)?;
+    if header_hash != relayed_hash {
+        error!(
+            relayed_block,
+            header_hash = %header.block_hash,
+            relayed_hash = %u256_to_hex(relayed_hash),
+            "Header does not match the block relayed from L1"
+        );
+        return Err(eyre!(
+            "Header of block {} has hash {}, but the block relayed from L1 has hash {}; \
+             the indexer may have followed a reorg or be reading the wrong database",
+            relayed_block,
+            header.block_hash,
+            u256_to_hex(relayed_hash)
+        ));
+    }
+    debug!(relayed_block, "Headers match the block relayed from L1");
+    Ok(())
+}
+
+/// Whether `e` means the batch was published by a newer publisher, in a format this
+/// build cannot read. Older formats are rebuilt like any other unreadable batch.
+fn is_newer_format(e: &eyre::Report) -> bool {
+    if let Some(version) = e.downcast_ref::<UnsupportedSchemaVersion>() {
+        return version.is_newer();
+    }
+    e.downcast_ref::<UnsupportedSnapshotVersion>()
+        .is_some_and(|version| version.is_newer())
+}
+
+/// Opens the batch database at `path` if its root is the one on-chain
+async fn open_if_root_matches(
+    path: &Path,
+    mmr_state: &MmrSnapshot,
+) -> Result<Option<(StoreManager, MMR, SqlitePool)>> {
+    let path = path
+        .to_str()
+        .ok_or_else(|| eyre!("Invalid path: {:?}", path))?;
+    let (store_manager, mmr, pool) = initialize_mmr(path).await?;
+
+    let elements_count = mmr.elements_count.get().await?;
+    let bag = mmr.bag_the_peaks(Some(elements_count)).await?;
+    let root = u256_from_hex(&mmr.calculate_root_hash(&bag, elements_count)?)?;
+
+    if root == mmr_state.root_hash() {
+        Ok(Some((store_manager, mmr, pool)))
+    } else {
+        pool.close().await;
+        Ok(None)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use eth_rlp_types::BlockHeader;
     use mockall::mock;
     use std::env;
 
@@ -597,6 +732,71 @@ mod tests {
         );
     }
 
+    fn header(number: i64, block_hash: &str) -> Header {
+        BlockHeader {
+            block_hash: block_hash.to_string(),
+            number,
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            nonce: "0x0".to_string(),
+            transaction_root: None,
+            receipts_root: None,
+            state_root: None,
+            base_fee_per_gas: None,
+            parent_hash: None,
+            ommers_hash: None,
+            miner: None,
+            logs_bloom: None,
+            difficulty: None,
+            totaldifficulty: None,
+            sha3_uncles: None,
+            timestamp: None,
+            extra_data: None,
+            mix_hash: None,
+            withdrawals_root: None,
+            blob_gas_used: None,
+            excess_blob_gas: None,
+            parent_beacon_block_root: None,
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_check_relayed_block() {
+        let hash_a = format!("0x{:064x}", 0xa);
+        let hash_b = format!("0x{:064x}", 0xb);
+        let headers = vec![header(10, &hash_a), header(11, &hash_b)];
+
+        check_relayed_block(&headers, 11, U256::from(0xbu64)).unwrap();
+        // The relayed block is outside the range, so there is nothing to compare
+        check_relayed_block(&headers, 12, U256::from(0xcu64)).unwrap();
+
+        let err = check_relayed_block(&headers, 10, U256::from(0xbu64)).unwrap_err();
+        assert!(err.to_string().contains("Header of block 10 has hash"));
+    }
+
+    #[test]
+    fn test_is_newer_format() {
+        let schema = eyre::Report::new(UnsupportedSchemaVersion {
+            found: 9,
+            supported: 2,
+        });
+        let snapshot = eyre::Report::new(UnsupportedSnapshotVersion {
+            found: 9,
+            supported: 2,
+        });
+        assert!(is_newer_format(&schema));
+        assert!(is_newer_format(&snapshot.wrap_err("Failed to restore")));
+        assert!(!is_newer_format(&eyre!("Truncated batch snapshot")));
+
+        let older = eyre::Report::new(UnsupportedSnapshotVersion {
+            found: 1,
+            supported: 2,
+        });
+        assert!(!is_newer_format(&older));
+        assert!(!is_newer_format(&older.wrap_err("Failed to restore")));
+    }
+
     #[tokio::test]
     async fn test_mock_traits() {
         let _mock_provider = MockStarknetProvider::new();
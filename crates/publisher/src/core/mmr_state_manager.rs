@@ -3,21 +3,38 @@ use eyre::{eyre, Result};
 use guest_types::GuestOutput;
 use mmr::MMR;
 use mmr_utils::StoreManager;
-use starknet_handler::{account::StarknetAccount, u256_from_hex, MmrState};
+use starknet_handler::{
+    account::StarknetAccount, provider::StarknetProvider, u256_from_hex, MmrState,
+};
+use std::sync::Arc;
 use store::SqlitePool;
 use tracing::{debug, error, info};
 pub struct MMRStateManager<'a> {
-    account: StarknetAccount,
+    account: Arc<StarknetAccount>,
     store_address: &'a str,
-    rpc_url: &'a str,
+    provider: StarknetProvider,
 }
 
 impl<'a> MMRStateManager<'a> {
-    pub fn new(account: StarknetAccount, store_address: &'a str, rpc_url: &'a str) -> Self {
+    pub fn new(
+        account: StarknetAccount,
+        store_address: &'a str,
+        provider: StarknetProvider,
+    ) -> Self {
+        Self::from_shared(Arc::new(account), store_address, provider)
+    }
+
+    /// Like [`Self::new`], for an account also used elsewhere, so that everything it
+    /// sends draws from the same nonces
+    pub fn from_shared(
+        account: Arc<StarknetAccount>,
+        store_address: &'a str,
+        provider: StarknetProvider,
+    ) -> Self {
         Self {
             account,
             store_address,
-            rpc_url,
+            provider,
         }
     }
 
@@ -29,8 +46,9 @@ impl<'a> MMRStateManager<'a> {
         self.store_address
     }
 
-    pub fn rpc_url(&self) -> &'a str {
-        self.rpc_url
+    /// The provider shared by everything reading the store
+    pub fn provider(&self) -> &StarknetProvider {
+        &self.provider
     }
 
     pub async fn update_state(
@@ -40,7 +58,7 @@ impl<'a> MMRStateManager<'a> {
         pool: &SqlitePool,
         latest_block_number: u64,
         guest_output: Option<&GuestOutput>,
-        headers: &Vec<String>,
+        headers: &[(u64, String)],
     ) -> Result<MmrState> {
         if headers.is_empty() {
             return Err(eyre!("Headers list cannot be empty: {:?}", headers));
@@ -49,7 +67,7 @@ impl<'a> MMRStateManager<'a> {
         info!("Updating MMR state with {} headers...", headers.len());
         debug!("Headers: {:?}", headers);
 
-        Self::append_headers(store_manager, mmr, pool, headers)
+        Self::append_headers(store_manager, mmr, pool, latest_block_number, headers)
             .await
             .map_err(|e| {
                 error!(error = %e, "Failed to append headers");
@@ -104,7 +122,7 @@ impl<'a> MMRStateManager<'a> {
                 e
             })?;
 
-            let latest_header = headers.last().unwrap();
+            let (_, latest_header) = headers.last().unwrap();
             debug!("Latest header: {}", latest_header);
 
             let latest_mmr_block_hash = u256_from_hex(latest_header).map_err(|e| {
@@ -134,15 +152,24 @@ impl<'a> MMRStateManager<'a> {
         }
     }
 
+    /// Appends `headers`, the numbers and hashes of consecutive blocks ending at
+    /// `latest_block_number`, recording each leaf's block number in the value index.
+    /// The appends and the index rows are committed together, so a failure part way
+    /// leaves the database as it was.
     async fn append_headers(
         store_manager: StoreManager,
         mmr: &mut MMR,
         pool: &SqlitePool,
-        headers: &Vec<String>,
+        latest_block_number: u64,
+        headers: &[(u64, String)],
     ) -> Result<()> {
         debug!("Appending headers to MMR");
 
-        for hash in headers {
+        check_block_numbers(headers, latest_block_number)?;
+
+        let mut batch = store_manager.begin_batch(pool)?;
+        for (block_number, hash) in headers {
+            let block_number = *block_number;
             if hash.trim().is_empty() {
                 return Err(eyre!("Header hash cannot be empty: {:?}", hash));
             }
@@ -151,14 +178,17 @@ impl<'a> MMRStateManager<'a> {
                 error!(error = %e, "Failed to append hash to MMR");
                 e
             })?;
-            store_manager
-                .insert_value_index_mapping(&pool, &hash, append_result.element_index)
-                .await
+            batch
+                .insert_leaf(hash, append_result.element_index, Some(block_number))
                 .map_err(|e| {
                     error!(error = %e, "Failed to insert value index mapping");
                     e
                 })?;
         }
+        batch.commit().await.map_err(|e| {
+            error!(error = %e, "Failed to commit appended headers");
+            e
+        })?;
         debug!("Headers appended successfully");
         Ok(())
     }
@@ -237,66 +267,65 @@ impl<'a> MMRStateManager<'a> {
 
     #[cfg(test)]
     pub fn mock() -> Self {
-        use starknet::providers::{jsonrpc::HttpTransport, JsonRpcClient, Url};
-        use std::sync::Arc;
+        let provider = StarknetProvider::new("http://localhost:5050").expect("Invalid URL");
+        let account = StarknetAccount::new(provider.provider(), "0x0", "0x0")
+            .expect("Failed to create StarknetAccount");
 
-        let provider = Arc::new(JsonRpcClient::new(HttpTransport::new(
-            Url::parse("http://localhost:5050").expect("Invalid URL"),
-        )));
-        let account = StarknetAccount::new(
-            provider, "0x0", "0x0", // private key as &str
-        )
-        .expect("Failed to create StarknetAccount");
+        MMRStateManager::new(account, "0x0", provider)
+    }
+}
 
-        MMRStateManager::new(
-            account,
-            "0x0",                   // store_address
-            "http://localhost:5050", // rpc_url
-        )
+/// Checks that `headers` are numbered consecutively up to `latest_block_number`, so
+/// every leaf is indexed under the block it hashes
+fn check_block_numbers(headers: &[(u64, String)], latest_block_number: u64) -> Result<()> {
+    for pair in headers.windows(2) {
+        if pair[0].0.checked_add(1) != Some(pair[1].0) {
+            return Err(eyre!(
+                "Header of block {} does not follow block {}",
+                pair[1].0,
+                pair[0].0
+            ));
+        }
+    }
+
+    match headers.last() {
+        Some((number, _)) if *number != latest_block_number => Err(eyre!(
+            "Headers end at block {}, expected {}",
+            number,
+            latest_block_number
+        )),
+        _ => Ok(()),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use guest_types::AvgFees;
     use mmr_utils::StoreManager;
-    use starknet::providers::{jsonrpc::HttpTransport, JsonRpcClient, Url};
     use starknet_handler::account::StarknetAccount;
     use std::sync::Arc;
     use store::memory::InMemoryStore;
 
     // Helper function to create test dependencies
     async fn setup_test() -> (MMRStateManager<'static>, StoreManager, MMR, SqlitePool) {
+        let provider = StarknetProvider::new("http://localhost:5050").expect("Invalid URL");
         let account = StarknetAccount::new(
-            Arc::new(JsonRpcClient::new(HttpTransport::new(
-                Url::parse("http://localhost:5050").expect("Invalid URL"),
-            ))),
+            provider.provider(),
             "0x1234567890abcdef", // Valid hex address
             "0x1234567890abcdef", // Valid hex private key
         )
         .expect("Failed to create StarknetAccount");
 
         let store_address = "0x1234567890abcdef"; // Valid hex store address
-        let rpc_url = "http://localhost:5050"; // Valid rpc_url
-        let mmr_state_manager = MMRStateManager::new(account, store_address, rpc_url);
+        let mmr_state_manager = MMRStateManager::new(account, store_address, provider);
 
         let memory_store = Arc::new(InMemoryStore::new(None));
         let pool = SqlitePool::connect("sqlite::memory:")
             .await
             .expect("Failed to create in-memory SQLite database");
 
-        // Create the required table
-        sqlx::query(
-            "CREATE TABLE IF NOT EXISTS value_index_map (
-                value TEXT PRIMARY KEY,
-                element_index INTEGER NOT NULL
-            )",
-        )
-        .execute(&pool)
-        .await
-        .expect("Failed to create value_index_map table");
-
-        let store_manager = StoreManager::new("sqlite::memory:")
+        let store_manager = StoreManager::with_pool(&pool)
             .await
             .expect("Failed to create StoreManager");
 
@@ -315,8 +344,14 @@ mod tests {
         let (manager, store_manager, mut mmr, pool) = setup_test().await;
 
         let headers = vec![
-            "0x0000000000000000000000000000000000000000000000001234567890abcdef".to_string(),
-            "0x0000000000000000000000000000000000000000000000000deadbeefcafe000".to_string(),
+            (
+                99,
+                "0x0000000000000000000000000000000000000000000000001234567890abcdef".to_string(),
+            ),
+            (
+                100,
+                "0x0000000000000000000000000000000000000000000000000deadbeefcafe000".to_string(),
+            ),
         ];
 
         // MMR is already initialized by MMR::new()
@@ -344,7 +379,7 @@ mod tests {
         let (manager, store_manager, mut mmr, pool) = setup_test().await;
 
         let result = manager
-            .update_state(store_manager, &mut mmr, &pool, 100, None, &vec![])
+            .update_state(store_manager, &mut mmr, &pool, 100, None, &[])
             .await;
 
         assert!(matches!(result, Err(e) if e.to_string().contains("Headers list cannot be empty")));
@@ -354,24 +389,46 @@ mod tests {
     async fn test_append_headers_with_empty_hash() {
         let (_, store_manager, mut mmr, pool) = setup_test().await;
 
-        let headers = vec!["".to_string()];
+        let headers = vec![(100, "".to_string())];
 
         let result =
-            MMRStateManager::append_headers(store_manager, &mut mmr, &pool, &headers).await;
+            MMRStateManager::append_headers(store_manager, &mut mmr, &pool, 100, &headers).await;
 
         assert!(matches!(result, Err(e) if e.to_string().contains("Header hash cannot be empty")));
     }
 
+    #[test]
+    fn test_check_block_numbers() {
+        let header = |number: u64| (number, format!("0x{:064x}", number));
+
+        assert!(check_block_numbers(&[header(98), header(99), header(100)], 100).is_ok());
+        assert!(check_block_numbers(&[header(100)], 100).is_ok());
+
+        // A gap or reordering would index leaves under the wrong blocks
+        let err = check_block_numbers(&[header(98), header(100)], 100).unwrap_err();
+        assert!(err.to_string().contains("does not follow"), "{}", err);
+        assert!(check_block_numbers(&[header(100), header(99)], 100).is_err());
+
+        // Consecutive, but not the range the caller expects
+        let err = check_block_numbers(&[header(98), header(99)], 100).unwrap_err();
+        assert!(err.to_string().contains("expected 100"), "{}", err);
+    }
+
     #[tokio::test]
     async fn test_create_new_state() {
         let guest_output = GuestOutput::new(
-            1,                                                                                // batch_index
+            guest_types::journal::JournalHeader::new([1; 8], 1),
+            1,   // batch_index
             100, // latest_mmr_block
             "0x0000000000000000000000000000000000000000000000001234567890abcdef".to_string(), // 64 chars hex
             "0x0000000000000000000000000000000000000000000000001234567890abcdef".to_string(), // 64 chars hex
             10, // leaves_count
             "0x0000000000000000000000000000000000000000000000001234567890abcdef".to_string(), // 64 chars hex
-            vec![(0, 100, 100), (1, 200, 200), (2, 300, 300), (3, 400, 400)],
+            vec![
+                AvgFees::new(0, 100, 100, 90, 110, 100, 100, 100, 1),
+                AvgFees::new(3600, 200, 200, 190, 210, 200, 200, 200, 1),
+            ],
+            guest_types::fork::Fork::Cancun.id(),
         );
 
         let result = MMRStateManager::create_new_state(100, &guest_output).await;
@@ -4,9 +4,9 @@ use garaga_rs::{
     },
     definitions::CurveID,
 };
+use guest_types::GuestOutput;
 use risc0_ethereum_contracts::encode_seal;
 use risc0_zkvm::{compute_image_id, default_prover, ExecutorEnv, ProverOpts, VerifierContext};
-use serde::Deserialize;
 use tokio::task;
 use tokio::time::{sleep, Duration};
 use tracing::{debug, info};
@@ -92,7 +92,21 @@ where
         self.generate_groth16_proof_with_retry(input).await
     }
 
-    pub fn decode_journal<U: for<'a> Deserialize<'a>>(&self, proof: &Groth16) -> Result<U> {
+    pub const fn method_id(&self) -> [u32; 8] {
+        self.method_id
+    }
+
+    /// The method ID as the journal encodes it: the eight words, big-endian, in hex
+    pub fn method_id_hex(&self) -> String {
+        self.method_id
+            .iter()
+            .map(|word| format!("{:08x}", word))
+            .collect()
+    }
+
+    /// Decodes the versioned journal committed by the guest and checks that it was
+    /// produced for the method this generator proves, on `chain_id`.
+    pub fn decode_journal(&self, proof: &Groth16, chain_id: u64) -> Result<GuestOutput> {
         if proof.receipt().journal.bytes.is_empty() {
             return Err(eyre!(
                 "Proof journal cannot be empty: {:?}",
@@ -100,8 +114,26 @@ where
             ));
         }
 
-        let receipt = proof.receipt();
-        Ok(receipt.journal.decode()?)
+        let output = GuestOutput::decode(&proof.receipt().journal.bytes)
+            .map_err(|e| eyre!("Failed to decode journal: {}", e))?;
+
+        if output.header().method_id() != self.method_id {
+            return Err(eyre!(
+                "Journal method ID {:?} does not match {:?}",
+                output.header().method_id(),
+                self.method_id
+            ));
+        }
+
+        if output.header().chain_id() != chain_id {
+            return Err(eyre!(
+                "Journal chain ID {} does not match {}",
+                output.header().chain_id(),
+                chain_id
+            ));
+        }
+
+        Ok(output)
     }
 
     async fn generate_groth16_proof_with_retry(&self, input: T) -> Result<Groth16> {
@@ -241,6 +273,15 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_method_id_hex() {
+        let generator = ProofGenerator::<TestInput>::new(TEST_METHOD_ELF, TEST_METHOD_ID).unwrap();
+        assert_eq!(
+            generator.method_id_hex(),
+            "0000000100000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
     #[tokio::test]
     async fn test_generate_stark_proof_invalid_input() {
         let proof_generator =
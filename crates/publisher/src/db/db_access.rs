@@ -1,6 +1,7 @@
 use common::get_env_var;
 use eth_rlp_types::BlockHeader;
 use eyre::{eyre, Result};
+use guest_types::header::Header;
 use mmr_utils::{create_database_file, ensure_directory_exists};
 use sqlx::{postgres::PgPoolOptions, Pool, Postgres};
 use std::sync::Arc;
@@ -77,7 +78,7 @@ impl DbConnection {
         &self,
         start_block: u64,
         end_block: u64,
-    ) -> Result<Vec<BlockHeader>> {
+    ) -> Result<Vec<Header>> {
         if start_block > end_block {
             return Err(eyre!(
                 "Invalid block range: start block {} is greater than end block {}",
@@ -94,7 +95,7 @@ impl DbConnection {
                    difficulty, totaldifficulty, sha3_uncles, 
                    CASE WHEN CAST("timestamp" AS text) ~ '^[0-9]+$' THEN CAST("timestamp" AS bigint) END AS "timestamp",
                    extra_data, mix_hash, withdrawals_root, 
-                   blob_gas_used, excess_blob_gas, parent_beacon_block_root
+                   blob_gas_used, excess_blob_gas, parent_beacon_block_root, requests_hash
             FROM public.blockheaders
             WHERE number BETWEEN $1 AND $2
             ORDER BY number ASC
@@ -105,8 +106,7 @@ impl DbConnection {
         .fetch_all(&self.pool)
         .await?;
 
-        let headers: Vec<BlockHeader> =
-            temp_headers.into_iter().map(temp_to_block_header).collect();
+        let headers: Vec<Header> = temp_headers.into_iter().map(temp_to_header).collect();
 
         Ok(headers)
     }
@@ -123,7 +123,7 @@ impl DbConnection {
                    base_fee_per_gas, parent_hash, miner, logs_bloom, 
                    difficulty, totaldifficulty, sha3_uncles, "timestamp", 
                    extra_data, mix_hash, withdrawals_root, 
-                   blob_gas_used, excess_blob_gas, parent_beacon_block_root
+                   blob_gas_used, excess_blob_gas, parent_beacon_block_root, requests_hash
             FROM blockheaders
             WHERE number = $1
             "#,
@@ -161,7 +161,7 @@ impl DbConnection {
                     base_fee_per_gas, parent_hash, miner, logs_bloom, 
                     difficulty, totaldifficulty, sha3_uncles, "timestamp", 
                     extra_data, mix_hash, withdrawals_root, 
-                    blob_gas_used, excess_blob_gas, parent_beacon_block_root
+                    blob_gas_used, excess_blob_gas, parent_beacon_block_root, requests_hash
                 FROM blockheaders
                 WHERE number BETWEEN $1 AND $2
                 ORDER BY date_trunc('hour', to_timestamp("timestamp")), number ASC
@@ -220,6 +220,12 @@ struct TempBlockHeader {
     pub blob_gas_used: Option<String>,
     pub excess_blob_gas: Option<String>,
     pub parent_beacon_block_root: Option<String>,
+    pub requests_hash: Option<String>,
+}
+
+fn temp_to_header(mut temp: TempBlockHeader) -> Header {
+    let requests_hash = temp.requests_hash.take();
+    Header::new(temp_to_block_header(temp), requests_hash)
 }
 
 fn temp_to_block_header(temp: TempBlockHeader) -> BlockHeader {
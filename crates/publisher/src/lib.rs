@@ -2,6 +2,8 @@
 use clap as _;
 use dotenv as _;
 use pyo3 as _;
+#[cfg(test)]
+use tempfile as _;
 use tracing_subscriber as _;
 pub mod api;
 pub mod cli;
@@ -1,7 +1,7 @@
 use risc0_zkvm::{Journal, Receipt};
 use serde::{Deserialize, Serialize};
 use starknet_crypto::Felt;
-use starknet_handler::MmrState;
+use starknet_handler::{cid::Cid, MmrState};
 
 use eyre::{eyre, Result};
 
@@ -65,7 +65,7 @@ pub struct BatchResult {
     end_block: u64,
     new_mmr_state: MmrState,
     proof: Option<Groth16>,
-    ipfs_hash: String,
+    ipfs_hash: Cid,
 }
 
 impl BatchResult {
@@ -74,7 +74,7 @@ impl BatchResult {
         end_block: u64,
         new_mmr_state: MmrState,
         proof: Option<Groth16>,
-        ipfs_hash: String,
+        ipfs_hash: Cid,
     ) -> Self {
         Self {
             start_block,
@@ -101,7 +101,7 @@ impl BatchResult {
         self.proof.clone()
     }
 
-    pub fn ipfs_hash(&self) -> String {
+    pub fn ipfs_hash(&self) -> Cid {
         self.ipfs_hash.clone()
     }
 }
@@ -2,14 +2,16 @@
 use mmr_utils::initialize_mmr;
 use std::fs;
 use std::path::PathBuf;
+use tempfile::TempDir;
 
 #[tokio::test]
 async fn test_mmr_proofs() {
-    // Get path to the test file's directory
-    let test_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests");
-    fs::create_dir_all(&test_dir).expect("Failed to create test directory");
-
-    let binding = test_dir.join("0.db");
+    // Opening a database migrates it and writes WAL files, so work on a copy of the
+    // checked-in fixture
+    let fixture = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/0.db");
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    let binding = dir.path().join("0.db");
+    fs::copy(&fixture, &binding).expect("Failed to copy test database");
     let store_path = binding.to_str().unwrap();
     let (store_manager, mmr, pool) = initialize_mmr(store_path).await.unwrap();
 
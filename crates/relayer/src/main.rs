@@ -1,6 +1,7 @@
 #![deny(unused_crate_dependencies)]
 
 mod relayer;
+mod signer;
 
 use crate::relayer::Relayer;
 use clap::Parser;
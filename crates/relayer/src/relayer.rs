@@ -1,8 +1,9 @@
+use crate::signer;
 use alloy::{
-    network::EthereumWallet, primitives::U256, providers::ProviderBuilder,
-    signers::local::PrivateKeySigner, sol_types::sol,
+    network::EthereumWallet, primitives::U256, providers::ProviderBuilder, sol_types::sol,
 };
-use common::{get_env_var, get_var};
+use common::deployment::{contract_address, Contract};
+use common::{get_env_var, get_var, signer::KeySource};
 use eyre::{eyre, Result};
 use std::time::Duration;
 use tracing::info;
@@ -21,14 +22,12 @@ pub struct Relayer {
 
 impl Relayer {
     pub async fn new() -> Result<Self> {
-        // Load the private key and initialize the signer
-        let signer: PrivateKeySigner = get_var("ACCOUNT_PRIVATE_KEY")?;
+        // Load the key from a keystore, remote signer or ACCOUNT_PRIVATE_KEY
+        let wallet = signer::wallet(&KeySource::from_env("ACCOUNT")?).await?;
 
-        // Create the wallet
-        let wallet = EthereumWallet::from(signer);
-
-        // Get the L2 proxy address as a string first
-        let addr_str = get_env_var("L2_MSG_PROXY")?;
+        // Get the L2 proxy address as a string first, from the deployment manifest
+        // or L2_MSG_PROXY
+        let addr_str = contract_address(Contract::L1MessageProxy)?;
 
         // Validate Starknet address format:
         // 1. Must start with "0x"
@@ -101,6 +100,7 @@ use std::env;
 mod tests {
     use super::*;
     use alloy::primitives::U256;
+    use alloy::signers::local::PrivateKeySigner;
     // use std::str::FromStr;
 
     fn setup_test_env() {
@@ -207,7 +207,7 @@ mod tests {
         // Just check that it's an error without expecting a specific type
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
-        assert!(err.contains("environment variable not found"));
+        assert!(err.contains("No signing key configured"));
     }
 
     // Add a test to verify environment variable parsing
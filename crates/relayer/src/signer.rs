@@ -0,0 +1,164 @@
+use alloy::{
+    consensus::SignableTransaction,
+    network::{EthereumWallet, TxSigner},
+    primitives::{Address, PrimitiveSignature, B256, U256},
+    signers::local::PrivateKeySigner,
+};
+use async_trait::async_trait;
+use common::signer::{KeySource, RemoteSigner};
+use eyre::{eyre, Result};
+use std::str::FromStr;
+use tracing::info;
+
+/// Builds the relayer's wallet from `source`. A remote signer is asked for its
+/// address up front, so a misconfigured signer fails at startup.
+pub async fn wallet(source: &KeySource) -> Result<EthereumWallet> {
+    match source {
+        KeySource::Local(private_key) => {
+            let signer: PrivateKeySigner = private_key
+                .parse()
+                .map_err(|e| eyre!("ACCOUNT_PRIVATE_KEY: {}", e))?;
+            Ok(EthereumWallet::from(signer))
+        }
+        KeySource::Remote(config) => {
+            let signer = RemoteTxSigner::connect(RemoteSigner::new(config.clone())?).await?;
+            info!(address = %signer.address, url = %config.url, "Using remote signer");
+            Ok(EthereumWallet::from(signer))
+        }
+    }
+}
+
+/// Signs transactions through a remote signer, checking that each signature
+/// recovers to the signer's address
+#[derive(Debug)]
+pub struct RemoteTxSigner {
+    signer: RemoteSigner,
+    address: Address,
+}
+
+impl RemoteTxSigner {
+    pub async fn connect(signer: RemoteSigner) -> Result<Self> {
+        let address = signer.public_key().await?;
+        let address = Address::from_str(&address)
+            .map_err(|e| eyre!("Invalid address from remote signer {}: {}", address, e))?;
+        Ok(Self { signer, address })
+    }
+
+    async fn sign_hash(&self, hash: B256) -> Result<PrimitiveSignature> {
+        let components = self.signer.sign(&hash.to_string()).await?;
+        let signature = parse_signature(&components)?;
+
+        let recovered = signature.recover_address_from_prehash(&hash)?;
+        if recovered != self.address {
+            return Err(eyre!(
+                "Remote signer signed as {}, expected {}",
+                recovered,
+                self.address
+            ));
+        }
+        Ok(signature)
+    }
+}
+
+/// Parses the `[r, s, v]` returned by a remote signer. `v` may be the y parity or
+/// the legacy 27/28.
+fn parse_signature(components: &[String]) -> Result<PrimitiveSignature> {
+    let [r, s, v] = components else {
+        return Err(eyre!(
+            "Expected a signature of 3 components, got {}",
+            components.len()
+        ));
+    };
+    let parse = |value: &str| {
+        U256::from_str(value).map_err(|e| eyre!("Invalid signature component {}: {}", value, e))
+    };
+    let y_parity = match parse(v)?.to::<u64>() {
+        0 | 27 => false,
+        1 | 28 => true,
+        v => return Err(eyre!("Invalid signature recovery id: {}", v)),
+    };
+    Ok(PrimitiveSignature::new(parse(r)?, parse(s)?, y_parity))
+}
+
+#[async_trait]
+impl TxSigner<PrimitiveSignature> for RemoteTxSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<PrimitiveSignature>,
+    ) -> alloy::signers::Result<PrimitiveSignature> {
+        self.sign_hash(tx.signature_hash())
+            .await
+            .map_err(alloy::signers::Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::consensus::TxEip1559;
+    use alloy::signers::SignerSync;
+    use common::signer::{mock, RemoteSignerConfig};
+
+    const KEY: &str = "0x1234567890123456789012345678901234567890123456789012345678901234";
+
+    async fn serve(key: PrivateKeySigner, address: Address) -> RemoteSigner {
+        let url = mock::serve(address.to_string(), None, move |hash| {
+            let signature = key.sign_hash_sync(&B256::from_str(hash).unwrap()).unwrap();
+            vec![
+                format!("{:#x}", signature.r()),
+                format!("{:#x}", signature.s()),
+                format!("{:#x}", signature.v() as u8),
+            ]
+        })
+        .await;
+        RemoteSigner::new(RemoteSignerConfig { url, token: None }).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_signs_transactions() {
+        let key: PrivateKeySigner = KEY.parse().unwrap();
+        let address = key.address();
+        let signer = RemoteTxSigner::connect(serve(key, address).await)
+            .await
+            .unwrap();
+        assert_eq!(TxSigner::address(&signer), address);
+
+        let mut tx = TxEip1559 {
+            chain_id: 11155111,
+            nonce: 7,
+            ..Default::default()
+        };
+        let signature = signer.sign_transaction(&mut tx).await.unwrap();
+        assert_eq!(
+            signature
+                .recover_address_from_prehash(&tx.signature_hash())
+                .unwrap(),
+            address
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_with_wrong_key() {
+        let key: PrivateKeySigner = KEY.parse().unwrap();
+        let signer = RemoteTxSigner::connect(serve(key, Address::repeat_byte(1)).await)
+            .await
+            .unwrap();
+
+        let mut tx = TxEip1559::default();
+        let err = signer.sign_transaction(&mut tx).await.unwrap_err();
+        assert!(err.to_string().contains("Remote signer signed as"));
+    }
+
+    #[test]
+    fn test_parse_signature() {
+        let components = |v: &str| vec!["0x1".to_string(), "0x2".to_string(), v.to_string()];
+        assert!(!parse_signature(&components("0x0")).unwrap().v());
+        assert!(parse_signature(&components("0x1c")).unwrap().v());
+        assert!(parse_signature(&components("0x2")).is_err());
+        assert!(parse_signature(&components("0x1")[..2]).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use clap::Parser;
+use common::{felt, get_env_var, initialize_logger_and_env, signer::KeySource};
+use eyre::Result;
+use starknet_handler::account::StarknetAccount;
+use starknet_handler::deploy::{parse_method_id, DeployConfig, Deployer};
+use starknet_handler::provider::StarknetProvider;
+use std::path::PathBuf;
+use tracing::info;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Declare, deploy and initialize the Fossil Starknet contracts", long_about = None)]
+struct Args {
+    /// Directory `scarb build` wrote the contract classes to
+    #[arg(short = 'a', long, default_value = "contracts/starknet/target/dev")]
+    artifacts_dir: PathBuf,
+
+    /// Where to write the deployment manifest
+    #[arg(short = 'o', long, default_value = "config/deployment.json")]
+    manifest: PathBuf,
+
+    /// Minimum number of seconds between two relayed block hashes
+    #[arg(short = 'i', long, default_value_t = 0)]
+    min_update_interval: u64,
+
+    /// Image ID of the mmr-build guest as the journal encodes it: 64 hex digits,
+    /// the eight words of `MMR_BUILD_ID` big-endian
+    #[arg(short = 'm', long)]
+    method_id: String,
+
+    /// Salt of the contract addresses. Running again with the same salt resumes an
+    /// interrupted deployment.
+    #[arg(short = 's', long, default_value = "0x1")]
+    salt: String,
+
+    /// Accept a store an earlier run already initialized. Its settings can't be read
+    /// back, so pass the `--min-update-interval` that run used.
+    #[arg(long, default_value_t = false)]
+    resume_initialized_store: bool,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    initialize_logger_and_env()?;
+    let args = Args::parse();
+
+    let provider = StarknetProvider::new(&get_env_var("STARKNET_RPC_URL")?)?;
+    provider.check_health().await?;
+    let account = StarknetAccount::connect(
+        provider.provider(),
+        &KeySource::from_env("STARKNET")?,
+        &get_env_var("STARKNET_ACCOUNT_ADDRESS")?,
+    )
+    .await?;
+
+    let config = DeployConfig {
+        artifacts_dir: args.artifacts_dir,
+        l1_message_sender: felt(&get_env_var("L1_MESSAGE_SENDER")?)?,
+        min_update_interval: args.min_update_interval,
+        method_id: parse_method_id(&args.method_id)?,
+        chain_id: get_env_var("CHAIN_ID")?.parse()?,
+        salt: felt(&args.salt)?,
+        resume_initialized_store: args.resume_initialized_store,
+    };
+    let deployment = Deployer::new(&account, config).run().await?;
+    deployment.save(&args.manifest)?;
+
+    info!(
+        manifest = %args.manifest.display(),
+        store = %deployment.store,
+        fossil_verifier = %deployment.fossil_verifier,
+        l1_message_proxy = %deployment.l1_message_proxy,
+        "Contracts deployed"
+    );
+    Ok(())
+}
@@ -0,0 +1,588 @@
+//! Typed bindings for the Fossil store and verifier contracts.
+//!
+//! Each binding mirrors the contract's interface in `contracts/starknet`
+//! (`IFossilStore`, `IFossilVerifier` and the OpenZeppelin ownable mixin): views are
+//! async methods returning decoded values, and external functions build the [`Call`]
+//! to pass to an account. The tests check the bindings against the Cairo sources so
+//! that a new or renamed entry point does not go unnoticed.
+use eyre::{eyre, Result};
+use starknet::core::codec::{Decode, Encode};
+use starknet::core::types::{BlockId, BlockTag, ByteArray, Call, FunctionCall, U256};
+use starknet::macros::selector;
+use starknet::providers::Provider;
+use starknet_crypto::Felt;
+use std::sync::Arc;
+
+use crate::cid::Cid;
+use crate::MmrSnapshot;
+
+/// `verifier::Journal`, the public output of a batch proof
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct Journal {
+    pub version: u8,
+    pub method_id: U256,
+    pub hasher_id: u8,
+    pub chain_id: u64,
+    pub batch_index: u64,
+    pub latest_mmr_block: u64,
+    pub latest_mmr_block_hash: U256,
+    pub root_hash: U256,
+    pub leaves_count: u64,
+    pub first_block_parent_hash: U256,
+    pub fork_id: u8,
+}
+
+/// The `method_id` of a journal proven by the guest with image ID `words`: the
+/// eight words, big-endian, concatenated
+pub fn method_id(words: &[u32; 8]) -> U256 {
+    let mut bytes = [0u8; 32];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    U256::from_words(
+        u128::from_be_bytes(bytes[16..].try_into().unwrap()),
+        u128::from_be_bytes(bytes[..16].try_into().unwrap()),
+    )
+}
+
+/// `verifier::AvgFees`, the fee statistics of one hour. For an hour proven across
+/// several batches the store keeps only `data_points`, `avg_fee`, `min_fee` and
+/// `max_fee`; the other statistics read 0.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct AvgFees {
+    pub timestamp: u64,
+    pub data_points: u64,
+    pub avg_fee: u64,
+    pub min_fee: u64,
+    pub max_fee: u64,
+    pub median_fee: u64,
+    pub weighted_avg_fee: u64,
+    pub twap_fee: u64,
+    pub blob_base_fee: u64,
+}
+
+fn encode(args: &[&dyn EncodeArg]) -> Result<Vec<Felt>> {
+    let mut calldata = vec![];
+    for arg in args {
+        arg.encode_into(&mut calldata)?;
+    }
+    Ok(calldata)
+}
+
+/// Object-safe wrapper around [`Encode`], so calldata can be built from mixed types
+trait EncodeArg {
+    fn encode_into(&self, calldata: &mut Vec<Felt>) -> Result<()>;
+}
+
+impl<T: Encode> EncodeArg for T {
+    fn encode_into(&self, calldata: &mut Vec<Felt>) -> Result<()> {
+        Ok(self.encode(calldata)?)
+    }
+}
+
+/// Decodes the `(u64, u256)` returned by `get_latest_blockhash_from_l1`
+fn decode_relayed_block(data: &[Felt]) -> Result<(u64, U256)> {
+    let mut data = data.iter();
+    let block_number = u64::decode_iter(&mut data)?;
+    let block_hash = U256::decode_iter(&mut data)?;
+    if data.next().is_some() {
+        return Err(eyre!("Unexpected trailing data after the relayed block"));
+    }
+    Ok((block_number, block_hash))
+}
+
+async fn view<P: Provider + Sync>(
+    provider: &P,
+    address: Felt,
+    block_id: BlockId,
+    entry_point_selector: Felt,
+    calldata: Vec<Felt>,
+) -> Result<Vec<Felt>> {
+    Ok(provider
+        .call(
+            FunctionCall {
+                contract_address: address,
+                entry_point_selector,
+                calldata,
+            },
+            block_id,
+        )
+        .await?)
+}
+
+/// The `Store` contract
+#[derive(Clone, Debug)]
+pub struct FossilStore<P> {
+    provider: Arc<P>,
+    address: Felt,
+    block_id: BlockId,
+}
+
+impl<P: Provider + Sync> FossilStore<P> {
+    /// Views read the latest block until pinned with [`Self::at`]
+    pub const fn new(provider: Arc<P>, address: Felt) -> Self {
+        Self {
+            provider,
+            address,
+            block_id: BlockId::Tag(BlockTag::Latest),
+        }
+    }
+
+    /// Reads views at `block_id` instead
+    pub fn at(self, block_id: BlockId) -> Self {
+        Self { block_id, ..self }
+    }
+
+    pub const fn address(&self) -> Felt {
+        self.address
+    }
+
+    pub const fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+
+    async fn view(&self, selector: Felt, calldata: Vec<Felt>) -> Result<Vec<Felt>> {
+        view(
+            self.provider.as_ref(),
+            self.address,
+            self.block_id,
+            selector,
+            calldata,
+        )
+        .await
+    }
+
+    /// The latest L1 block relayed by the message proxy, and its hash
+    pub async fn get_latest_blockhash_from_l1(&self) -> Result<(u64, U256)> {
+        let data = self
+            .view(selector!("get_latest_blockhash_from_l1"), vec![])
+            .await?;
+        decode_relayed_block(&data)
+    }
+
+    pub async fn get_mmr_state(&self, batch_index: u64) -> Result<MmrSnapshot> {
+        let data = self
+            .view(selector!("get_mmr_state"), encode(&[&batch_index])?)
+            .await?;
+        Ok(MmrSnapshot::decode(&data)?)
+    }
+
+    pub async fn get_latest_mmr_block(&self) -> Result<u64> {
+        let data = self.view(selector!("get_latest_mmr_block"), vec![]).await?;
+        Ok(u64::decode(&data)?)
+    }
+
+    pub async fn get_min_mmr_block(&self) -> Result<u64> {
+        let data = self.view(selector!("get_min_mmr_block"), vec![]).await?;
+        Ok(u64::decode(&data)?)
+    }
+
+    /// Hash of the last block of `batch_index`, which the next batch must link to
+    pub async fn get_batch_last_block_link(&self, batch_index: u64) -> Result<U256> {
+        let data = self
+            .view(
+                selector!("get_batch_last_block_link"),
+                encode(&[&batch_index])?,
+            )
+            .await?;
+        Ok(U256::decode(&data)?)
+    }
+
+    pub async fn get_batch_first_block_parent_hash(&self, batch_index: u64) -> Result<U256> {
+        let data = self
+            .view(
+                selector!("get_batch_first_block_parent_hash"),
+                encode(&[&batch_index])?,
+            )
+            .await?;
+        Ok(U256::decode(&data)?)
+    }
+
+    pub async fn get_avg_fee(&self, timestamp: u64) -> Result<u64> {
+        let data = self
+            .view(selector!("get_avg_fee"), encode(&[&timestamp])?)
+            .await?;
+        Ok(u64::decode(&data)?)
+    }
+
+    pub async fn get_hourly_fees(&self, timestamp: u64) -> Result<AvgFees> {
+        let data = self
+            .view(selector!("get_hourly_fees"), encode(&[&timestamp])?)
+            .await?;
+        Ok(AvgFees::decode(&data)?)
+    }
+
+    /// Average fee of every hour from `start_timestamp` to `end_timestamp`
+    pub async fn get_avg_fees_in_range(
+        &self,
+        start_timestamp: u64,
+        end_timestamp: u64,
+    ) -> Result<Vec<u64>> {
+        let data = self
+            .view(
+                selector!("get_avg_fees_in_range"),
+                encode(&[&start_timestamp, &end_timestamp])?,
+            )
+            .await?;
+        Ok(Vec::<u64>::decode(&data)?)
+    }
+
+    pub async fn owner(&self) -> Result<Felt> {
+        let data = self.view(selector!("owner"), vec![]).await?;
+        Ok(Felt::decode(&data)?)
+    }
+
+    fn call(&self, selector: Felt, calldata: Vec<Felt>) -> Call {
+        Call {
+            to: self.address,
+            selector,
+            calldata,
+        }
+    }
+
+    pub fn initialize(
+        &self,
+        verifier_address: Felt,
+        l1_message_proxy_address: Felt,
+        min_update_interval: u64,
+    ) -> Result<Call> {
+        Ok(self.call(
+            selector!("initialize"),
+            encode(&[
+                &verifier_address,
+                &l1_message_proxy_address,
+                &min_update_interval,
+            ])?,
+        ))
+    }
+
+    /// Only accepted from the L1 message proxy
+    pub fn store_latest_blockhash_from_l1(
+        &self,
+        block_number: u64,
+        blockhash: U256,
+    ) -> Result<Call> {
+        Ok(self.call(
+            selector!("store_latest_blockhash_from_l1"),
+            encode(&[&block_number, &blockhash])?,
+        ))
+    }
+
+    /// Only accepted from the verifier
+    pub fn update_store_state(
+        &self,
+        verifier_caller: Felt,
+        journal: &Journal,
+        avg_fees: &[AvgFees],
+        ipfs_hash: &Cid,
+    ) -> Result<Call> {
+        Ok(self.call(
+            selector!("update_store_state"),
+            encode(&[
+                &verifier_caller,
+                journal,
+                &avg_fees.to_vec(),
+                &ByteArray::from(ipfs_hash),
+            ])?,
+        ))
+    }
+
+    pub fn upgrade(&self, new_class_hash: Felt) -> Result<Call> {
+        Ok(self.call(selector!("upgrade"), encode(&[&new_class_hash])?))
+    }
+
+    pub fn transfer_ownership(&self, new_owner: Felt) -> Result<Call> {
+        Ok(self.call(selector!("transfer_ownership"), encode(&[&new_owner])?))
+    }
+
+    pub fn renounce_ownership(&self) -> Call {
+        self.call(selector!("renounce_ownership"), vec![])
+    }
+}
+
+/// The `FossilVerifier` contract
+#[derive(Clone, Debug)]
+pub struct FossilVerifier<P> {
+    provider: Arc<P>,
+    address: Felt,
+    block_id: BlockId,
+}
+
+impl<P: Provider + Sync> FossilVerifier<P> {
+    /// Views read the latest block until pinned with [`Self::at`]
+    pub const fn new(provider: Arc<P>, address: Felt) -> Self {
+        Self {
+            provider,
+            address,
+            block_id: BlockId::Tag(BlockTag::Latest),
+        }
+    }
+
+    /// Reads views at `block_id` instead
+    pub fn at(self, block_id: BlockId) -> Self {
+        Self { block_id, ..self }
+    }
+
+    pub const fn address(&self) -> Felt {
+        self.address
+    }
+
+    pub const fn block_id(&self) -> BlockId {
+        self.block_id
+    }
+
+    async fn view(&self, selector: Felt) -> Result<Vec<Felt>> {
+        view(
+            self.provider.as_ref(),
+            self.address,
+            self.block_id,
+            selector,
+            vec![],
+        )
+        .await
+    }
+
+    /// The Groth16 verifier that checks proofs
+    pub async fn get_verifier_address(&self) -> Result<Felt> {
+        let data = self.view(selector!("get_verifier_address")).await?;
+        Ok(Felt::decode(&data)?)
+    }
+
+    pub async fn get_fossil_store_address(&self) -> Result<Felt> {
+        let data = self.view(selector!("get_fossil_store_address")).await?;
+        Ok(Felt::decode(&data)?)
+    }
+
+    /// Image ID of the guest whose proofs are accepted, see [`method_id`]
+    pub async fn get_method_id(&self) -> Result<U256> {
+        let data = self.view(selector!("get_method_id")).await?;
+        Ok(U256::decode(&data)?)
+    }
+
+    /// The Ethereum chain the batches are built from
+    pub async fn get_chain_id(&self) -> Result<u64> {
+        let data = self.view(selector!("get_chain_id")).await?;
+        Ok(u64::decode(&data)?)
+    }
+
+    pub async fn owner(&self) -> Result<Felt> {
+        let data = self.view(selector!("owner")).await?;
+        Ok(Felt::decode(&data)?)
+    }
+
+    fn call(&self, selector: Felt, calldata: Vec<Felt>) -> Call {
+        Call {
+            to: self.address,
+            selector,
+            calldata,
+        }
+    }
+
+    pub fn verify_mmr_proof(
+        &self,
+        proof: &[Felt],
+        ipfs_hash: &Cid,
+        is_build: bool,
+    ) -> Result<Call> {
+        Ok(self.call(
+            selector!("verify_mmr_proof"),
+            encode(&[&proof.to_vec(), &ByteArray::from(ipfs_hash), &is_build])?,
+        ))
+    }
+
+    pub fn update_verifier_address(&self, new_verifier_address: Felt) -> Result<Call> {
+        Ok(self.call(
+            selector!("update_verifier_address"),
+            encode(&[&new_verifier_address])?,
+        ))
+    }
+
+    /// Accepts proofs of another guest, after a guest upgrade
+    pub fn update_method_id(&self, new_method_id: U256) -> Result<Call> {
+        Ok(self.call(selector!("update_method_id"), encode(&[&new_method_id])?))
+    }
+
+    pub fn upgrade(&self, new_class_hash: Felt) -> Result<Call> {
+        Ok(self.call(selector!("upgrade"), encode(&[&new_class_hash])?))
+    }
+
+    pub fn transfer_ownership(&self, new_owner: Felt) -> Result<Call> {
+        Ok(self.call(selector!("transfer_ownership"), encode(&[&new_owner])?))
+    }
+
+    pub fn renounce_ownership(&self) -> Call {
+        self.call(selector!("renounce_ownership"), vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::providers::{jsonrpc::HttpTransport, JsonRpcClient, Url};
+
+    const STORE_SOURCE: &str = include_str!("../../../contracts/starknet/store/src/lib.cairo");
+    const VERIFIER_SOURCE: &str =
+        include_str!("../../../contracts/starknet/verifier/src/fossil_verifier.cairo");
+
+    /// Entry points declared by the `#[starknet::interface]` trait in `source`
+    fn interface_functions(source: &str) -> Vec<String> {
+        let start = source.find("#[starknet::interface]").unwrap();
+        let end = start + source[start..].find("\n}").unwrap();
+        source[start..end]
+            .split("fn ")
+            .skip(1)
+            .map(|f| f[..f.find('(').unwrap()].trim().to_string())
+            .collect()
+    }
+
+    fn provider() -> Arc<JsonRpcClient<HttpTransport>> {
+        Arc::new(JsonRpcClient::new(HttpTransport::new(
+            Url::parse("http://localhost:5050").unwrap(),
+        )))
+    }
+
+    #[test]
+    fn test_bindings_cover_interfaces() {
+        let store = [
+            "initialize",
+            "store_latest_blockhash_from_l1",
+            "update_store_state",
+            "get_latest_blockhash_from_l1",
+            "get_mmr_state",
+            "get_latest_mmr_block",
+            "get_min_mmr_block",
+            "get_batch_last_block_link",
+            "get_batch_first_block_parent_hash",
+            "get_avg_fee",
+            "get_hourly_fees",
+            "get_avg_fees_in_range",
+            "upgrade",
+        ];
+        assert_eq!(interface_functions(STORE_SOURCE), store);
+
+        let verifier = [
+            "verify_mmr_proof",
+            "update_verifier_address",
+            "update_method_id",
+            "get_verifier_address",
+            "get_fossil_store_address",
+            "get_method_id",
+            "get_chain_id",
+            "upgrade",
+        ];
+        assert_eq!(interface_functions(VERIFIER_SOURCE), verifier);
+    }
+
+    #[test]
+    fn test_decode_relayed_block() {
+        let data = vec![Felt::from(21_000_000u64), Felt::from(7u64), Felt::ONE];
+        assert_eq!(
+            decode_relayed_block(&data).unwrap(),
+            (21_000_000, U256::from_words(7, 1))
+        );
+
+        assert!(decode_relayed_block(&data[..2]).is_err());
+        let mut trailing = data.clone();
+        trailing.push(Felt::ZERO);
+        assert!(decode_relayed_block(&trailing).is_err());
+    }
+
+    #[test]
+    fn test_verify_mmr_proof_calldata() {
+        let verifier = FossilVerifier::new(provider(), Felt::from(0x123u64));
+        let proof = vec![Felt::from(7u64), Felt::from(8u64)];
+
+        let cid: Cid = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+            .parse()
+            .unwrap();
+        let bytes = cid.as_str().as_bytes();
+
+        let call = verifier.verify_mmr_proof(&proof, &cid, true).unwrap();
+        assert_eq!(call.to, Felt::from(0x123u64));
+        assert_eq!(call.selector, selector!("verify_mmr_proof"));
+        assert_eq!(
+            call.calldata,
+            vec![
+                // proof
+                Felt::from(2u64),
+                Felt::from(7u64),
+                Felt::from(8u64),
+                // ipfs_hash: one full 31-byte word, pending word of length 15
+                Felt::ONE,
+                Felt::from_bytes_be_slice(&bytes[..31]),
+                Felt::from_bytes_be_slice(&bytes[31..]),
+                Felt::from(15u64),
+                // is_build
+                Felt::ONE,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_method_id() {
+        let words = [
+            0x11111111, 0x22222222, 0x33333333, 0x44444444, 0x55555555, 0x66666666, 0x77777777,
+            0x88888888,
+        ];
+        // Same value as the method ID of the journal test vector
+        assert_eq!(
+            method_id(&words),
+            U256::from_words(
+                0x55555555666666667777777788888888,
+                0x11111111222222223333333344444444
+            )
+        );
+
+        let verifier = FossilVerifier::new(provider(), Felt::from(0x123u64));
+        let call = verifier.update_method_id(method_id(&words)).unwrap();
+        assert_eq!(call.selector, selector!("update_method_id"));
+        assert_eq!(
+            call.calldata,
+            vec![
+                Felt::from(0x55555555666666667777777788888888u128),
+                Felt::from(0x11111111222222223333333344444444u128),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_store_calldata() {
+        let store = FossilStore::new(provider(), Felt::from(0x456u64));
+
+        let call = store
+            .store_latest_blockhash_from_l1(42, U256::from(5u64))
+            .unwrap();
+        assert_eq!(call.selector, selector!("store_latest_blockhash_from_l1"));
+        // u256 is encoded as low then high
+        assert_eq!(
+            call.calldata,
+            vec![Felt::from(42u64), Felt::from(5u64), Felt::ZERO]
+        );
+
+        let fees = AvgFees {
+            timestamp: 3600,
+            data_points: 1,
+            avg_fee: 2,
+            min_fee: 3,
+            max_fee: 4,
+            median_fee: 5,
+            weighted_avg_fee: 6,
+            twap_fee: 7,
+            blob_base_fee: 8,
+        };
+        let mut encoded = vec![];
+        fees.encode(&mut encoded).unwrap();
+        assert_eq!(AvgFees::decode(&encoded).unwrap(), fees);
+        assert_eq!(encoded.len(), 9);
+    }
+
+    #[test]
+    fn test_pinned_views() {
+        let store = FossilStore::new(provider(), Felt::from(0x456u64));
+        assert_eq!(store.block_id(), BlockId::Tag(BlockTag::Latest));
+
+        let store = store.at(BlockId::Number(7));
+        assert_eq!(store.block_id(), BlockId::Number(7));
+        assert_eq!(store.address(), Felt::from(0x456u64));
+    }
+}
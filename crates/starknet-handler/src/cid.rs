@@ -0,0 +1,95 @@
+//! CIDs of batch databases, as the store contract keeps them.
+//!
+//! The contract stores a batch's CID as a Cairo `ByteArray` and accepts any bytes,
+//! so nothing on-chain stops a malformed CID from being published. [`Cid`] only
+//! holds CIDs the batch stores can fetch and verify, and is what crosses the
+//! boundary in both directions.
+use eyre::{eyre, Result};
+use cid_utils::CidFormat;
+use starknet::core::types::ByteArray;
+use std::fmt;
+use std::str::FromStr;
+
+/// A CIDv0 (`Qm…`) or base32 CIDv1 (`bafk…`, `bafy…`) of sha2-256 content
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Cid(String);
+
+impl Cid {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Cid {
+    type Err = eyre::Report;
+
+    fn from_str(cid: &str) -> Result<Self> {
+        CidFormat::parse(cid)?;
+        Ok(Self(cid.to_string()))
+    }
+}
+
+impl fmt::Display for Cid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Cid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&Cid> for ByteArray {
+    fn from(cid: &Cid) -> Self {
+        Self::from(cid.as_str())
+    }
+}
+
+impl TryFrom<ByteArray> for Cid {
+    type Error = eyre::Report;
+
+    fn try_from(bytes: ByteArray) -> Result<Self> {
+        String::try_from(bytes)
+            .map_err(|_| eyre!("CID is not valid UTF-8"))?
+            .parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CID_V0: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+    const CID_V1: &str = "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku";
+
+    #[test]
+    fn test_parse() {
+        for cid in [CID_V0, CID_V1] {
+            assert_eq!(cid.parse::<Cid>().unwrap().as_str(), cid);
+        }
+
+        for invalid in [
+            "",
+            "QmTest123",
+            // Base58 of the right length, but not a sha2-256 multihash
+            "Qm1111111111111111111111111111111111111111111",
+            "0x1234",
+            // Uppercase base32 is a different multibase
+            "BAFKREIHDWDCEFGH4DQKJV67UZCMW7OJEE6XEDZDETOJUZJEVTENXQUVYKU",
+        ] {
+            assert!(invalid.parse::<Cid>().is_err(), "{} parsed", invalid);
+        }
+    }
+
+    #[test]
+    fn test_byte_array_round_trip() {
+        let cid: Cid = CID_V1.parse().unwrap();
+        let bytes = ByteArray::from(&cid);
+        assert_eq!(bytes, ByteArray::from(CID_V1));
+        assert_eq!(Cid::try_from(bytes).unwrap(), cid);
+
+        assert!(Cid::try_from(ByteArray::from("QmTest123")).is_err());
+    }
+}
@@ -0,0 +1,376 @@
+//! Declaration and deployment of the Fossil contracts from the Scarb artifacts.
+//!
+//! [`Deployer::run`] declares every class under `contracts/starknet/target/dev`,
+//! deploys the contracts through the Universal Deployer, initializes the store and
+//! returns the [`Deployment`] manifest. Deployments are not unique to the deploying
+//! account, so an address only depends on the class, the salt and the constructor
+//! arguments: running again with the same salt picks up whatever an interrupted run
+//! already declared or deployed.
+use crate::account::StarknetAccount;
+use crate::bindings::{self, FossilStore};
+use common::deployment::{ClassHashes, Deployment};
+use eyre::{eyre, Result, WrapErr};
+use starknet::core::types::contract::{CompiledClass, SierraClass};
+use starknet::core::types::{BlockId, BlockTag, Call, FlattenedSierraClass, StarknetError};
+use starknet::core::utils::{get_udc_deployed_address, parse_cairo_short_string, UdcUniqueness};
+use starknet::macros::{felt, selector};
+use starknet::providers::{Provider, ProviderError};
+use starknet_crypto::Felt;
+use std::path::{Path, PathBuf};
+use tracing::{info, instrument, warn};
+
+/// The Universal Deployer Contract, at this address on every network including Katana
+pub const UDC_ADDRESS: Felt =
+    felt!("0x041a78e741e5af2fec34b695679bc6891742439f7afb8484ecd7766661ad02bf");
+
+/// Artifact names, as Scarb writes them: `<package>_<contract>`
+pub const STORE: &str = "fossil_store_Store";
+pub const FOSSIL_VERIFIER: &str = "verifier_FossilVerifier";
+pub const GROTH16_VERIFIER: &str = "verifier_Risc0Groth16VerifierBN254";
+pub const UNIVERSAL_ECIP: &str = "verifier_UniversalECIP";
+pub const L1_MESSAGE_PROXY: &str = "l1_message_proxy_L1MessageProxy";
+
+const LATEST: BlockId = BlockId::Tag(BlockTag::Latest);
+
+#[derive(Clone, Debug)]
+pub struct DeployConfig {
+    /// Where `scarb build` wrote the artifacts
+    pub artifacts_dir: PathBuf,
+    /// The L1 contract whose messages the proxy accepts
+    pub l1_message_sender: Felt,
+    /// Minimum number of seconds between two relayed block hashes
+    pub min_update_interval: u64,
+    /// Image ID of the mmr-build guest, the only one the Fossil verifier accepts
+    /// proofs of
+    pub method_id: [u32; 8],
+    /// The Ethereum chain the batches are built from
+    pub chain_id: u64,
+    pub salt: Felt,
+    /// Accept a store an earlier run already initialized. The store has no getter
+    /// for its settings, so the manifest then records `min_update_interval` as
+    /// configured here, which is only right if the earlier run used the same value.
+    pub resume_initialized_store: bool,
+}
+
+/// Parses an image ID written as the journal encodes it: 64 hex digits, the eight
+/// words big-endian
+pub fn parse_method_id(hex: &str) -> Result<[u32; 8]> {
+    let hex = hex.trim().trim_start_matches("0x");
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(eyre!("Method ID must be 64 hex digits, got {:?}", hex));
+    }
+
+    let mut words = [0u32; 8];
+    for (i, word) in words.iter_mut().enumerate() {
+        *word = u32::from_str_radix(&hex[i * 8..(i + 1) * 8], 16)
+            .wrap_err_with(|| format!("Invalid method ID {}", hex))?;
+    }
+    Ok(words)
+}
+
+/// Reads the Sierra class of artifact `name`, and hashes its CASM
+pub fn load_class(artifacts_dir: &Path, name: &str) -> Result<(FlattenedSierraClass, Felt)> {
+    let read = |extension: &str| {
+        let path = artifacts_dir.join(format!("{}.{}", name, extension));
+        std::fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Failed to read {}, run `scarb build` first", path.display()))
+    };
+
+    let sierra: SierraClass = serde_json::from_str(&read("contract_class.json")?)
+        .wrap_err_with(|| format!("Invalid Sierra class {}", name))?;
+    let casm: CompiledClass = serde_json::from_str(&read("compiled_contract_class.json")?)
+        .wrap_err_with(|| format!("Invalid CASM class {}", name))?;
+
+    let class = sierra
+        .flatten()
+        .map_err(|e| eyre!("Failed to flatten {}: {}", name, e))?;
+    let compiled_class_hash = casm
+        .class_hash()
+        .map_err(|e| eyre!("Failed to hash the CASM of {}: {}", name, e))?;
+    Ok((class, compiled_class_hash))
+}
+
+/// The UDC call deploying `class_hash` with `calldata`, and the address the
+/// contract will have
+pub fn udc_deploy(class_hash: Felt, salt: Felt, calldata: &[Felt]) -> (Call, Felt) {
+    let address = get_udc_deployed_address(salt, class_hash, &UdcUniqueness::NotUnique, calldata);
+
+    let mut udc_calldata = vec![class_hash, salt, Felt::ZERO, Felt::from(calldata.len())];
+    udc_calldata.extend_from_slice(calldata);
+    let call = Call {
+        to: UDC_ADDRESS,
+        selector: selector!("deployContract"),
+        calldata: udc_calldata,
+    };
+    (call, address)
+}
+
+pub struct Deployer<'a> {
+    account: &'a StarknetAccount,
+    config: DeployConfig,
+}
+
+impl<'a> Deployer<'a> {
+    /// The account pays for the deployment and owns the store and Fossil verifier
+    pub const fn new(account: &'a StarknetAccount, config: DeployConfig) -> Self {
+        Self { account, config }
+    }
+
+    /// Declares artifact `name`, unless its class is already declared, and returns
+    /// the class hash
+    #[instrument(skip(self), level = "debug")]
+    pub async fn declare(&self, name: &str) -> Result<Felt> {
+        let (class, compiled_class_hash) = load_class(&self.config.artifacts_dir, name)?;
+        let class_hash = class.class_hash();
+
+        match self.account.provider().get_class(LATEST, class_hash).await {
+            Ok(_) => {
+                info!(name, class_hash = ?class_hash, "Class already declared");
+                return Ok(class_hash);
+            }
+            Err(ProviderError::StarknetError(StarknetError::ClassHashNotFound)) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        info!(name, class_hash = ?class_hash, "Declaring class");
+        self.account
+            .declare(class, compiled_class_hash)
+            .await?
+            .into_result()
+            .wrap_err_with(|| format!("Failed to declare {}", name))?;
+        Ok(class_hash)
+    }
+
+    /// Deploys `class_hash` with `calldata`, unless it is already deployed, and
+    /// returns the contract address
+    #[instrument(skip(self, calldata), level = "debug")]
+    pub async fn deploy(&self, name: &str, class_hash: Felt, calldata: &[Felt]) -> Result<Felt> {
+        let (call, address) = udc_deploy(class_hash, self.config.salt, calldata);
+
+        match self
+            .account
+            .provider()
+            .get_class_hash_at(LATEST, address)
+            .await
+        {
+            Ok(deployed) if deployed == class_hash => {
+                info!(name, address = ?address, "Contract already deployed");
+                return Ok(address);
+            }
+            Ok(deployed) => {
+                return Err(eyre!(
+                    "{} would be deployed at {}, which holds class {} instead",
+                    name,
+                    address.to_fixed_hex_string(),
+                    deployed.to_fixed_hex_string()
+                ))
+            }
+            Err(ProviderError::StarknetError(StarknetError::ContractNotFound)) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        info!(name, address = ?address, "Deploying contract");
+        self.account
+            .execute(vec![call])
+            .await?
+            .into_result()
+            .wrap_err_with(|| format!("Failed to deploy {}", name))?;
+        Ok(address)
+    }
+
+    /// Declares and deploys the store, the verifiers and the L1 message proxy,
+    /// then initializes the store with them
+    pub async fn run(&self) -> Result<Deployment> {
+        let owner = self.account.address();
+        let provider = self.account.provider();
+
+        let universal_ecip = self.declare(UNIVERSAL_ECIP).await?;
+        let groth16_class = self.declare(GROTH16_VERIFIER).await?;
+        let groth16_verifier = self
+            .deploy(GROTH16_VERIFIER, groth16_class, &[universal_ecip])
+            .await?;
+
+        let store_class = self.declare(STORE).await?;
+        let store = self.deploy(STORE, store_class, &[owner]).await?;
+
+        let fossil_verifier_class = self.declare(FOSSIL_VERIFIER).await?;
+        let method_id = bindings::method_id(&self.config.method_id);
+        let fossil_verifier = self
+            .deploy(
+                FOSSIL_VERIFIER,
+                fossil_verifier_class,
+                &[
+                    groth16_verifier,
+                    store,
+                    owner,
+                    Felt::from(method_id.low()),
+                    Felt::from(method_id.high()),
+                    Felt::from(self.config.chain_id),
+                ],
+            )
+            .await?;
+
+        let l1_message_proxy_class = self.declare(L1_MESSAGE_PROXY).await?;
+        let l1_message_proxy = self
+            .deploy(
+                L1_MESSAGE_PROXY,
+                l1_message_proxy_class,
+                &[self.config.l1_message_sender, store],
+            )
+            .await?;
+
+        let initialize = FossilStore::new(provider.clone(), store).initialize(
+            fossil_verifier,
+            l1_message_proxy,
+            self.config.min_update_interval,
+        )?;
+        let outcome = self.account.execute(vec![initialize]).await?;
+        if outcome
+            .revert_reason()
+            .is_some_and(|reason| reason.contains("already initialized"))
+        {
+            // The store has no getter for its settings, so they can't be compared
+            if !self.config.resume_initialized_store {
+                return Err(eyre!(
+                    "Store {} was initialized by an earlier run, whose minimum update \
+                     interval may differ from {}. Pass --resume-initialized-store with the \
+                     interval that run used to accept it.",
+                    store.to_fixed_hex_string(),
+                    self.config.min_update_interval
+                ));
+            }
+            warn!(
+                min_update_interval = self.config.min_update_interval,
+                "Store was initialized by an earlier run, assuming the same settings"
+            );
+        } else {
+            outcome
+                .into_result()
+                .wrap_err("Failed to initialize the store")?;
+            info!(
+                min_update_interval = self.config.min_update_interval,
+                "Store initialized"
+            );
+        }
+
+        let chain_id = parse_cairo_short_string(&provider.chain_id().await?)?;
+        Ok(Deployment {
+            chain_id,
+            block_number: provider.block_number().await?,
+            store: store.to_fixed_hex_string(),
+            fossil_verifier: fossil_verifier.to_fixed_hex_string(),
+            groth16_verifier: groth16_verifier.to_fixed_hex_string(),
+            l1_message_proxy: l1_message_proxy.to_fixed_hex_string(),
+            min_update_interval: self.config.min_update_interval,
+            class_hashes: ClassHashes {
+                store: store_class.to_fixed_hex_string(),
+                fossil_verifier: fossil_verifier_class.to_fixed_hex_string(),
+                groth16_verifier: groth16_class.to_fixed_hex_string(),
+                universal_ecip: universal_ecip.to_fixed_hex_string(),
+                l1_message_proxy: l1_message_proxy_class.to_fixed_hex_string(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACTS: &[(&str, &str)] = &[
+        (
+            STORE,
+            include_str!("../../../contracts/starknet/store/src/lib.cairo"),
+        ),
+        (
+            FOSSIL_VERIFIER,
+            include_str!("../../../contracts/starknet/verifier/src/fossil_verifier.cairo"),
+        ),
+        (
+            GROTH16_VERIFIER,
+            include_str!("../../../contracts/starknet/verifier/src/groth16_verifier.cairo"),
+        ),
+        (
+            L1_MESSAGE_PROXY,
+            include_str!("../../../contracts/starknet/l1_message_proxy/src/lib.cairo"),
+        ),
+    ];
+
+    /// Parameters of the constructor in `source`, besides `self`
+    fn constructor_params(source: &str) -> usize {
+        let start = source.find("fn constructor(").unwrap() + "fn constructor(".len();
+        let end = start + source[start..].find(')').unwrap();
+        source[start..end]
+            .split(',')
+            .filter(|param| !param.trim().is_empty() && !param.contains("self"))
+            .count()
+    }
+
+    #[test]
+    fn test_constructors_match_sources() {
+        // Parameters `run` passes arguments for
+        let arguments = [
+            (STORE, 1),
+            (FOSSIL_VERIFIER, 5),
+            (GROTH16_VERIFIER, 1),
+            (L1_MESSAGE_PROXY, 2),
+        ];
+        for (name, count) in arguments {
+            let (_, source) = CONTRACTS.iter().find(|(n, _)| *n == name).unwrap();
+            assert_eq!(constructor_params(source), count, "{}", name);
+
+            // The artifact is named after the contract module
+            let module = name.rsplit('_').next().unwrap();
+            assert!(source.contains(&format!("mod {} {{", module)), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_udc_deploy() {
+        let class_hash = Felt::from(0x1234u64);
+        let salt = Felt::ONE;
+        let calldata = [Felt::from(7u64), Felt::from(8u64)];
+
+        let (call, address) = udc_deploy(class_hash, salt, &calldata);
+        assert_eq!(call.to, UDC_ADDRESS);
+        assert_eq!(call.selector, selector!("deployContract"));
+        assert_eq!(
+            call.calldata,
+            vec![
+                class_hash,
+                salt,
+                // Not unique to the caller
+                Felt::ZERO,
+                Felt::TWO,
+                Felt::from(7u64),
+                Felt::from(8u64),
+            ]
+        );
+
+        // The address is deterministic and depends on every argument
+        assert_eq!(udc_deploy(class_hash, salt, &calldata).1, address);
+        assert_ne!(udc_deploy(class_hash, Felt::TWO, &calldata).1, address);
+        assert_ne!(udc_deploy(class_hash, salt, &calldata[..1]).1, address);
+        assert_ne!(udc_deploy(Felt::ONE, salt, &calldata).1, address);
+    }
+
+    #[test]
+    fn test_parse_method_id() {
+        let words = [
+            0x11111111, 0x22222222, 0x33333333, 0x44444444, 0x55555555, 0x66666666, 0x77777777,
+            0x88888888,
+        ];
+        let hex = "1111111122222222333333334444444455555555666666667777777788888888";
+        assert_eq!(parse_method_id(hex).unwrap(), words);
+        assert_eq!(parse_method_id(&format!("0x{}", hex)).unwrap(), words);
+
+        assert!(parse_method_id(&hex[1..]).is_err());
+        assert!(parse_method_id(&hex.replace('8', "g")).is_err());
+    }
+
+    #[test]
+    fn test_load_class_missing_artifacts() {
+        let err = load_class(Path::new("/nonexistent"), STORE).unwrap_err();
+        assert!(err.to_string().contains("scarb build"), "{}", err);
+    }
+}
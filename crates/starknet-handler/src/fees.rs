@@ -0,0 +1,330 @@
+//! Fee limits and nonce sequencing for transactions sent by [`crate::account::StarknetAccount`].
+//!
+//! Every transaction is estimated first. The estimated gas amounts and prices are
+//! scaled by [`FeeConfig`]'s multipliers to absorb price moves between estimation and
+//! inclusion, and the resulting maximum fee is checked against a hard cap before
+//! anything is signed. Nonces come from a [`NonceManager`] rather than the node, so
+//! several submissions from one account can be in flight at once.
+use crate::parse_env;
+use eyre::{eyre, Result};
+use starknet::core::types::FeeEstimate;
+use starknet_crypto::Felt;
+use std::future::Future;
+use std::sync::Mutex;
+
+pub const DEFAULT_GAS_MULTIPLIER: f64 = 1.5;
+pub const DEFAULT_GAS_PRICE_MULTIPLIER: f64 = 1.5;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FeeConfig {
+    l1_gas_multiplier: f64,
+    l2_gas_multiplier: f64,
+    gas_price_multiplier: f64,
+    max_fee: Option<u128>,
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            l1_gas_multiplier: DEFAULT_GAS_MULTIPLIER,
+            l2_gas_multiplier: DEFAULT_GAS_MULTIPLIER,
+            gas_price_multiplier: DEFAULT_GAS_PRICE_MULTIPLIER,
+            max_fee: None,
+        }
+    }
+}
+
+impl FeeConfig {
+    /// Multipliers apply to the estimated amounts (L1 data gas counts as L1 gas) and
+    /// to all estimated prices. `max_fee`, in FRI, caps the most a transaction may
+    /// cost once scaled.
+    pub fn new(
+        l1_gas_multiplier: f64,
+        l2_gas_multiplier: f64,
+        gas_price_multiplier: f64,
+        max_fee: Option<u128>,
+    ) -> Result<Self> {
+        for (name, multiplier) in [
+            ("L1 gas", l1_gas_multiplier),
+            ("L2 gas", l2_gas_multiplier),
+            ("gas price", gas_price_multiplier),
+        ] {
+            if !multiplier.is_finite() || multiplier < 1.0 {
+                return Err(eyre!(
+                    "The {} multiplier must be at least 1: {}",
+                    name,
+                    multiplier
+                ));
+            }
+        }
+
+        Ok(Self {
+            l1_gas_multiplier,
+            l2_gas_multiplier,
+            gas_price_multiplier,
+            max_fee,
+        })
+    }
+
+    /// Reads `STARKNET_L1_GAS_MULTIPLIER`, `STARKNET_L2_GAS_MULTIPLIER`,
+    /// `STARKNET_GAS_PRICE_MULTIPLIER` and `STARKNET_MAX_FEE`
+    pub fn from_env() -> Result<Self> {
+        Self::new(
+            parse_env("STARKNET_L1_GAS_MULTIPLIER")?.unwrap_or(DEFAULT_GAS_MULTIPLIER),
+            parse_env("STARKNET_L2_GAS_MULTIPLIER")?.unwrap_or(DEFAULT_GAS_MULTIPLIER),
+            parse_env("STARKNET_GAS_PRICE_MULTIPLIER")?.unwrap_or(DEFAULT_GAS_PRICE_MULTIPLIER),
+            parse_env("STARKNET_MAX_FEE")?,
+        )
+    }
+
+    pub const fn max_fee(&self) -> Option<u128> {
+        self.max_fee
+    }
+
+    /// Scales `estimate` into the resource bounds to sign, failing if they could cost
+    /// more than the cap
+    pub fn bounds(&self, estimate: &GasEstimate) -> Result<GasBounds> {
+        let bounds = GasBounds {
+            l1_gas: scale_amount(estimate.l1_gas, self.l1_gas_multiplier),
+            l1_gas_price: scale_price(estimate.l1_gas_price, self.gas_price_multiplier),
+            l2_gas: scale_amount(estimate.l2_gas, self.l2_gas_multiplier),
+            l2_gas_price: scale_price(estimate.l2_gas_price, self.gas_price_multiplier),
+            l1_data_gas: scale_amount(estimate.l1_data_gas, self.l1_gas_multiplier),
+            l1_data_gas_price: scale_price(estimate.l1_data_gas_price, self.gas_price_multiplier),
+        };
+
+        let max_fee = bounds
+            .max_fee()
+            .ok_or_else(|| eyre!("Maximum fee overflows: {:?}", bounds))?;
+        if let Some(cap) = self.max_fee {
+            if max_fee > cap {
+                return Err(eyre!(
+                    "Transaction may cost up to {} FRI (estimated {}), above the cap of {}",
+                    max_fee,
+                    estimate.overall_fee,
+                    cap
+                ));
+            }
+        }
+        Ok(bounds)
+    }
+}
+
+fn scale_amount(amount: u64, multiplier: f64) -> u64 {
+    (amount as f64 * multiplier).ceil() as u64
+}
+
+fn scale_price(price: u128, multiplier: f64) -> u128 {
+    (price as f64 * multiplier).ceil() as u128
+}
+
+/// What fee estimation returned for a transaction
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GasEstimate {
+    pub l1_gas: u64,
+    pub l1_gas_price: u128,
+    pub l2_gas: u64,
+    pub l2_gas_price: u128,
+    pub l1_data_gas: u64,
+    pub l1_data_gas_price: u128,
+    pub overall_fee: u128,
+}
+
+impl From<&FeeEstimate> for GasEstimate {
+    fn from(estimate: &FeeEstimate) -> Self {
+        Self {
+            l1_gas: estimate.l1_gas_consumed,
+            l1_gas_price: estimate.l1_gas_price,
+            l2_gas: estimate.l2_gas_consumed,
+            l2_gas_price: estimate.l2_gas_price,
+            l1_data_gas: estimate.l1_data_gas_consumed,
+            l1_data_gas_price: estimate.l1_data_gas_price,
+            overall_fee: estimate.overall_fee,
+        }
+    }
+}
+
+/// Resource bounds of a v3 transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GasBounds {
+    pub l1_gas: u64,
+    pub l1_gas_price: u128,
+    pub l2_gas: u64,
+    pub l2_gas_price: u128,
+    pub l1_data_gas: u64,
+    pub l1_data_gas_price: u128,
+}
+
+impl GasBounds {
+    /// The most the transaction can be charged, `None` on overflow
+    pub fn max_fee(&self) -> Option<u128> {
+        let l1 = (self.l1_gas as u128).checked_mul(self.l1_gas_price)?;
+        let l2 = (self.l2_gas as u128).checked_mul(self.l2_gas_price)?;
+        let data = (self.l1_data_gas as u128).checked_mul(self.l1_data_gas_price)?;
+        l1.checked_add(l2)?.checked_add(data)
+    }
+}
+
+/// Hands out consecutive nonces for one account. The first nonce is read from the
+/// chain; after that the manager counts locally until a submission fails.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    next: tokio::sync::Mutex<Option<Felt>>,
+}
+
+impl NonceManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserves the next nonce, calling `fetch` for the on-chain nonce if none is
+    /// known yet
+    pub async fn reserve<F, Fut, E>(&self, fetch: F) -> Result<Felt>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Felt, E>>,
+        E: Into<eyre::Report>,
+    {
+        let mut next = self.next.lock().await;
+        let nonce = match *next {
+            Some(nonce) => nonce,
+            None => fetch().await.map_err(Into::into)?,
+        };
+        *next = Some(nonce + Felt::ONE);
+        Ok(nonce)
+    }
+
+    /// Gives back a nonce whose transaction was never accepted. If later nonces were
+    /// handed out meanwhile, the manager forgets what it knows and asks the chain
+    /// again, since the node will not accept anything past the gap.
+    pub async fn release(&self, nonce: Felt) {
+        let mut next = self.next.lock().await;
+        *next = match *next {
+            Some(expected) if expected == nonce + Felt::ONE => Some(nonce),
+            _ => None,
+        };
+    }
+
+    /// Forgets the local count, so the next nonce is read from the chain
+    pub async fn reset(&self) {
+        *self.next.lock().await = None;
+    }
+}
+
+/// Running totals of what an account spent
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeTotals {
+    pub transactions: u64,
+    pub reverted: u64,
+    /// In FRI
+    pub fees_spent: u128,
+}
+
+#[derive(Debug, Default)]
+pub struct FeeMetrics {
+    totals: Mutex<FeeTotals>,
+}
+
+impl FeeMetrics {
+    /// Records a transaction that was included, whether or not it reverted
+    pub fn record(&self, fee: u128, reverted: bool) -> FeeTotals {
+        let mut totals = self.totals.lock().unwrap_or_else(|e| e.into_inner());
+        totals.transactions += 1;
+        totals.reverted += reverted as u64;
+        totals.fees_spent = totals.fees_spent.saturating_add(fee);
+        *totals
+    }
+
+    pub fn totals(&self) -> FeeTotals {
+        *self.totals.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    fn estimate() -> GasEstimate {
+        GasEstimate {
+            l1_gas: 0,
+            l1_gas_price: 100,
+            l2_gas: 1_000_000,
+            l2_gas_price: 10,
+            l1_data_gas: 200,
+            l1_data_gas_price: 5,
+            overall_fee: 10_001_000,
+        }
+    }
+
+    #[test]
+    fn test_bounds_apply_multipliers() {
+        let config = FeeConfig::new(2.0, 1.5, 1.2, None).unwrap();
+        let bounds = config.bounds(&estimate()).unwrap();
+        assert_eq!(
+            bounds,
+            GasBounds {
+                l1_gas: 0,
+                l1_gas_price: 120,
+                l2_gas: 1_500_000,
+                l2_gas_price: 12,
+                l1_data_gas: 400,
+                l1_data_gas_price: 6,
+            }
+        );
+        assert_eq!(bounds.max_fee(), Some(18_002_400));
+    }
+
+    #[test]
+    fn test_bounds_respect_cap() {
+        let config = FeeConfig::new(1.0, 1.0, 1.0, Some(10_001_000)).unwrap();
+        assert!(config.bounds(&estimate()).is_ok());
+
+        let config = FeeConfig::new(1.0, 1.5, 1.0, Some(10_001_000)).unwrap();
+        let err = config.bounds(&estimate()).unwrap_err();
+        assert!(err.to_string().contains("above the cap of 10001000"));
+    }
+
+    #[test]
+    fn test_invalid_multipliers() {
+        assert!(FeeConfig::new(0.5, 1.0, 1.0, None).is_err());
+        assert!(FeeConfig::new(1.0, f64::NAN, 1.0, None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_nonce_manager_sequences_nonces() {
+        let nonces = NonceManager::new();
+        let fetch = || async { Ok::<_, Infallible>(Felt::from(7u64)) };
+
+        assert_eq!(nonces.reserve(fetch).await.unwrap(), Felt::from(7u64));
+        // Counted locally from now on
+        let unreachable = || async { Err::<Felt, _>(eyre!("node unreachable")) };
+        assert_eq!(nonces.reserve(unreachable).await.unwrap(), Felt::from(8u64));
+
+        // Releasing the latest nonce hands it out again
+        nonces.release(Felt::from(8u64)).await;
+        assert_eq!(nonces.reserve(unreachable).await.unwrap(), Felt::from(8u64));
+
+        // Releasing an older one leaves a gap, so the chain is asked again
+        nonces.reserve(unreachable).await.unwrap();
+        nonces.release(Felt::from(8u64)).await;
+        assert!(nonces.reserve(unreachable).await.is_err());
+        assert_eq!(nonces.reserve(fetch).await.unwrap(), Felt::from(7u64));
+    }
+
+    #[test]
+    fn test_fee_metrics() {
+        let metrics = FeeMetrics::default();
+        metrics.record(100, false);
+        let totals = metrics.record(50, true);
+        assert_eq!(
+            totals,
+            FeeTotals {
+                transactions: 2,
+                reverted: 1,
+                fees_spent: 150,
+            }
+        );
+        assert_eq!(metrics.totals(), totals);
+    }
+}
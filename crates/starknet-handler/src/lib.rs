@@ -1,8 +1,18 @@
 #![deny(unused_crate_dependencies)]
+#[cfg(feature = "cli")]
+use clap as _;
 
 use crypto_bigint::U256 as CryptoBigIntU256;
 pub mod account;
+pub mod bindings;
+pub mod cid;
+pub mod deploy;
+pub mod fees;
 pub mod provider;
+pub mod signer;
+pub mod transport;
+pub mod tx;
+use crate::cid::Cid;
 use eyre::{eyre, Result};
 use starknet::core::codec::{Decode, Encode};
 use starknet::core::types::{ByteArray, U256};
@@ -35,18 +45,24 @@ impl MmrSnapshot {
         self.leaves_count
     }
 
-    pub fn ipfs_hash(&self) -> ByteArray {
-        self.ipfs_hash.clone()
+    /// CID of the batch database, `None` until the batch is first published
+    pub fn ipfs_hash(&self) -> Result<Option<Cid>> {
+        let cid = String::try_from(self.ipfs_hash.clone())
+            .map_err(|_| eyre!("Invalid IPFS hash: {:?}", self.ipfs_hash))?;
+        if cid.is_empty() {
+            return Ok(None);
+        }
+        cid.parse().map(Some)
     }
 }
 
-#[derive(Clone, Debug, Encode, Decode)]
+#[derive(Clone, Debug)]
 pub struct MmrState {
     latest_mmr_block: u64,
     latest_mmr_block_hash: U256,
     root_hash: U256,
     leaves_count: u64,
-    ipfs_hash: Option<ByteArray>,
+    ipfs_hash: Option<Cid>,
 }
 
 impl MmrState {
@@ -56,7 +72,7 @@ impl MmrState {
         latest_mmr_block_hash: U256,
         root_hash: U256,
         leaves_count: u64,
-        ipfs_hash: Option<ByteArray>,
+        ipfs_hash: Option<Cid>,
     ) -> Self {
         debug!(latest_mmr_block, leaves_count, "Creating new MMR state");
         Self {
@@ -88,7 +104,7 @@ impl MmrState {
         self.leaves_count
     }
 
-    pub fn ipfs_hash(&self) -> Option<ByteArray> {
+    pub fn ipfs_hash(&self) -> Option<Cid> {
         self.ipfs_hash.clone()
     }
 }
@@ -114,6 +130,23 @@ pub fn u256_from_hex(hex: &str) -> Result<U256> {
     Ok(result)
 }
 
+/// Parses the environment variable `name`, treating unset and blank as `None`
+pub(crate) fn parse_env<T: std::str::FromStr>(name: &str) -> Result<Option<T>> {
+    match std::env::var(name) {
+        Ok(value) if !value.trim().is_empty() => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| eyre!("Invalid {}: {}", name, value)),
+        _ => Ok(None),
+    }
+}
+
+/// Formats `value` as 0x-prefixed, zero-padded hex, as accepted by [`u256_from_hex`]
+pub fn u256_to_hex(value: U256) -> String {
+    format!("0x{:032x}{:032x}", value.high(), value.low())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,6 +190,16 @@ mod tests {
         assert_eq!(result.to_string(), "1");
     }
 
+    #[test]
+    fn test_u256_to_hex() {
+        let hex = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        assert_eq!(u256_to_hex(u256_from_hex(hex).unwrap()), hex);
+        assert_eq!(
+            u256_to_hex(U256::from(1u64)),
+            "0x0000000000000000000000000000000000000000000000000000000000000001"
+        );
+    }
+
     #[test]
     fn test_mmr_state() {
         let block = 100u64;
@@ -167,7 +210,11 @@ mod tests {
             u256_from_hex("0000000000000000000000000000000000000000000000000000000000009876")
                 .unwrap();
         let leaves = 50u64;
-        let ipfs = Some(ByteArray::from("0x1234"));
+        let ipfs = Some(
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+                .parse()
+                .unwrap(),
+        );
 
         let state = MmrState::new(block, block_hash, root, leaves, ipfs.clone());
 
@@ -192,7 +239,7 @@ mod tests {
             )
             .unwrap(),
             leaves_count: 50,
-            ipfs_hash: ByteArray::from("0x1234"),
+            ipfs_hash: ByteArray::from("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
         };
 
         assert_eq!(
@@ -200,7 +247,23 @@ mod tests {
             u256_from_hex("0000000000000000000000000000000000000000000000000000000000009876")
                 .unwrap()
         );
-        assert_eq!(snapshot.ipfs_hash(), ByteArray::from("0x1234"));
+        assert_eq!(
+            snapshot.ipfs_hash().unwrap().unwrap().as_str(),
+            "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"
+        );
+
+        // Nothing published yet
+        let unpublished = MmrSnapshot {
+            ipfs_hash: ByteArray::from(""),
+            ..snapshot.clone()
+        };
+        assert_eq!(unpublished.ipfs_hash().unwrap(), None);
+
+        let malformed = MmrSnapshot {
+            ipfs_hash: ByteArray::from("0x1234"),
+            ..snapshot
+        };
+        assert!(malformed.ipfs_hash().is_err());
     }
 
     #[test]
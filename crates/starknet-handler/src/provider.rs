@@ -1,89 +1,161 @@
-use starknet::providers::Provider;
 use std::sync::Arc;
 use tracing::{debug, info, instrument, warn};
 
+use crate::bindings::{FossilStore, FossilVerifier};
+use crate::transport::FailoverTransport;
 use crate::MmrSnapshot;
-use eyre::Result;
-use starknet::macros::selector;
-use starknet::{
-    core::{
-        codec::Decode,
-        types::{BlockId, BlockTag, FunctionCall},
-    },
-    providers::{jsonrpc::HttpTransport, JsonRpcClient, Url},
-};
+use eyre::{eyre, Result};
+use starknet::core::types::{BlockId, BlockTag, U256};
+use starknet::providers::{JsonRpcClient, Provider};
 use starknet_crypto::Felt;
-#[derive(Debug)]
+
+/// The JSON-RPC client every Fossil component talks to Starknet through
+pub type RpcClient = JsonRpcClient<FailoverTransport>;
+
+const LATEST: BlockId = BlockId::Tag(BlockTag::Latest);
+
+/// Reads the Fossil contracts. Getters take an optional [`BlockId`] and read the
+/// latest block when given `None`; use [`StarknetProvider::snapshot`] to pin a
+/// sequence of reads to one block.
+///
+/// Cloning is cheap and clones share one [`FailoverTransport`], so create a single
+/// provider and pass it around to keep rate limits and endpoint health in one place.
+#[derive(Clone, Debug)]
 pub struct StarknetProvider {
-    provider: Arc<JsonRpcClient<HttpTransport>>,
+    provider: Arc<RpcClient>,
+    transport: FailoverTransport,
     rpc_url: String,
 }
 
 impl StarknetProvider {
+    /// `rpc_url` may list several endpoints separated by commas, tried in order.
+    /// See [`FailoverTransport::from_env`] for the settings read from the environment.
     #[instrument(level = "debug", fields(rpc_url = %rpc_url))]
     pub fn new(rpc_url: &str) -> Result<Self> {
         debug!("Initializing StarknetProvider");
 
-        let parsed_url = Url::parse(rpc_url)?;
-        debug!("Parsed RPC URL successfully");
+        let transport = FailoverTransport::from_env(rpc_url)?;
+        debug!(
+            endpoints = transport.endpoints().len(),
+            "Parsed RPC URLs successfully"
+        );
 
         Ok(Self {
-            provider: Arc::new(JsonRpcClient::new(HttpTransport::new(parsed_url))),
             rpc_url: rpc_url.to_string(),
+            ..Self::with_transport(transport)
         })
     }
 
+    pub fn with_transport(transport: FailoverTransport) -> Self {
+        let rpc_url = transport
+            .endpoints()
+            .iter()
+            .map(|endpoint| endpoint.url().as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        Self {
+            provider: Arc::new(JsonRpcClient::new(transport.clone())),
+            transport,
+            rpc_url,
+        }
+    }
+
+    /// The configured endpoints, comma separated
     pub fn rpc_url(&self) -> &str {
         &self.rpc_url
     }
 
-    pub fn provider(&self) -> Arc<JsonRpcClient<HttpTransport>> {
+    pub fn provider(&self) -> Arc<RpcClient> {
         self.provider.clone()
     }
 
+    pub const fn transport(&self) -> &FailoverTransport {
+        &self.transport
+    }
+
+    /// Checks every endpoint, failing only if none of them is reachable
     #[instrument(skip(self), level = "debug")]
-    pub async fn get_latest_mmr_block(&self, l2_store_address: &str) -> Result<u64> {
+    pub async fn check_health(&self) -> Result<()> {
+        let mut healthy = 0;
+        for (url, status) in self.transport.check_health().await {
+            match status {
+                Ok(block_number) => {
+                    healthy += 1;
+                    info!(url = %url, block_number, "RPC endpoint healthy");
+                }
+                Err(e) => warn!(url = %url, error = %e, "RPC endpoint unhealthy"),
+            }
+        }
+
+        if healthy == 0 {
+            return Err(eyre!("No RPC endpoint is reachable: {}", self.rpc_url));
+        }
+        Ok(())
+    }
+
+    /// Bindings for the store contract at `l2_store_address`
+    pub fn store(&self, l2_store_address: &str) -> Result<FossilStore<RpcClient>> {
+        Ok(FossilStore::new(
+            self.provider.clone(),
+            Felt::from_hex(l2_store_address)?,
+        ))
+    }
+
+    /// Bindings for the verifier contract at `verifier_address`
+    pub fn verifier(&self, verifier_address: &str) -> Result<FossilVerifier<RpcClient>> {
+        Ok(FossilVerifier::new(
+            self.provider.clone(),
+            Felt::from_hex(verifier_address)?,
+        ))
+    }
+
+    /// Pins the current L2 block, so that every read through the snapshot sees the
+    /// same state. The block is pinned by hash: if it is reorged away, reads fail
+    /// instead of silently moving to another block.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn snapshot(&self) -> Result<StarknetSnapshot> {
+        let block = self.provider.block_hash_and_number().await?;
+        debug!(block_number = block.block_number, block_hash = ?block.block_hash, "Pinned L2 block");
+
+        Ok(StarknetSnapshot {
+            provider: self.clone(),
+            block_number: block.block_number,
+            block_hash: block.block_hash,
+        })
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_latest_mmr_block(
+        &self,
+        l2_store_address: &str,
+        block_id: Option<BlockId>,
+    ) -> Result<u64> {
         debug!("Fetching latest MMR block");
 
-        let entry_point_selector = selector!("get_latest_mmr_block");
-
-        let data = self
-            .provider
-            .call(
-                FunctionCall {
-                    contract_address: Felt::from_hex(l2_store_address)?,
-                    entry_point_selector,
-                    calldata: vec![],
-                },
-                BlockId::Tag(BlockTag::Latest),
-            )
+        let mmr_block = self
+            .store(l2_store_address)?
+            .at(block_id.unwrap_or(LATEST))
+            .get_latest_mmr_block()
             .await?;
-
-        let mmr_block = u64::decode(&data)?;
         info!(mmr_block, "Retrieved latest MMR block");
 
         Ok(mmr_block)
     }
 
     #[instrument(skip(self), level = "debug")]
-    pub async fn get_min_mmr_block(&self, l2_store_address: &str) -> Result<u64> {
+    pub async fn get_min_mmr_block(
+        &self,
+        l2_store_address: &str,
+        block_id: Option<BlockId>,
+    ) -> Result<u64> {
         debug!("Fetching min MMR block");
 
-        let entry_point_selector = selector!("get_min_mmr_block");
-
-        let data = self
-            .provider
-            .call(
-                FunctionCall {
-                    contract_address: Felt::from_hex(l2_store_address)?,
-                    entry_point_selector,
-                    calldata: vec![],
-                },
-                BlockId::Tag(BlockTag::Latest),
-            )
+        let min_mmr_block = self
+            .store(l2_store_address)?
+            .at(block_id.unwrap_or(LATEST))
+            .get_min_mmr_block()
             .await?;
-
-        let min_mmr_block = u64::decode(&data)?;
         info!(min_mmr_block, "Retrieved minimum MMR block");
 
         Ok(min_mmr_block)
@@ -94,52 +166,96 @@ impl StarknetProvider {
         &self,
         l2_store_address: &str,
         batch_index: u64,
+        block_id: Option<BlockId>,
     ) -> Result<MmrSnapshot> {
         debug!(batch_index, "Fetching MMR state");
 
-        let entry_point_selector = selector!("get_mmr_state");
-
-        let data = self
-            .provider
-            .call(
-                FunctionCall {
-                    contract_address: Felt::from_hex(l2_store_address)?,
-                    entry_point_selector,
-                    calldata: vec![Felt::from(batch_index)],
-                },
-                BlockId::Tag(BlockTag::Latest),
-            )
+        let mmr_state = self
+            .store(l2_store_address)?
+            .at(block_id.unwrap_or(LATEST))
+            .get_mmr_state(batch_index)
             .await?;
-
-        let mmr_state = MmrSnapshot::decode(&data)?;
         info!("Retrieved On-chain MMR state");
 
         Ok(mmr_state)
     }
 
+    /// The latest L1 block relayed to the store, as `(block_number, block_hash)`
     #[instrument(skip(self), level = "debug")]
-    pub async fn get_latest_relayed_block(&self, l2_store_address: &str) -> Result<u64> {
+    pub async fn get_latest_relayed_block(
+        &self,
+        l2_store_address: &str,
+        block_id: Option<BlockId>,
+    ) -> Result<(u64, U256)> {
         debug!("Fetching latest relayed block");
 
-        let entry_point_selector = selector!("get_latest_blockhash_from_l1");
-
-        let data = self
-            .provider
-            .call(
-                FunctionCall {
-                    contract_address: Felt::from_hex(l2_store_address)?,
-                    entry_point_selector,
-                    calldata: vec![],
-                },
-                BlockId::Tag(BlockTag::Latest),
-            )
+        let (block_number, block_hash) = self
+            .store(l2_store_address)?
+            .at(block_id.unwrap_or(LATEST))
+            .get_latest_blockhash_from_l1()
             .await?;
+        info!(block_number, block_hash = ?block_hash, "Retrieved latest relayed block");
+
+        Ok((block_number, block_hash))
+    }
+}
+
+/// A [`StarknetProvider`] whose reads are all made at one L2 block
+#[derive(Clone, Debug)]
+pub struct StarknetSnapshot {
+    provider: StarknetProvider,
+    block_number: u64,
+    block_hash: Felt,
+}
+
+impl StarknetSnapshot {
+    pub const fn block_number(&self) -> u64 {
+        self.block_number
+    }
 
-        let block_number =
-            u64::from_str_radix(data[0].to_hex_string().trim_start_matches("0x"), 16)?;
-        info!(block_number, "Retrieved latest relayed block");
+    pub const fn block_hash(&self) -> Felt {
+        self.block_hash
+    }
+
+    pub const fn block_id(&self) -> BlockId {
+        BlockId::Hash(self.block_hash)
+    }
+
+    pub fn provider(&self) -> &StarknetProvider {
+        &self.provider
+    }
 
-        Ok(block_number)
+    /// Bindings for the store contract, pinned to the snapshot's block
+    pub fn store(&self, l2_store_address: &str) -> Result<FossilStore<RpcClient>> {
+        Ok(self.provider.store(l2_store_address)?.at(self.block_id()))
+    }
+
+    pub async fn get_latest_mmr_block(&self, l2_store_address: &str) -> Result<u64> {
+        self.provider
+            .get_latest_mmr_block(l2_store_address, Some(self.block_id()))
+            .await
+    }
+
+    pub async fn get_min_mmr_block(&self, l2_store_address: &str) -> Result<u64> {
+        self.provider
+            .get_min_mmr_block(l2_store_address, Some(self.block_id()))
+            .await
+    }
+
+    pub async fn get_mmr_state(
+        &self,
+        l2_store_address: &str,
+        batch_index: u64,
+    ) -> Result<MmrSnapshot> {
+        self.provider
+            .get_mmr_state(l2_store_address, batch_index, Some(self.block_id()))
+            .await
+    }
+
+    pub async fn get_latest_relayed_block(&self, l2_store_address: &str) -> Result<(u64, U256)> {
+        self.provider
+            .get_latest_relayed_block(l2_store_address, Some(self.block_id()))
+            .await
     }
 }
 
@@ -149,6 +265,8 @@ mod tests {
     use mockall::mock;
     use mockall::predicate;
     use mockall::predicate::*;
+    use starknet::core::types::FunctionCall;
+    use starknet::macros::selector;
     // use std::str::FromStr;
 
     #[test]
@@ -177,6 +295,43 @@ mod tests {
         assert!(Arc::strong_count(&provider.provider()) >= 1);
     }
 
+    #[test]
+    fn test_provider_with_several_endpoints() {
+        let provider =
+            StarknetProvider::new("http://localhost:5050, http://localhost:5051").unwrap();
+        let urls: Vec<_> = provider
+            .transport()
+            .endpoints()
+            .iter()
+            .map(|endpoint| endpoint.url().as_str())
+            .collect();
+        assert_eq!(urls, ["http://localhost:5050/", "http://localhost:5051/"]);
+
+        // Clones share the transport, and so its rate limits and health
+        let clone = provider.clone();
+        assert!(std::ptr::eq(
+            provider.transport().endpoints(),
+            clone.transport().endpoints()
+        ));
+    }
+
+    #[test]
+    fn test_snapshot_pins_store() {
+        let snapshot = StarknetSnapshot {
+            provider: StarknetProvider::new("http://localhost:5050").unwrap(),
+            block_number: 42,
+            block_hash: Felt::from(0xabcu64),
+        };
+
+        assert_eq!(snapshot.block_id(), BlockId::Hash(Felt::from(0xabcu64)));
+        let store = snapshot.store("0x123").unwrap();
+        assert_eq!(store.block_id(), snapshot.block_id());
+        assert_eq!(
+            snapshot.provider().store("0x123").unwrap().block_id(),
+            LATEST
+        );
+    }
+
     mock! {
         Provider {
             fn call(
@@ -266,8 +421,11 @@ mod tests {
                 predicate::eq(BlockId::Tag(BlockTag::Latest)),
             )
             .return_once(move |_, _| {
+                // (u64, u256): block number, then the hash's low and high halves
                 Ok(vec![
-                    Felt::from_hex(&format!("{:x}", expected_block)).unwrap()
+                    Felt::from(expected_block),
+                    Felt::from(1u64),
+                    Felt::ZERO,
                 ])
             });
 
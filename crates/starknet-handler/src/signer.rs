@@ -0,0 +1,179 @@
+//! Signers for [`crate::account::StarknetAccount`], built from a [`KeySource`]
+use async_trait::async_trait;
+use common::felt;
+use common::signer::{KeySource, RemoteSigner};
+use eyre::{eyre, Result};
+use starknet::core::crypto::Signature;
+use starknet::signers::{LocalWallet, Signer, SigningKey, VerifyingKey};
+use starknet_crypto::Felt;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum StarknetSigner {
+    Local(LocalWallet),
+    Remote(RemoteSigner),
+}
+
+impl StarknetSigner {
+    pub fn from_source(source: &KeySource) -> Result<Self> {
+        match source {
+            KeySource::Local(private_key) => Ok(Self::Local(LocalWallet::from(
+                SigningKey::from_secret_scalar(felt(private_key)?),
+            ))),
+            KeySource::Remote(config) => Ok(Self::Remote(RemoteSigner::new(config.clone())?)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SignerError(String);
+
+impl fmt::Display for SignerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SignerError {}
+
+impl SignerError {
+    fn new(error: impl fmt::Display) -> Self {
+        Self(format!("{:#}", error))
+    }
+}
+
+/// Parses the `[r, s]` returned by a remote signer
+fn parse_signature(components: &[String]) -> Result<Signature> {
+    match components {
+        [r, s] => Ok(Signature {
+            r: felt(r)?,
+            s: felt(s)?,
+        }),
+        _ => Err(eyre!(
+            "Expected a signature of 2 felts, got {}",
+            components.len()
+        )),
+    }
+}
+
+#[async_trait]
+impl Signer for StarknetSigner {
+    type GetPublicKeyError = SignerError;
+    type SignError = SignerError;
+
+    async fn get_public_key(&self) -> Result<VerifyingKey, Self::GetPublicKeyError> {
+        match self {
+            Self::Local(wallet) => wallet.get_public_key().await.map_err(SignerError::new),
+            Self::Remote(signer) => {
+                let public_key = signer.public_key().await.map_err(SignerError::new)?;
+                Ok(VerifyingKey::from_scalar(
+                    felt(&public_key).map_err(SignerError::new)?,
+                ))
+            }
+        }
+    }
+
+    async fn sign_hash(&self, hash: &Felt) -> Result<Signature, Self::SignError> {
+        match self {
+            Self::Local(wallet) => wallet.sign_hash(hash).await.map_err(SignerError::new),
+            Self::Remote(signer) => {
+                let components = signer
+                    .sign(&hash.to_hex_string())
+                    .await
+                    .map_err(SignerError::new)?;
+                let signature = parse_signature(&components).map_err(SignerError::new)?;
+
+                // A signature the account would reject is only found out once the
+                // transaction fails validation, so check it here
+                let public_key = self.get_public_key().await?;
+                if !public_key
+                    .verify(hash, &signature)
+                    .map_err(SignerError::new)?
+                {
+                    return Err(SignerError::new(format!(
+                        "Remote signer signature does not verify against its public key {}",
+                        public_key.scalar().to_hex_string()
+                    )));
+                }
+                Ok(signature)
+            }
+        }
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::signer::{mock, RemoteSignerConfig};
+
+    #[tokio::test]
+    async fn test_remote_signer_matches_local_key() {
+        let key = SigningKey::from_secret_scalar(Felt::from(0x1234u64));
+        let public_key = key.verifying_key().scalar();
+
+        let url = mock::serve(public_key.to_hex_string(), None, move |hash| {
+            let signature = key.sign(&Felt::from_hex(hash).unwrap()).unwrap();
+            vec![signature.r.to_hex_string(), signature.s.to_hex_string()]
+        })
+        .await;
+        let signer = StarknetSigner::from_source(&KeySource::Remote(RemoteSignerConfig {
+            url,
+            token: None,
+        }))
+        .unwrap();
+
+        let hash = Felt::from(0xabcdu64);
+        let verifying_key = signer.get_public_key().await.unwrap();
+        assert_eq!(verifying_key.scalar(), public_key);
+        let signature = signer.sign_hash(&hash).await.unwrap();
+        assert!(verifying_key.verify(&hash, &signature).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_with_wrong_key() {
+        let key = SigningKey::from_secret_scalar(Felt::from(0x1234u64));
+        let other_key = SigningKey::from_secret_scalar(Felt::from(0x5678u64));
+
+        let url = mock::serve(
+            other_key.verifying_key().scalar().to_hex_string(),
+            None,
+            move |hash| {
+                let signature = key.sign(&Felt::from_hex(hash).unwrap()).unwrap();
+                vec![signature.r.to_hex_string(), signature.s.to_hex_string()]
+            },
+        )
+        .await;
+        let signer = StarknetSigner::from_source(&KeySource::Remote(RemoteSignerConfig {
+            url,
+            token: None,
+        }))
+        .unwrap();
+
+        let err = signer.sign_hash(&Felt::from(0xabcdu64)).await.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("does not verify against its public key"));
+    }
+
+    #[tokio::test]
+    async fn test_local_signer() {
+        let signer = StarknetSigner::from_source(&KeySource::Local("0x1234".to_string())).unwrap();
+        let hash = Felt::from(0xabcdu64);
+        let signature = signer.sign_hash(&hash).await.unwrap();
+        let verifying_key = signer.get_public_key().await.unwrap();
+        assert!(verifying_key.verify(&hash, &signature).unwrap());
+
+        assert!(StarknetSigner::from_source(&KeySource::Local("not a key".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_parse_signature() {
+        let signature = parse_signature(&["0x1".to_string(), "0x2".to_string()]).unwrap();
+        assert_eq!((signature.r, signature.s), (Felt::ONE, Felt::TWO));
+        assert!(parse_signature(&["0x1".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,588 @@
+//! JSON-RPC transport spreading requests over several Starknet endpoints.
+//!
+//! Endpoints are tried in the order they are configured. One that errors or times out
+//! is marked unhealthy for a cooldown and only tried after the healthy ones, so a
+//! flaky or rate-limiting provider is skipped rather than retried on every request.
+//! Each endpoint can be limited to a number of requests per second, which is how
+//! large backfills stay under a provider's quota.
+//!
+//! A read answered with "block not found" is also tried on the next endpoint, since
+//! the first may just lag behind. Writes are only sent to another endpoint when the
+//! previous one certainly never received them: resending a transaction that did
+//! arrive would submit it twice.
+use crate::parse_env;
+use async_trait::async_trait;
+use eyre::{eyre, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use starknet::providers::jsonrpc::{
+    HttpTransport, HttpTransportError, JsonRpcMethod, JsonRpcResponse, JsonRpcTransport,
+};
+use starknet::providers::{ProviderRequestData, Url};
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a failed endpoint is passed over
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// JSON-RPC error code of `BLOCK_NOT_FOUND`
+const BLOCK_NOT_FOUND: i64 = 24;
+
+/// One RPC endpoint with its rate limit and health
+#[derive(Debug)]
+pub struct Endpoint {
+    url: Url,
+    transport: HttpTransport,
+    min_interval: Option<Duration>,
+    next_slot: Mutex<Option<Instant>>,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl Endpoint {
+    pub fn new(url: Url) -> Self {
+        Self {
+            transport: HttpTransport::new(url.clone()),
+            url,
+            min_interval: None,
+            next_slot: Mutex::new(None),
+            unhealthy_until: Mutex::new(None),
+        }
+    }
+
+    /// Sends at most `requests_per_second` requests to this endpoint, 0 meaning
+    /// unlimited
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.min_interval =
+            (requests_per_second > 0).then(|| Duration::from_secs(1) / requests_per_second);
+        self
+    }
+
+    pub const fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        let unhealthy_until = self
+            .unhealthy_until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        unhealthy_until.is_none_or(|until| Instant::now() >= until)
+    }
+
+    fn mark_healthy(&self) {
+        let mut unhealthy_until = self
+            .unhealthy_until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if unhealthy_until.take().is_some() {
+            info!(url = %self.url, "RPC endpoint recovered");
+        }
+    }
+
+    fn mark_unhealthy(&self, cooldown: Duration) {
+        *self
+            .unhealthy_until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(Instant::now() + cooldown);
+    }
+
+    /// Waits until the rate limit allows another request
+    async fn wait_for_slot(&self) {
+        let Some(min_interval) = self.min_interval else {
+            return;
+        };
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+            let slot = next_slot.map_or(Instant::now(), |next| next.max(Instant::now()));
+            *next_slot = Some(slot + min_interval);
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Returned when no endpoint answered a request
+#[derive(Debug)]
+pub struct FailoverError {
+    errors: Vec<String>,
+}
+
+impl fmt::Display for FailoverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "All RPC endpoints failed: {}", self.errors.join("; "))
+    }
+}
+
+impl std::error::Error for FailoverError {}
+
+/// A request an endpoint did not answer
+struct Attempt {
+    error: String,
+    /// Whether the endpoint may have received the request anyway
+    delivered: bool,
+}
+
+/// Cheap to clone; clones share endpoints, and so rate limits and health
+#[derive(Clone, Debug)]
+pub struct FailoverTransport {
+    endpoints: Arc<[Endpoint]>,
+    timeout: Duration,
+    cooldown: Duration,
+}
+
+impl FailoverTransport {
+    pub fn new(endpoints: Vec<Endpoint>) -> Result<Self> {
+        if endpoints.is_empty() {
+            return Err(eyre!("At least one RPC endpoint is required"));
+        }
+
+        Ok(Self {
+            endpoints: endpoints.into(),
+            timeout: DEFAULT_TIMEOUT,
+            cooldown: DEFAULT_COOLDOWN,
+        })
+    }
+
+    /// Builds a transport for the comma separated `rpc_urls`, tuned by
+    /// `STARKNET_RPC_RATE_LIMIT` (requests per second, either one value for every
+    /// endpoint or one per endpoint), `STARKNET_RPC_TIMEOUT_SECS` and
+    /// `STARKNET_RPC_COOLDOWN_SECS`
+    pub fn from_env(rpc_urls: &str) -> Result<Self> {
+        let urls = parse_urls(rpc_urls)?;
+        let rate_limits = match std::env::var("STARKNET_RPC_RATE_LIMIT") {
+            Ok(value) if !value.trim().is_empty() => parse_rate_limits(&value, urls.len())?,
+            _ => vec![0; urls.len()],
+        };
+
+        let endpoints = urls
+            .into_iter()
+            .zip(rate_limits)
+            .map(|(url, rate_limit)| Endpoint::new(url).with_rate_limit(rate_limit))
+            .collect();
+        let mut transport = Self::new(endpoints)?;
+        if let Some(timeout) = parse_env("STARKNET_RPC_TIMEOUT_SECS")? {
+            transport = transport.with_timeout(Duration::from_secs(timeout));
+        }
+        if let Some(cooldown) = parse_env("STARKNET_RPC_COOLDOWN_SECS")? {
+            transport = transport.with_cooldown(Duration::from_secs(cooldown));
+        }
+        Ok(transport)
+    }
+
+    /// Gives up on an endpoint that has not answered within `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Passes over a failed endpoint for `cooldown`
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    pub fn endpoints(&self) -> &[Endpoint] {
+        &self.endpoints
+    }
+
+    /// Asks every endpoint for its latest block, updating their health. Returns the
+    /// block number, or the error, of each endpoint.
+    pub async fn check_health(&self) -> Vec<(Url, Result<u64>)> {
+        let mut statuses = Vec::with_capacity(self.endpoints.len());
+        for endpoint in self.endpoints.iter() {
+            let status = match self.send_to(endpoint, block_number).await {
+                Ok(JsonRpcResponse::Success { result, .. }) => Ok(result),
+                Ok(JsonRpcResponse::Error { error, .. }) => Err(eyre!("{}", error.message)),
+                Err(attempt) => Err(eyre!("{}", attempt.error)),
+            };
+            statuses.push((endpoint.url.clone(), status));
+        }
+        statuses
+    }
+
+    /// Healthy endpoints in order, then the unhealthy ones as a last resort
+    fn candidates(&self) -> impl Iterator<Item = &Endpoint> {
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = self
+            .endpoints
+            .iter()
+            .partition(|endpoint| endpoint.is_healthy());
+        healthy.into_iter().chain(unhealthy)
+    }
+
+    /// Sends `request` to `endpoint` within the rate limit and timeout, updating the
+    /// endpoint's health
+    async fn send_to<'a, T, F, Fut>(&self, endpoint: &'a Endpoint, request: F) -> Result<T, Attempt>
+    where
+        F: FnOnce(&'a HttpTransport) -> Fut,
+        Fut: Future<Output = Result<T, HttpTransportError>>,
+    {
+        endpoint.wait_for_slot().await;
+        let (error, delivered) =
+            match tokio::time::timeout(self.timeout, request(&endpoint.transport)).await {
+                Ok(Ok(response)) => {
+                    endpoint.mark_healthy();
+                    return Ok(response);
+                }
+                // Refused connections are the only failures known to happen before
+                // the request is sent
+                Ok(Err(HttpTransportError::Reqwest(e))) if e.is_connect() => (e.to_string(), false),
+                Ok(Err(e)) => (e.to_string(), true),
+                Err(_) => (format!("no response within {:?}", self.timeout), true),
+            };
+
+        warn!(url = %endpoint.url, error = %error, "RPC endpoint failed");
+        endpoint.mark_unhealthy(self.cooldown);
+        Err(Attempt {
+            error: format!("{}: {}", endpoint.url, error),
+            delivered,
+        })
+    }
+
+    /// Sends `request` to each endpoint in turn until one answers. An answer for
+    /// which `lagging` holds is only returned if no other endpoint answers
+    /// differently. A write is not sent on once an endpoint may have received it.
+    async fn send<'a, T, F, Fut>(
+        &'a self,
+        write: bool,
+        lagging: impl Fn(&T) -> bool,
+        request: F,
+    ) -> Result<T, FailoverError>
+    where
+        F: Fn(&'a HttpTransport) -> Fut,
+        Fut: Future<Output = Result<T, HttpTransportError>>,
+    {
+        let mut errors = vec![];
+        let mut fallback = None;
+        for endpoint in self.candidates() {
+            match self.send_to(endpoint, &request).await {
+                Ok(response) if lagging(&response) => {
+                    debug!(url = %endpoint.url, "Block not found, trying the next endpoint");
+                    fallback.get_or_insert(response);
+                }
+                Ok(response) => return Ok(response),
+                Err(attempt) => {
+                    errors.push(attempt.error);
+                    if write && attempt.delivered {
+                        warn!(
+                            url = %endpoint.url,
+                            "Not resending a write the endpoint may have received"
+                        );
+                        break;
+                    }
+                }
+            }
+        }
+        fallback.ok_or(FailoverError { errors })
+    }
+}
+
+/// Whether `method` submits a transaction
+fn is_write(method: JsonRpcMethod) -> bool {
+    matches!(
+        method,
+        JsonRpcMethod::AddInvokeTransaction
+            | JsonRpcMethod::AddDeclareTransaction
+            | JsonRpcMethod::AddDeployAccountTransaction
+    )
+}
+
+fn is_block_not_found<R>(response: &JsonRpcResponse<R>) -> bool {
+    matches!(response, JsonRpcResponse::Error { error, .. } if error.code == BLOCK_NOT_FOUND)
+}
+
+/// `starknet_blockNumber`, which takes no parameters
+async fn block_number(
+    transport: &HttpTransport,
+) -> Result<JsonRpcResponse<u64>, HttpTransportError> {
+    transport
+        .send_request(JsonRpcMethod::BlockNumber, [(); 0])
+        .await
+}
+
+#[async_trait]
+impl JsonRpcTransport for FailoverTransport {
+    type Error = FailoverError;
+
+    async fn send_request<P, R>(
+        &self,
+        method: JsonRpcMethod,
+        params: P,
+    ) -> Result<JsonRpcResponse<R>, Self::Error>
+    where
+        P: Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let params = &params;
+        self.send(is_write(method), is_block_not_found, |transport| {
+            transport.send_request(method, params)
+        })
+        .await
+    }
+
+    async fn send_requests<R>(
+        &self,
+        requests: R,
+    ) -> Result<Vec<JsonRpcResponse<serde_json::Value>>, Self::Error>
+    where
+        R: AsRef<[ProviderRequestData]> + Send + Sync,
+    {
+        let requests = requests.as_ref();
+        let write = requests.iter().any(|request| {
+            matches!(
+                request,
+                ProviderRequestData::AddInvokeTransaction(_)
+                    | ProviderRequestData::AddDeclareTransaction(_)
+                    | ProviderRequestData::AddDeployAccountTransaction(_)
+            )
+        });
+        let lagging = |responses: &Vec<JsonRpcResponse<serde_json::Value>>| {
+            responses.iter().any(is_block_not_found)
+        };
+        self.send(write, lagging, |transport| {
+            transport.send_requests(requests)
+        })
+        .await
+    }
+}
+
+/// Parses a comma separated list of URLs
+pub fn parse_urls(rpc_urls: &str) -> Result<Vec<Url>> {
+    let urls = rpc_urls
+        .split(',')
+        .map(str::trim)
+        .filter(|url| !url.is_empty())
+        .map(|url| Url::parse(url).map_err(|e| eyre!("Invalid RPC URL {}: {}", url, e)))
+        .collect::<Result<Vec<_>>>()?;
+    if urls.is_empty() {
+        return Err(eyre!("No RPC URL given"));
+    }
+    Ok(urls)
+}
+
+fn parse_rate_limits(value: &str, endpoints: usize) -> Result<Vec<u32>> {
+    let limits = value
+        .split(',')
+        .map(|limit| {
+            limit
+                .trim()
+                .parse()
+                .map_err(|_| eyre!("Invalid STARKNET_RPC_RATE_LIMIT: {}", value))
+        })
+        .collect::<Result<Vec<u32>>>()?;
+    match limits.len() {
+        1 => Ok(vec![limits[0]; endpoints]),
+        n if n == endpoints => Ok(limits),
+        n => Err(eyre!(
+            "STARKNET_RPC_RATE_LIMIT has {} values for {} endpoints",
+            n,
+            endpoints
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::providers::{JsonRpcClient, Provider};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const BLOCK_NUMBER: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 36\r\nConnection: close\r\n\r\n{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":42}";
+    const BLOCK_NOT_FOUND_ERROR: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 72\r\nConnection: close\r\n\r\n{\"jsonrpc\":\"2.0\",\"id\":1,\"error\":{\"code\":24,\"message\":\"Block not found\"}}";
+    const TOO_MANY_REQUESTS: &str =
+        "HTTP/1.1 429 Too Many Requests\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+    /// Answers every request with `response`, or never answers if it is `None`
+    async fn serve(response: Option<&'static str>) -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                tokio::spawn(async move {
+                    let mut request = [0u8; 4096];
+                    let _ = socket.read(&mut request).await;
+                    match response {
+                        Some(response) => {
+                            let _ = socket.write_all(response.as_bytes()).await;
+                            let _ = socket.shutdown().await;
+                        }
+                        None => tokio::time::sleep(Duration::from_secs(60)).await,
+                    }
+                });
+            }
+        });
+        url
+    }
+
+    #[tokio::test]
+    async fn test_fails_over_to_next_endpoint() {
+        let limited = serve(Some(TOO_MANY_REQUESTS)).await;
+        let working = serve(Some(BLOCK_NUMBER)).await;
+        let transport =
+            FailoverTransport::new(vec![Endpoint::new(limited), Endpoint::new(working)]).unwrap();
+        let client = JsonRpcClient::new(transport.clone());
+
+        assert_eq!(client.block_number().await.unwrap(), 42);
+        assert!(!transport.endpoints()[0].is_healthy());
+        assert!(transport.endpoints()[1].is_healthy());
+
+        // Health checks ask every endpoint, healthy or not
+        let statuses = transport.check_health().await;
+        assert!(statuses[0].1.is_err());
+        assert_eq!(statuses[1].1.as_ref().unwrap(), &42);
+    }
+
+    #[tokio::test]
+    async fn test_times_out_unresponsive_endpoint() {
+        let hanging = serve(None).await;
+        let working = serve(Some(BLOCK_NUMBER)).await;
+        let transport =
+            FailoverTransport::new(vec![Endpoint::new(hanging), Endpoint::new(working)])
+                .unwrap()
+                .with_timeout(Duration::from_millis(200));
+        let client = JsonRpcClient::new(transport.clone());
+
+        assert_eq!(client.block_number().await.unwrap(), 42);
+        assert!(!transport.endpoints()[0].is_healthy());
+    }
+
+    /// URL nothing listens on
+    async fn refused_url() -> Url {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_write_is_not_resent_after_timeout() {
+        let hanging = serve(None).await;
+        let working = serve(Some(BLOCK_NUMBER)).await;
+        let transport = FailoverTransport::new(vec![
+            Endpoint::new(hanging.clone()),
+            Endpoint::new(working.clone()),
+        ])
+        .unwrap()
+        .with_timeout(Duration::from_millis(200));
+
+        let err = transport
+            .send_request::<_, u64>(JsonRpcMethod::AddInvokeTransaction, [(); 0])
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(hanging.as_str()));
+        assert!(!err.contains(working.as_str()));
+
+        // Reads still fail over
+        let client = JsonRpcClient::new(transport);
+        assert_eq!(client.block_number().await.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn test_write_fails_over_when_unreachable() {
+        let transport = FailoverTransport::new(vec![
+            Endpoint::new(refused_url().await),
+            Endpoint::new(serve(Some(BLOCK_NUMBER)).await),
+        ])
+        .unwrap();
+
+        let response = transport
+            .send_request::<_, u64>(JsonRpcMethod::AddInvokeTransaction, [(); 0])
+            .await
+            .unwrap();
+        assert!(matches!(
+            response,
+            JsonRpcResponse::Success { result: 42, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_block_not_found_tries_next_endpoint() {
+        let lagging = serve(Some(BLOCK_NOT_FOUND_ERROR)).await;
+        let synced = serve(Some(BLOCK_NUMBER)).await;
+        let transport =
+            FailoverTransport::new(vec![Endpoint::new(lagging.clone()), Endpoint::new(synced)])
+                .unwrap();
+
+        let response = transport
+            .send_request::<_, u64>(JsonRpcMethod::BlockNumber, [(); 0])
+            .await
+            .unwrap();
+        assert!(matches!(
+            response,
+            JsonRpcResponse::Success { result: 42, .. }
+        ));
+        // Lagging is not failing
+        assert!(transport.endpoints()[0].is_healthy());
+
+        // When no endpoint has the block, that is the answer
+        let transport = FailoverTransport::new(vec![Endpoint::new(lagging)]).unwrap();
+        let response = transport
+            .send_request::<_, u64>(JsonRpcMethod::BlockNumber, [(); 0])
+            .await
+            .unwrap();
+        assert!(is_block_not_found(&response));
+    }
+
+    #[tokio::test]
+    async fn test_all_endpoints_failing() {
+        let first = serve(Some(TOO_MANY_REQUESTS)).await;
+        let second = serve(Some(TOO_MANY_REQUESTS)).await;
+        let transport = FailoverTransport::new(vec![
+            Endpoint::new(first.clone()),
+            Endpoint::new(second.clone()),
+        ])
+        .unwrap()
+        .with_cooldown(Duration::ZERO);
+
+        let err = transport
+            .send_request::<_, u64>(JsonRpcMethod::BlockNumber, [(); 0])
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(first.as_str()));
+        assert!(err.contains(second.as_str()));
+        // No cooldown, so both are tried first again
+        assert!(transport.endpoints()[0].is_healthy());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limit_spaces_requests() {
+        let endpoint =
+            Endpoint::new(Url::parse("http://localhost:5050").unwrap()).with_rate_limit(10);
+        let started = Instant::now();
+        for _ in 0..5 {
+            endpoint.wait_for_slot().await;
+        }
+        assert_eq!(started.elapsed(), Duration::from_millis(400));
+
+        let unlimited =
+            Endpoint::new(Url::parse("http://localhost:5050").unwrap()).with_rate_limit(0);
+        let started = Instant::now();
+        unlimited.wait_for_slot().await;
+        unlimited.wait_for_slot().await;
+        assert_eq!(started.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_parse_urls_and_rate_limits() {
+        let urls = parse_urls("http://a:5050, http://b:5050/rpc,").unwrap();
+        assert_eq!(
+            urls.iter().map(Url::as_str).collect::<Vec<_>>(),
+            ["http://a:5050/", "http://b:5050/rpc"]
+        );
+        assert!(parse_urls(" , ").is_err());
+        assert!(parse_urls("not a url").is_err());
+
+        assert_eq!(parse_rate_limits("5", 3).unwrap(), [5, 5, 5]);
+        assert_eq!(parse_rate_limits("5, 0", 2).unwrap(), [5, 0]);
+        assert!(parse_rate_limits("5,0", 3).is_err());
+        assert!(parse_rate_limits("fast", 1).is_err());
+    }
+}
@@ -0,0 +1,253 @@
+//! Tracking of submitted transactions.
+//!
+//! Starknet accepts a transaction long before it is executed, so a transaction hash
+//! alone says nothing about whether the call did what it was meant to. Callers wait
+//! for the receipt with [`wait_for_receipt`] and get a [`TxOutcome`] back. Reverts
+//! are outcomes, not errors: retrying them cannot help, whereas `Err` is reserved for
+//! failures to reach the node, which can be retried.
+use eyre::{eyre, Result};
+use starknet::core::codec::Decode;
+use starknet::core::types::{ByteArray, ExecutionResult, StarknetError, TransactionFinalityStatus};
+use starknet::providers::{Provider, ProviderError};
+use starknet_crypto::Felt;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// How often the receipt is polled
+pub const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How long to wait for a transaction to be accepted on L2
+pub const RECEIPT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// First felt of a `ByteArray` panic, as raised by `assert!` and `panic!` with a
+/// formatted message
+const BYTE_ARRAY_MAGIC: &str = "0x46a6158a16a947e5916b2a2ca68501a45e93d7110e81aa2d6438b1c57c879a3";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxOutcome {
+    /// Executed and accepted on L2
+    Succeeded {
+        transaction_hash: Felt,
+        actual_fee: Felt,
+    },
+    /// Included in a block, but execution reverted. The fee is still charged.
+    Reverted {
+        transaction_hash: Felt,
+        actual_fee: Felt,
+        reason: String,
+    },
+    /// Refused by the node before submission, usually because fee estimation hit
+    /// the same revert
+    Rejected { reason: String },
+}
+
+impl TxOutcome {
+    pub const fn is_success(&self) -> bool {
+        matches!(self, Self::Succeeded { .. })
+    }
+
+    pub const fn transaction_hash(&self) -> Option<Felt> {
+        match self {
+            Self::Succeeded {
+                transaction_hash, ..
+            }
+            | Self::Reverted {
+                transaction_hash, ..
+            } => Some(*transaction_hash),
+            Self::Rejected { .. } => None,
+        }
+    }
+
+    /// The fee charged, if the transaction made it into a block
+    pub const fn actual_fee(&self) -> Option<Felt> {
+        match self {
+            Self::Succeeded { actual_fee, .. } | Self::Reverted { actual_fee, .. } => {
+                Some(*actual_fee)
+            }
+            Self::Rejected { .. } => None,
+        }
+    }
+
+    /// The decoded revert reason, if the transaction did not succeed
+    pub fn revert_reason(&self) -> Option<&str> {
+        match self {
+            Self::Succeeded { .. } => None,
+            Self::Reverted { reason, .. } | Self::Rejected { reason } => Some(reason),
+        }
+    }
+
+    /// Turns anything but success into an error carrying the revert reason
+    pub fn into_result(self) -> Result<Felt> {
+        match self {
+            Self::Succeeded {
+                transaction_hash, ..
+            } => Ok(transaction_hash),
+            Self::Reverted {
+                transaction_hash,
+                reason,
+                ..
+            } => Err(eyre!(
+                "Transaction {} reverted: {}",
+                transaction_hash.to_hex_string(),
+                reason
+            )),
+            Self::Rejected { reason } => Err(eyre!("Transaction rejected: {}", reason)),
+        }
+    }
+}
+
+/// Polls the receipt of `transaction_hash` until it is accepted on L2. Errors only if
+/// the node cannot be reached or the transaction is not accepted within `timeout`.
+pub async fn wait_for_receipt<P: Provider + Sync>(
+    provider: &P,
+    transaction_hash: Felt,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> Result<TxOutcome> {
+    let started = Instant::now();
+    loop {
+        match provider.get_transaction_receipt(transaction_hash).await {
+            Ok(receipt) => {
+                let receipt = receipt.receipt;
+                let accepted = matches!(
+                    receipt.finality_status(),
+                    TransactionFinalityStatus::AcceptedOnL2
+                        | TransactionFinalityStatus::AcceptedOnL1
+                );
+                if accepted {
+                    let outcome = match receipt.execution_result() {
+                        ExecutionResult::Succeeded => TxOutcome::Succeeded {
+                            transaction_hash,
+                            actual_fee: receipt.actual_fee().amount,
+                        },
+                        ExecutionResult::Reverted { reason } => TxOutcome::Reverted {
+                            transaction_hash,
+                            actual_fee: receipt.actual_fee().amount,
+                            reason: decode_revert_reason(reason),
+                        },
+                    };
+                    info!(tx_hash = ?transaction_hash, outcome = ?outcome, "Transaction accepted");
+                    return Ok(outcome);
+                }
+                debug!(tx_hash = ?transaction_hash, "Transaction not accepted on L2 yet");
+            }
+            Err(ProviderError::StarknetError(StarknetError::TransactionHashNotFound)) => {
+                debug!(tx_hash = ?transaction_hash, "Transaction not received yet");
+            }
+            Err(e) => warn!(error = ?e, tx_hash = ?transaction_hash, "Failed to fetch receipt"),
+        }
+
+        if started.elapsed() >= timeout {
+            return Err(eyre!(
+                "Transaction {} was not accepted within {:?}",
+                transaction_hash.to_hex_string(),
+                timeout
+            ));
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Extracts the human readable message from a raw revert reason. Handles both
+/// `ByteArray` panics, which nodes report as felts, and short string panics, which
+/// they annotate as `0x... ('message')`. Falls back to the raw reason.
+pub fn decode_revert_reason(raw: &str) -> String {
+    let felts: Vec<&str> = raw
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|token| token.starts_with("0x"))
+        .collect();
+    if let Some(start) = felts.iter().position(|felt| *felt == BYTE_ARRAY_MAGIC) {
+        let data = felts[start + 1..]
+            .iter()
+            .map(|felt| Felt::from_hex(felt))
+            .collect::<Result<Vec<_>, _>>();
+        if let Some(message) = data
+            .ok()
+            .and_then(|data| ByteArray::decode_iter(&mut data.iter()).ok())
+            .and_then(|bytes| String::try_from(bytes).ok())
+        {
+            return message;
+        }
+    }
+
+    let short_strings: Vec<&str> = raw
+        .split("('")
+        .skip(1)
+        .filter_map(|rest| rest.split_once("')").map(|(message, _)| message))
+        .collect();
+    if !short_strings.is_empty() {
+        return short_strings.join(", ");
+    }
+
+    raw.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starknet::core::codec::Encode;
+
+    #[test]
+    fn test_decode_byte_array_revert() {
+        let message =
+            "Update interval: 5 must be greater than or equal to the minimum update interval: 10";
+        let mut felts = vec![];
+        ByteArray::from(message).encode(&mut felts).unwrap();
+        let raw = format!(
+            "Transaction execution has failed:\n0: Error in the called contract (0x0123):\nExecution failed. Failure reason: ({}, {}).",
+            BYTE_ARRAY_MAGIC,
+            felts
+                .iter()
+                .map(|felt| felt.to_hex_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        assert_eq!(decode_revert_reason(&raw), message);
+    }
+
+    #[test]
+    fn test_decode_short_string_revert() {
+        let raw = "Execution failed. Failure reason: (0x43616c6c6572206973206e6f7420746865206f776e6572 ('Caller is not the owner'), 0x454e545259504f494e545f4641494c4544 ('ENTRYPOINT_FAILED')).";
+        assert_eq!(
+            decode_revert_reason(raw),
+            "Caller is not the owner, ENTRYPOINT_FAILED"
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_revert() {
+        assert_eq!(decode_revert_reason("  Out of gas\n"), "Out of gas");
+    }
+
+    #[test]
+    fn test_outcome_accessors() {
+        let hash = Felt::from(0xabcu64);
+        let succeeded = TxOutcome::Succeeded {
+            transaction_hash: hash,
+            actual_fee: Felt::ONE,
+        };
+        assert!(succeeded.is_success());
+        assert_eq!(succeeded.revert_reason(), None);
+        assert_eq!(succeeded.into_result().unwrap(), hash);
+
+        let reverted = TxOutcome::Reverted {
+            transaction_hash: hash,
+            actual_fee: Felt::TWO,
+            reason: "Batch link mismatch".to_string(),
+        };
+        assert_eq!(reverted.transaction_hash(), Some(hash));
+        assert_eq!(reverted.actual_fee(), Some(Felt::TWO));
+        assert_eq!(reverted.revert_reason(), Some("Batch link mismatch"));
+        assert_eq!(
+            reverted.into_result().unwrap_err().to_string(),
+            "Transaction 0xabc reverted: Batch link mismatch"
+        );
+
+        let rejected = TxOutcome::Rejected {
+            reason: "Out of gas".to_string(),
+        };
+        assert_eq!(rejected.transaction_hash(), None);
+        assert_eq!(rejected.actual_fee(), None);
+    }
+}
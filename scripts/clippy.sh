@@ -15,6 +15,7 @@ cargo clippy \
     -p relayer \
     -p starknet-handler \
     -p guest-mmr \
+    -p fee-math \
     -- \
     -W clippy::branches_sharing_code \
     -W clippy::clear_with_drain \
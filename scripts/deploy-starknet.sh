@@ -61,6 +61,12 @@ source "${ENV_FILES[0]}"
 
 STARKNET_DIR="$ORIGINAL_DIR/contracts/starknet"
 
+# The Fossil verifier only accepts proofs of this guest, built from this chain
+if [ -z "$MMR_BUILD_METHOD_ID" ] || [ -z "$CHAIN_ID" ]; then
+    echo "Error: MMR_BUILD_METHOD_ID and CHAIN_ID must be set in ${ENV_FILES[0]}"
+    exit 1
+fi
+
 # Define colors
 GREEN='\033[0;32m'
 BLUE='\033[0;34m'
@@ -121,7 +127,7 @@ echo -e "${GREEN}Class hash declared: ${BOLD}$FOSSIL_VERIFIER_HASH${NC}"
 echo
 
 echo -e "${YELLOW}Deploying Fossil Verifier contract...${NC}"
-FOSSIL_VERIFIER_ADDRESS=$(starkli deploy $FOSSIL_VERIFIER_HASH $VERIFIER_ADDRESS $FOSSILSTORE_ADDRESS $STARKNET_ACCOUNT_ADDRESS --account $STARKNET_ACCOUNT --rpc $STARKNET_RPC_URL --salt 1 -w | grep -o '0x[a-fA-F0-9]\{64\}' | head -1)
+FOSSIL_VERIFIER_ADDRESS=$(starkli deploy $FOSSIL_VERIFIER_HASH $VERIFIER_ADDRESS $FOSSILSTORE_ADDRESS $STARKNET_ACCOUNT_ADDRESS u256:0x$MMR_BUILD_METHOD_ID $CHAIN_ID --account $STARKNET_ACCOUNT --rpc $STARKNET_RPC_URL --salt 1 -w | grep -o '0x[a-fA-F0-9]\{64\}' | head -1)
 echo -e "${GREEN}Contract deployed at: ${BOLD}$FOSSIL_VERIFIER_ADDRESS${NC}"
 echo
 